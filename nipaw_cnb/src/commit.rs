@@ -2,7 +2,7 @@ use crate::CnbClientInner;
 use async_trait::async_trait;
 use crate::common::JsonValue;
 use nipaw_core::option::commit::ListOptions;
-use nipaw_core::types::commit::{CommitInfo, CommitListInfo};
+use nipaw_core::types::commit::{CommitInfo, CommitListInfo, CompareInfo};
 use nipaw_core::types::repo::RepoPath;
 use nipaw_core::{Commit, Error, Result};
 use serde_json::Value;
@@ -95,8 +95,8 @@ impl Commit for CnbCommit {
 		}
 		let mut params: HashMap<&str, String> = HashMap::new();
 		if let Some(option) = option {
-			let per_page = option.per_page.unwrap_or(30).max(100);
-			params.insert("per_page", per_page.to_string());
+			let per_page = option.per_page.unwrap_or(30).min(100);
+			params.insert("page_size", per_page.to_string());
 			let page = option.page.unwrap_or_default();
 			params.insert("page", page.to_string());
 			if let Some(sha) = option.sha {
@@ -116,4 +116,61 @@ impl Commit for CnbCommit {
 		let commit_infos: Vec<JsonValue> = resp.json().await?;
 		Ok(commit_infos.into_iter().map(|v| v.into()).collect())
 	}
+
+	async fn compare(&self, repo_path: RepoPath, base: &str, head: &str) -> Result<CompareInfo> {
+		let (token, api_url, base_url) =
+			(&self.0.config.token, &self.0.config.api_url, &self.0.config.base_url);
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let url = format!(
+			"{}/{}/{}/-/git/compare/{}...{}",
+			api_url, repo_path.owner, repo_path.repo, base, head
+		);
+		let client = self.0.client.read().await;
+		let request = client.get(url).bearer_auth(token.as_ref().unwrap());
+		let mut compare_info = request.send().await?.json::<JsonValue>().await?;
+		if let Some(commits) = compare_info.0.get_mut("commits").and_then(|v| v.as_array_mut()) {
+			for commit in commits.iter_mut() {
+				for role in ["author", "committer"] {
+					if let Some(user) = commit
+						.get_mut("commit")
+						.and_then(|commit| commit.get_mut(role))
+						.and_then(|user| user.as_object_mut())
+					{
+						let name = user.get("name").and_then(|v| v.as_str()).unwrap_or_default();
+						let avatar_url = format!("{}/users/{}/avatar/l", base_url, name);
+						user.insert("avatar_url".to_string(), Value::String(avatar_url));
+					}
+				}
+			}
+		}
+		Ok(compare_info.into())
+	}
+
+	async fn diff(&self, repo_path: RepoPath, sha: &str) -> Result<String> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let url =
+			format!("{}/{}/{}/-/git/commits/{}", api_url, repo_path.owner, repo_path.repo, sha);
+		let client = self.0.client.read().await;
+		let request = client.get(url).bearer_auth(token.as_ref().unwrap());
+		let commit_info = request.send().await?.json::<JsonValue>().await?;
+		drop(client);
+		let parent_sha = commit_info
+			.0
+			.get("parents")
+			.and_then(|p| p.as_array())
+			.and_then(|arr| arr.first())
+			.and_then(|p| p.get("sha"))
+			.and_then(|v| v.as_str());
+		// CNB没有返回原始差异的接口, 与父提交比较后由各文件的差异内容拼接
+		let files = match parent_sha {
+			Some(parent_sha) => self.compare(repo_path, parent_sha, sha).await?.files,
+			None => CommitInfo::from(commit_info).files,
+		};
+		Ok(files.iter().filter_map(|file| file.to_unified_diff()).collect())
+	}
 }
@@ -1,8 +1,10 @@
 mod commit;
 mod issue;
 mod org;
+mod pipeline;
 mod release;
 mod repo;
+mod status;
 mod user;
 
 use serde::{Deserialize, Serialize};
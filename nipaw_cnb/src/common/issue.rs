@@ -1,7 +1,8 @@
 use crate::CnbConfig;
 use crate::common::JsonValue;
 use chrono::Utc;
-use nipaw_core::types::issue::{IssueInfo, LabelInfo, StateType, UserInfo};
+use nipaw_core::types::issue::{IssueCommentInfo, IssueInfo, LabelInfo, StateType, UserInfo};
+use serde_json::Value;
 
 impl From<JsonValue> for IssueInfo {
 	fn from(issue: JsonValue) -> Self {
@@ -39,6 +40,24 @@ impl From<JsonValue> for IssueInfo {
 				.get("closed_at")
 				.and_then(|v| v.as_str())
 				.and_then(|s| s.parse::<chrono::DateTime<Utc>>().ok()),
+			assignees: issue_info
+				.get("assignees")
+				.and_then(|v| v.as_array())
+				.map(|arr| arr.iter().filter_map(parse_user).collect())
+				.unwrap_or_default(),
+			comments: issue_info
+				.get("comment_count")
+				.or(issue_info.get("comments"))
+				.and_then(|v| v.as_u64())
+				.unwrap_or_default(),
+			milestone: issue_info
+				.get("milestone")
+				.and_then(|v| v.get("title"))
+				.and_then(|v| v.as_str())
+				.map(|s| s.to_string()),
+			html_url: issue_info.get("html_url").and_then(|v| v.as_str()).map(|s| s.to_string()),
+			locked: None,
+			is_pull_request: false,
 		}
 	}
 }
@@ -75,3 +94,36 @@ impl From<JsonValue> for Vec<LabelInfo> {
 			.unwrap_or_default()
 	}
 }
+
+/// 解析只包含用户名的用户信息, 头像地址由用户名拼接
+fn parse_user(user: &Value) -> Option<UserInfo> {
+	let login = user.get("username")?.as_str()?;
+	Some(UserInfo {
+		login: login.to_string(),
+		avatar_url: format!("{}/users/{}/avatar/l", CnbConfig::default().base_url, login),
+		email: None,
+	})
+}
+
+impl From<JsonValue> for IssueCommentInfo {
+	fn from(comment: JsonValue) -> Self {
+		let comment_info = comment.0;
+		let parse_time = |key: &str| {
+			comment_info
+				.get(key)
+				.and_then(|v| v.as_str())
+				.and_then(|s| s.parse::<chrono::DateTime<Utc>>().ok())
+		};
+		Self {
+			id: match comment_info.get("id") {
+				Some(Value::String(id)) => id.to_string(),
+				Some(id) => id.to_string(),
+				None => String::new(),
+			},
+			user: comment_info.get("author").and_then(parse_user),
+			body: comment_info.get("body").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+			created_at: parse_time("created_at").unwrap_or_default(),
+			updated_at: parse_time("updated_at"),
+		}
+	}
+}
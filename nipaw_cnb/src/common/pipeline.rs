@@ -0,0 +1,71 @@
+use crate::common::JsonValue;
+use chrono::{DateTime, Utc};
+use nipaw_core::types::pipeline::{PipelineJob, PipelineRun, PipelineStatus, PipelineStep};
+use serde_json::Value;
+
+/// 解析时间, 接口可能返回RFC3339字符串或毫秒时间戳
+fn parse_time(value: &Value, key: &str) -> Option<DateTime<Utc>> {
+	match value.get(key)? {
+		Value::String(s) => s.parse().ok(),
+		Value::Number(n) => DateTime::from_timestamp_millis(n.as_i64()?),
+		_ => None,
+	}
+}
+
+fn parse_status(value: &Value) -> PipelineStatus {
+	PipelineStatus::parse(value.get("status").and_then(|v| v.as_str()).unwrap_or_default(), None)
+}
+
+impl From<JsonValue> for PipelineRun {
+	fn from(value: JsonValue) -> Self {
+		let build = value.0;
+		let get_str = |key: &str| build.get(key).and_then(|v| v.as_str()).map(|s| s.to_string());
+		Self {
+			id: get_str("sn").unwrap_or_default(),
+			name: get_str("commitTitle"),
+			number: None,
+			branch: get_str("sourceRef").map(|r| r.trim_start_matches("refs/heads/").to_string()),
+			sha: get_str("sha"),
+			event: get_str("event"),
+			status: parse_status(&build),
+			html_url: get_str("buildLogUrl"),
+			created_at: parse_time(&build, "createTime"),
+			updated_at: parse_time(&build, "updateTime"),
+			jobs: Vec::new(),
+		}
+	}
+}
+
+impl From<JsonValue> for PipelineJob {
+	fn from(value: JsonValue) -> Self {
+		let pipeline = value.0;
+		Self {
+			id: pipeline.get("id").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+			name: pipeline.get("name").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+			status: parse_status(&pipeline),
+			started_at: parse_time(&pipeline, "startTime"),
+			completed_at: parse_time(&pipeline, "endTime"),
+			steps: pipeline
+				.get("stages")
+				.and_then(|v| v.as_array())
+				.map(|stages| {
+					stages
+						.iter()
+						.enumerate()
+						.map(|(index, stage)| PipelineStep {
+							name: stage
+								.get("name")
+								.and_then(|v| v.as_str())
+								.unwrap_or_default()
+								.to_string(),
+							number: index as u64 + 1,
+							status: parse_status(stage),
+							started_at: parse_time(stage, "startTime"),
+							completed_at: parse_time(stage, "endTime"),
+						})
+						.collect()
+				})
+				.unwrap_or_default(),
+		}
+	}
+}
@@ -1,5 +1,10 @@
 use crate::common::JsonValue;
-use nipaw_core::types::repo::{RepoInfo, Visibility};
+use nipaw_core::types::repo::{
+	CollaboratorInfo, CollaboratorPermission, LanguageUnit, RepoInfo, RepoLanguages, RepoPath,
+	Visibility,
+};
+use serde_json::Value;
+use std::collections::HashMap;
 
 impl From<JsonValue> for RepoInfo {
 	fn from(json_value: JsonValue) -> Self {
@@ -31,11 +36,18 @@ impl From<JsonValue> for RepoInfo {
 				.and_then(|v| v.as_str())
 				.is_some(),
 			fork_count: repo_info.get("fork_count").and_then(|v| v.as_u64()).unwrap_or(0),
+			parent: repo_info
+				.get("forked_from_repo")
+				.and_then(|v| v.get("path"))
+				.and_then(|v| v.as_str())
+				.and_then(|path| path.rsplit_once('/'))
+				.map(|(owner, repo)| RepoPath::new(owner, repo)),
 			language: repo_info
 				.get("language")
 				.and_then(|v| v.as_str())
 				.filter(|s| !s.is_empty())
 				.map(|s| s.to_string()),
+			topics: parse_topics(repo_info.get("topics")),
 			star_count: repo_info.get("star_count").and_then(|v| v.as_u64()).unwrap_or(0),
 			default_branch: repo_info
 				.get("default_branch")
@@ -66,3 +78,75 @@ impl From<JsonValue> for RepoInfo {
 		}
 	}
 }
+
+impl From<JsonValue> for CollaboratorInfo {
+	fn from(json_value: JsonValue) -> Self {
+		let member = json_value.0;
+		let login = member.get("username").and_then(|v| v.as_str()).unwrap().to_string();
+		Self {
+			avatar_url: format!("https://cnb.cool/users/{}/avatar/l", login),
+			login,
+			permission: JsonValue(member).into(),
+		}
+	}
+}
+
+impl From<JsonValue> for CollaboratorPermission {
+	fn from(json_value: JsonValue) -> Self {
+		match json_value.0.get("access_level").and_then(|v| v.as_str()) {
+			Some("Owner") | Some("Master") => CollaboratorPermission::Admin,
+			Some("Developer") => CollaboratorPermission::Push,
+			Some("Reporter") | Some("Guest") => CollaboratorPermission::Pull,
+			_ => CollaboratorPermission::None,
+		}
+	}
+}
+
+/// 统一主题格式, 兼容字符串数组、带`name`字段的对象数组以及逗号分隔的字符串
+fn parse_topics(value: Option<&Value>) -> Vec<String> {
+	let topics: Vec<String> = match value {
+		Some(Value::Array(items)) => items
+			.iter()
+			.filter_map(|v| v.as_str().or_else(|| v.get("name").and_then(|v| v.as_str())))
+			.map(|s| s.trim().to_string())
+			.collect(),
+		Some(Value::String(s)) => s.split(',').map(|s| s.trim().to_string()).collect(),
+		_ => Vec::new(),
+	};
+	topics.into_iter().filter(|s| !s.is_empty()).collect()
+}
+
+impl From<JsonValue> for RepoLanguages {
+	fn from(json_value: JsonValue) -> Self {
+		// CNB返回语言列表, 每项包含语言名称与占比
+		let languages = match json_value.0 {
+			Value::Array(items) => items
+				.iter()
+				.filter_map(|item| {
+					let name = item
+						.get("language")
+						.or_else(|| item.get("name"))
+						.and_then(|v| v.as_str())?;
+					let percent = item
+						.get("percent")
+						.or_else(|| item.get("percentage"))
+						.and_then(parse_percent)?;
+					Some((name.to_string(), percent))
+				})
+				.collect(),
+			Value::Object(map) => {
+				map.iter().filter_map(|(k, v)| Some((k.clone(), parse_percent(v)?))).collect()
+			}
+			_ => HashMap::new(),
+		};
+		Self { unit: LanguageUnit::Percent, languages }
+	}
+}
+
+fn parse_percent(value: &Value) -> Option<f64> {
+	match value {
+		Value::Number(n) => n.as_f64(),
+		Value::String(s) => s.trim().trim_end_matches('%').parse().ok(),
+		_ => None,
+	}
+}
@@ -0,0 +1,47 @@
+use async_trait::async_trait;
+use nipaw_core::option::gist::{CreateOptions, UpdateOptions};
+use nipaw_core::option::repo::ListOptions;
+use nipaw_core::types::gist::GistInfo;
+use nipaw_core::{Error, Gist, Result};
+
+/// CNB没有代码片段功能
+pub struct CnbGist;
+
+#[async_trait]
+impl Gist for CnbGist {
+	async fn list(
+		&self,
+		_user_name: Option<&str>,
+		_option: Option<ListOptions>,
+	) -> Result<Vec<GistInfo>> {
+		Err(Error::unsupported("CNB", "gists"))
+	}
+
+	async fn info(&self, _id: &str) -> Result<GistInfo> {
+		Err(Error::unsupported("CNB", "gists"))
+	}
+
+	async fn create(&self, _option: CreateOptions) -> Result<GistInfo> {
+		Err(Error::unsupported("CNB", "gists"))
+	}
+
+	async fn update(&self, _id: &str, _option: UpdateOptions) -> Result<GistInfo> {
+		Err(Error::unsupported("CNB", "gists"))
+	}
+
+	async fn delete(&self, _id: &str) -> Result<()> {
+		Err(Error::unsupported("CNB", "gists"))
+	}
+
+	async fn star(&self, _id: &str) -> Result<()> {
+		Err(Error::unsupported("CNB", "gists"))
+	}
+
+	async fn unstar(&self, _id: &str) -> Result<()> {
+		Err(Error::unsupported("CNB", "gists"))
+	}
+
+	async fn fork(&self, _id: &str) -> Result<GistInfo> {
+		Err(Error::unsupported("CNB", "gists"))
+	}
+}
@@ -0,0 +1,79 @@
+use async_trait::async_trait;
+use nipaw_core::option::git::{FileChange, TreeEntryOptions};
+use nipaw_core::types::git::{GitCommit, GitRef, GitTree};
+use nipaw_core::types::repo::RepoPath;
+use nipaw_core::{Error, Git, Result};
+
+/// CNB未开放底层Git数据的接口
+pub struct CnbGit;
+
+#[async_trait]
+impl Git for CnbGit {
+	async fn get_ref(&self, _repo_path: RepoPath, _reference: &str) -> Result<GitRef> {
+		Err(Error::unsupported("CNB", "git refs"))
+	}
+
+	async fn create_ref(
+		&self,
+		_repo_path: RepoPath,
+		_reference: &str,
+		_sha: &str,
+	) -> Result<GitRef> {
+		Err(Error::unsupported("CNB", "git refs"))
+	}
+
+	async fn update_ref(
+		&self,
+		_repo_path: RepoPath,
+		_reference: &str,
+		_sha: &str,
+		_force: bool,
+	) -> Result<GitRef> {
+		Err(Error::unsupported("CNB", "git refs"))
+	}
+
+	async fn delete_ref(&self, _repo_path: RepoPath, _reference: &str) -> Result<()> {
+		Err(Error::unsupported("CNB", "git refs"))
+	}
+
+	async fn tree(&self, _repo_path: RepoPath, _sha: &str, _recursive: bool) -> Result<GitTree> {
+		Err(Error::unsupported("CNB", "git trees"))
+	}
+
+	async fn create_blob(&self, _repo_path: RepoPath, _content: &[u8]) -> Result<String> {
+		Err(Error::unsupported("CNB", "git blobs"))
+	}
+
+	async fn create_tree(
+		&self,
+		_repo_path: RepoPath,
+		_base_tree: Option<&str>,
+		_entries: Vec<TreeEntryOptions>,
+	) -> Result<GitTree> {
+		Err(Error::unsupported("CNB", "git trees"))
+	}
+
+	async fn get_commit(&self, _repo_path: RepoPath, _sha: &str) -> Result<GitCommit> {
+		Err(Error::unsupported("CNB", "git commits"))
+	}
+
+	async fn create_commit(
+		&self,
+		_repo_path: RepoPath,
+		_message: &str,
+		_tree: &str,
+		_parents: Vec<String>,
+	) -> Result<GitCommit> {
+		Err(Error::unsupported("CNB", "git commits"))
+	}
+
+	async fn commit_files(
+		&self,
+		_repo_path: RepoPath,
+		_branch: &str,
+		_message: &str,
+		_changes: Vec<FileChange>,
+	) -> Result<GitCommit> {
+		Err(Error::unsupported("CNB", "multi-file commits"))
+	}
+}
@@ -2,15 +2,53 @@ use crate::common::JsonValue;
 use async_trait::async_trait;
 use crate::{CnbClientInner, get_user_info};
 use futures::future::join_all;
-use nipaw_core::option::issue::{CreateOptions, ListOptions, UpdateOptions};
-use nipaw_core::types::issue::{IssueInfo, StateType};
+use nipaw_core::option::issue::{CreateOptions, EventListOptions, ListOptions, UpdateOptions};
+use nipaw_core::types::issue::{IssueEventInfo, IssueInfo, StateType, TimelineItem};
 use nipaw_core::types::repo::RepoPath;
 use nipaw_core::{Error, Issue, Result};
+use reqwest::Method;
 use std::collections::HashMap;
 use std::sync::Arc;
 
+/// CNB未开放议题事件与锁定的接口, 时间线只包含评论
 pub struct CnbIssue(pub(crate) Arc<CnbClientInner>);
 
+impl CnbIssue {
+	/// 接口不返回议题网页地址, 由仓库路径与编号拼接
+	fn with_html_url(&self, repo_path: &RepoPath, mut issue_info: IssueInfo) -> IssueInfo {
+		issue_info.html_url = Some(format!(
+			"{}/{}/{}/-/issues/{}",
+			self.0.config.base_url, repo_path.owner, repo_path.repo, issue_info.number
+		));
+		issue_info
+	}
+
+	/// 添加或移除负责人, 完成后重新获取议题信息
+	async fn change_assignees(
+		&self,
+		method: Method,
+		repo_path: RepoPath,
+		issue_number: &str,
+		assignees: Vec<String>,
+	) -> Result<IssueInfo> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let url = format!(
+			"{}/{}/{}/-/issues/{}/assignees",
+			api_url, repo_path.owner, repo_path.repo, issue_number
+		);
+		{
+			let client = self.0.client.read().await;
+			let request = client.request(method, url).bearer_auth(token.as_ref().unwrap());
+			let req_body = serde_json::json!({ "assignees": assignees });
+			request.json(&req_body).send().await?.error_for_status()?;
+		}
+		self.info(repo_path, issue_number).await
+	}
+}
+
 #[async_trait]
 impl Issue for CnbIssue {
 	async fn create(
@@ -51,7 +89,7 @@ impl Issue for CnbIssue {
 			.unwrap_or_default();
 		let user_info = get_user_info(client.clone(), &self.0.config, &user_name).await?;
 		res.0.as_object_mut().unwrap().insert("user".to_string(), serde_json::to_value(user_info)?);
-		Ok(res.into())
+		Ok(self.with_html_url(&repo_path, res.into()))
 	}
 
 	async fn info(&self, repo_path: RepoPath, issue_number: &str) -> Result<IssueInfo> {
@@ -72,7 +110,7 @@ impl Issue for CnbIssue {
 			.unwrap_or_default();
 		let user_info = get_user_info(client.clone(), &self.0.config, &user_name).await?;
 		res.0.as_object_mut().unwrap().insert("user".to_string(), serde_json::to_value(user_info)?);
-		Ok(res.into())
+		Ok(self.with_html_url(&repo_path, res.into()))
 	}
 
 	async fn list(
@@ -137,7 +175,7 @@ impl Issue for CnbIssue {
 		}))
 		.await
 		.into_iter()
-		.map(|v| v.into())
+		.map(|v| self.with_html_url(&repo_path, v.into()))
 		.collect::<Vec<IssueInfo>>();
 		Ok(issues)
 	}
@@ -181,6 +219,69 @@ impl Issue for CnbIssue {
 			.unwrap_or_default();
 		let user_info = get_user_info(client.clone(), &self.0.config, &user_name).await?;
 		res.0.as_object_mut().unwrap().insert("user".to_string(), serde_json::to_value(user_info)?);
-		Ok(res.into())
+		Ok(self.with_html_url(&repo_path, res.into()))
+	}
+
+	async fn events(
+		&self,
+		_repo_path: RepoPath,
+		_issue_number: &str,
+		_options: Option<EventListOptions>,
+	) -> Result<Vec<IssueEventInfo>> {
+		Err(Error::unsupported("CNB", "issue events"))
+	}
+
+	async fn timeline(
+		&self,
+		repo_path: RepoPath,
+		issue_number: &str,
+		options: Option<EventListOptions>,
+	) -> Result<Vec<TimelineItem>> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let url = format!(
+			"{}/{}/{}/-/issues/{}/comments",
+			api_url, repo_path.owner, repo_path.repo, issue_number
+		);
+		let options = options.unwrap_or_default();
+		let page_size = options.per_page.unwrap_or(30).min(100);
+		let page = options.page.unwrap_or(1);
+		let client = self.0.client.read().await;
+		let request = client.get(url).bearer_auth(token.as_ref().unwrap());
+		let res = request
+			.query(&[("page_size", page_size), ("page", page)])
+			.send()
+			.await?
+			.json::<Vec<JsonValue>>()
+			.await?;
+		Ok(res.into_iter().map(|v| TimelineItem::Comment(v.into())).collect())
+	}
+
+	async fn add_assignees(
+		&self,
+		repo_path: RepoPath,
+		issue_number: &str,
+		assignees: Vec<String>,
+	) -> Result<IssueInfo> {
+		self.change_assignees(Method::POST, repo_path, issue_number, assignees).await
+	}
+
+	async fn remove_assignees(
+		&self,
+		repo_path: RepoPath,
+		issue_number: &str,
+		assignees: Vec<String>,
+	) -> Result<IssueInfo> {
+		self.change_assignees(Method::DELETE, repo_path, issue_number, assignees).await
+	}
+
+	async fn lock(&self, _repo_path: RepoPath, _issue_number: &str) -> Result<()> {
+		Err(Error::unsupported("CNB", "issue lock"))
+	}
+
+	async fn unlock(&self, _repo_path: RepoPath, _issue_number: &str) -> Result<()> {
+		Err(Error::unsupported("CNB", "issue lock"))
 	}
 }
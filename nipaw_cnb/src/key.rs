@@ -0,0 +1,34 @@
+use async_trait::async_trait;
+use nipaw_core::option::repo::ListOptions;
+use nipaw_core::types::key::{GpgKeyInfo, SshKeyInfo};
+use nipaw_core::{Error, Key, Result};
+
+/// CNB未开放公钥管理接口
+pub struct CnbKey;
+
+#[async_trait]
+impl Key for CnbKey {
+	async fn ssh_keys(&self, _option: Option<ListOptions>) -> Result<Vec<SshKeyInfo>> {
+		Err(Error::unsupported("CNB", "ssh_keys"))
+	}
+
+	async fn add_ssh_key(&self, _title: &str, _key: &str) -> Result<SshKeyInfo> {
+		Err(Error::unsupported("CNB", "add_ssh_key"))
+	}
+
+	async fn delete_ssh_key(&self, _id: u64) -> Result<()> {
+		Err(Error::unsupported("CNB", "delete_ssh_key"))
+	}
+
+	async fn gpg_keys(&self, _option: Option<ListOptions>) -> Result<Vec<GpgKeyInfo>> {
+		Err(Error::unsupported("CNB", "gpg_keys"))
+	}
+
+	async fn add_gpg_key(&self, _title: Option<&str>, _key: &str) -> Result<GpgKeyInfo> {
+		Err(Error::unsupported("CNB", "add_gpg_key"))
+	}
+
+	async fn delete_gpg_key(&self, _id: u64) -> Result<()> {
+		Err(Error::unsupported("CNB", "delete_gpg_key"))
+	}
+}
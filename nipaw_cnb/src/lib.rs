@@ -1,24 +1,48 @@
 mod commit;
 mod common;
+mod gist;
+mod git;
 mod issue;
+mod key;
 mod middleware;
+mod notification;
 mod org;
+mod pipeline;
 mod release;
 mod repo;
+mod search;
+mod secret;
+mod stats;
+mod status;
+mod traffic;
 mod user;
 
 use crate::{
 	commit::CnbCommit,
 	common::JsonValue,
+	gist::CnbGist,
+	git::CnbGit,
 	issue::CnbIssue,
+	key::CnbKey,
 	middleware::{HeaderMiddleware, ResponseMiddleware},
+	notification::CnbNotifications,
 	org::CnbOrg,
+	pipeline::CnbPipeline,
 	release::CnbRelease,
 	repo::CnbRepo,
+	search::CnbSearch,
+	secret::CnbSecret,
+	stats::CnbStats,
+	status::CnbStatus,
+	traffic::CnbTraffic,
 	user::CnbUser,
 };
 use nipaw_core::types::user::UserInfo;
-pub use nipaw_core::{Client, Commit, Config, Error, Issue, Org, Provider, Release, Repo, Result, Token, User};
+use nipaw_core::stats::CommitStats;
+pub use nipaw_core::{
+	Client, Commit, Config, Error, Gist, Git, Issue, Key, Notifications, Org, Pipeline, Provider,
+	Release, Repo, Result, Search, Secret, Stats, Status, Token, Traffic, User,
+};
 use reqwest_middleware::{ClientBuilder, ClientWithMiddleware};
 use serde_json::Value;
 use std::sync::Arc;
@@ -109,6 +133,16 @@ impl Provider for CnbClient {
 	type Commit = CnbCommit;
 	type Issue = CnbIssue;
 	type Release = CnbRelease;
+	type Status = CnbStatus;
+	type Search = CnbSearch;
+	type Key = CnbKey;
+	type Stats = CnbStats;
+	type Traffic = CnbTraffic;
+	type Notifications = CnbNotifications;
+	type Gist = CnbGist;
+	type Pipeline = CnbPipeline;
+	type Secret = CnbSecret;
+	type Git = CnbGit;
 
 	fn user(&self) -> CnbUser {
 		CnbUser(self.inner.clone())
@@ -133,6 +167,46 @@ impl Provider for CnbClient {
 	fn release(&self) -> CnbRelease {
 		CnbRelease(self.inner.clone())
 	}
+
+	fn status(&self) -> CnbStatus {
+		CnbStatus(self.inner.clone())
+	}
+
+	fn search(&self) -> CnbSearch {
+		CnbSearch(self.inner.clone())
+	}
+
+	fn key(&self) -> CnbKey {
+		CnbKey
+	}
+
+	fn stats(&self) -> CnbStats {
+		CommitStats(CnbCommit(self.inner.clone()))
+	}
+
+	fn traffic(&self) -> CnbTraffic {
+		CnbTraffic
+	}
+
+	fn notifications(&self) -> CnbNotifications {
+		CnbNotifications
+	}
+
+	fn gist(&self) -> CnbGist {
+		CnbGist
+	}
+
+	fn pipeline(&self) -> CnbPipeline {
+		CnbPipeline(self.inner.clone())
+	}
+
+	fn secret(&self) -> CnbSecret {
+		CnbSecret
+	}
+
+	fn git(&self) -> CnbGit {
+		CnbGit
+	}
 }
 
 pub(crate) async fn get_repo_default_branch(
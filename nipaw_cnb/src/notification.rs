@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+use nipaw_core::option::notification::ListOptions;
+use nipaw_core::types::notification::NotificationInfo;
+use nipaw_core::types::repo::RepoPath;
+use nipaw_core::{Error, Notifications, Result};
+
+/// CNB的开放接口没有站内通知, 所有方法均返回[Error::Unsupported]
+pub struct CnbNotifications;
+
+#[async_trait]
+impl Notifications for CnbNotifications {
+	async fn list(&self, _option: Option<ListOptions>) -> Result<Vec<NotificationInfo>> {
+		Err(Error::unsupported("CNB", "notifications"))
+	}
+
+	async fn mark_read(&self, _thread_id: &str) -> Result<()> {
+		Err(Error::unsupported("CNB", "notifications"))
+	}
+
+	async fn mark_all_read(&self, _repo_path: Option<RepoPath>) -> Result<()> {
+		Err(Error::unsupported("CNB", "notifications"))
+	}
+
+	async fn subscribe(&self, _thread_id: &str) -> Result<()> {
+		Err(Error::unsupported("CNB", "notifications"))
+	}
+
+	async fn unsubscribe(&self, _thread_id: &str) -> Result<()> {
+		Err(Error::unsupported("CNB", "notifications"))
+	}
+}
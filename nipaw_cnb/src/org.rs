@@ -2,14 +2,54 @@ use crate::CnbClientInner;
 use async_trait::async_trait;
 use crate::common::JsonValue;
 use nipaw_core::option::repo::ListOptions;
-use nipaw_core::types::{org::OrgInfo, repo::RepoInfo};
+use nipaw_core::types::{
+	org::{MemberRole, OrgInfo, OrgMember, TeamInfo, TeamRepoInfo},
+	repo::RepoInfo,
+	user::UserInfo,
+};
 use nipaw_core::{Error, Org, Result};
+use reqwest::Method;
 use serde_json::Value;
 use std::collections::HashMap;
 use std::sync::Arc;
 
+/// CNB的组织以子组织代替团队, 团队相关方法均返回[Error::Unsupported]
 pub struct CnbOrg(pub(crate) Arc<CnbClientInner>);
 
+impl CnbOrg {
+	/// 发送不关心响应内容的请求, 需要登录
+	async fn send_empty(&self, method: Method, url: String, body: Option<Value>) -> Result<()> {
+		let token = &self.0.config.token;
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let client = self.0.client.read().await;
+		let mut request = client.request(method, url).bearer_auth(token.as_ref().unwrap());
+		if let Some(body) = body {
+			request = request.json(&body);
+		}
+		request.send().await?.error_for_status()?;
+		Ok(())
+	}
+
+	/// 获取组织成员列表
+	async fn get_members(
+		&self,
+		org_name: &str,
+		params: HashMap<&str, String>,
+	) -> Result<Vec<OrgMember>> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let url = format!("{}/{}/-/members", api_url, org_name);
+		let client = self.0.client.read().await;
+		let request = client.get(url).bearer_auth(token.as_ref().unwrap());
+		let members = request.query(&params).send().await?.json::<Vec<JsonValue>>().await?;
+		Ok(members.into_iter().map(|v| v.into()).collect())
+	}
+}
+
 #[async_trait]
 impl Org for CnbOrg {
 	async fn info(&self, org_name: &str) -> Result<OrgInfo> {
@@ -70,4 +110,88 @@ impl Org for CnbOrg {
 		let url = format!("{}/{}/-/logos/l", base_url, org_name);
 		Ok(url)
 	}
+
+	async fn members(
+		&self,
+		org_name: &str,
+		options: Option<ListOptions>,
+	) -> Result<Vec<OrgMember>> {
+		let mut params: HashMap<&str, String> = HashMap::new();
+		if let Some(option) = options {
+			let per_page = option.per_page.unwrap_or(30).min(100);
+			params.insert("page_size", per_page.to_string());
+			let page = option.page.unwrap_or(1);
+			params.insert("page", page.to_string());
+		}
+		self.get_members(org_name, params).await
+	}
+
+	async fn add_member(
+		&self,
+		org_name: &str,
+		user_name: &str,
+		role: Option<MemberRole>,
+	) -> Result<()> {
+		let url = format!("{}/{}/-/members/{}", self.0.config.api_url, org_name, user_name);
+		let access_level = match role {
+			Some(MemberRole::Admin) => "Master",
+			_ => "Developer",
+		};
+		let body = serde_json::json!({ "access_level": access_level });
+		self.send_empty(Method::POST, url, Some(body)).await
+	}
+
+	async fn remove_member(&self, org_name: &str, user_name: &str) -> Result<()> {
+		let url = format!("{}/{}/-/members/{}", self.0.config.api_url, org_name, user_name);
+		self.send_empty(Method::DELETE, url, None).await
+	}
+
+	async fn is_member(&self, org_name: &str, user_name: &str) -> Result<bool> {
+		// 没有单独查询成员的接口, 通过搜索成员列表判断
+		let mut params: HashMap<&str, String> = HashMap::new();
+		params.insert("search", user_name.to_string());
+		params.insert("page_size", "100".to_string());
+		let members = self.get_members(org_name, params).await?;
+		Ok(members.iter().any(|member| member.login == user_name))
+	}
+
+	async fn teams(&self, _org_name: &str, _options: Option<ListOptions>) -> Result<Vec<TeamInfo>> {
+		Err(Error::unsupported("CNB", "teams"))
+	}
+
+	async fn team_members(
+		&self,
+		_org_name: &str,
+		_team_slug: &str,
+		_options: Option<ListOptions>,
+	) -> Result<Vec<UserInfo>> {
+		Err(Error::unsupported("CNB", "teams"))
+	}
+
+	async fn add_team_member(
+		&self,
+		_org_name: &str,
+		_team_slug: &str,
+		_user_name: &str,
+	) -> Result<()> {
+		Err(Error::unsupported("CNB", "teams"))
+	}
+
+	async fn remove_team_member(
+		&self,
+		_org_name: &str,
+		_team_slug: &str,
+		_user_name: &str,
+	) -> Result<()> {
+		Err(Error::unsupported("CNB", "teams"))
+	}
+
+	async fn team_repos(
+		&self,
+		_org_name: &str,
+		_team_slug: &str,
+		_options: Option<ListOptions>,
+	) -> Result<Vec<TeamRepoInfo>> {
+		Err(Error::unsupported("CNB", "teams"))
+	}
 }
@@ -0,0 +1,182 @@
+use crate::CnbClientInner;
+use crate::common::JsonValue;
+use async_trait::async_trait;
+use nipaw_core::option::pipeline::{ListOptions, TriggerOptions};
+use nipaw_core::types::pipeline::{PipelineJob, PipelineRun, PipelineStatus};
+use nipaw_core::types::release::DownloadResult;
+use nipaw_core::types::repo::RepoPath;
+use nipaw_core::{Error, Pipeline, Result, download};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::io::AsyncWrite;
+
+pub struct CnbPipeline(pub(crate) Arc<CnbClientInner>);
+
+impl CnbPipeline {
+	/// 获取构建列表, 需要登录
+	async fn build_logs(
+		&self,
+		repo_path: &RepoPath,
+		params: HashMap<&str, String>,
+	) -> Result<Vec<PipelineRun>> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let url = format!("{}/{}/{}/-/build/logs", api_url, repo_path.owner, repo_path.repo);
+		let client = self.0.client.read().await;
+		let request = client.get(url).bearer_auth(token.as_ref().unwrap());
+		let res =
+			request.query(&params).send().await?.error_for_status()?.json::<JsonValue>().await?;
+		let builds = res.0.get("data").cloned().unwrap_or_default();
+		Ok(JsonValue(builds).into_iter().map(|v| v.into()).collect())
+	}
+
+	/// 获取构建中各流水线的状态, 需要登录
+	async fn build_status(&self, repo_path: &RepoPath, sn: &str) -> Result<Vec<PipelineJob>> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let url =
+			format!("{}/{}/{}/-/build/status/{}", api_url, repo_path.owner, repo_path.repo, sn);
+		let client = self.0.client.read().await;
+		let request = client.get(url).bearer_auth(token.as_ref().unwrap());
+		let res = request.send().await?.error_for_status()?.json::<JsonValue>().await?;
+		let pipelines = res.0.get("pipelinesStatus").cloned().unwrap_or_default();
+		let pipelines = match pipelines {
+			serde_json::Value::Object(map) => map
+				.into_iter()
+				.map(|(id, mut pipeline)| {
+					if pipeline.get("id").is_none() {
+						pipeline["id"] = id.into();
+					}
+					pipeline
+				})
+				.collect(),
+			serde_json::Value::Array(list) => list,
+			_ => Vec::new(),
+		};
+		Ok(pipelines.into_iter().map(|v| JsonValue(v).into()).collect())
+	}
+}
+
+/// 将状态转换为构建列表接口的`status`筛选值
+fn status_filter(status: PipelineStatus) -> Result<&'static str> {
+	Ok(match status {
+		PipelineStatus::Queued | PipelineStatus::Waiting => "pending",
+		PipelineStatus::Running => "running",
+		PipelineStatus::Success => "success",
+		PipelineStatus::Failure => "error",
+		PipelineStatus::Cancelled => "cancel",
+		PipelineStatus::Skipped => "skip",
+		PipelineStatus::Unknown => {
+			return Err(Error::InvalidParam {
+				param: "status".to_string(),
+				reason: "unknown status cannot be used as a filter".to_string(),
+			});
+		}
+	})
+}
+
+#[async_trait]
+impl Pipeline for CnbPipeline {
+	async fn list(
+		&self,
+		repo_path: RepoPath,
+		option: Option<ListOptions>,
+	) -> Result<Vec<PipelineRun>> {
+		let option = option.unwrap_or_default();
+		let mut params: HashMap<&str, String> = HashMap::new();
+		let per_page = option.per_page.unwrap_or(30).min(100);
+		params.insert("pagesize", per_page.to_string());
+		let page = option.page.unwrap_or(1);
+		params.insert("page", page.to_string());
+		if let Some(branch) = option.branch {
+			params.insert("sourceRef", branch);
+		}
+		if let Some(status) = option.status {
+			params.insert("status", status_filter(status)?.to_string());
+		}
+		if let Some(event) = option.event {
+			params.insert("event", event);
+		}
+		self.build_logs(&repo_path, params).await
+	}
+
+	async fn info(&self, repo_path: RepoPath, run_id: &str) -> Result<PipelineRun> {
+		let params = HashMap::from([("sn", run_id.to_string())]);
+		let mut run = self.build_logs(&repo_path, params).await?.into_iter().next().ok_or(
+			Error::InvalidParam {
+				param: "run_id".to_string(),
+				reason: format!("build {} not found", run_id),
+			},
+		)?;
+		run.jobs = self.build_status(&repo_path, run_id).await?;
+		Ok(run)
+	}
+
+	async fn rerun(&self, _repo_path: RepoPath, _run_id: &str) -> Result<()> {
+		// CNB只能通过触发新的构建重新运行
+		Err(Error::unsupported("CNB", "rerunning build"))
+	}
+
+	async fn cancel(&self, repo_path: RepoPath, run_id: &str) -> Result<()> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let url =
+			format!("{}/{}/{}/-/build/stop/{}", api_url, repo_path.owner, repo_path.repo, run_id);
+		let client = self.0.client.read().await;
+		let request = client.post(url).bearer_auth(token.as_ref().unwrap());
+		request.send().await?.error_for_status()?;
+		Ok(())
+	}
+
+	async fn trigger(
+		&self,
+		repo_path: RepoPath,
+		workflow: &str,
+		option: TriggerOptions,
+	) -> Result<Option<String>> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let url = format!("{}/{}/{}/-/build/start", api_url, repo_path.owner, repo_path.repo);
+		let client = self.0.client.read().await;
+		let request = client.post(url).bearer_auth(token.as_ref().unwrap());
+		let body = serde_json::json!({
+			"branch": option.reference,
+			"event": workflow,
+			"env": option.inputs,
+		});
+		let res = request.json(&body).send().await?.json::<JsonValue>().await?;
+		Ok(res.0.get("sn").and_then(|v| v.as_str()).map(|s| s.to_string()))
+	}
+
+	async fn download_logs(
+		&self,
+		repo_path: RepoPath,
+		run_id: &str,
+		writer: &mut (dyn AsyncWrite + Unpin + Send),
+	) -> Result<DownloadResult> {
+		let jobs = self.build_status(&repo_path, run_id).await?;
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		let client = self.0.client.read().await;
+		let mut result = DownloadResult { written: 0, size: 0, resumed: false, sha256: None };
+		// 各流水线的日志分别下载, 按顺序写入同一目标
+		for job in jobs {
+			let url = format!(
+				"{}/{}/{}/-/build/runner/download/log/{}",
+				api_url, repo_path.owner, repo_path.repo, job.id
+			);
+			let request = client.get(url).bearer_auth(token.as_ref().unwrap());
+			let part = download::to_writer(request, writer, &Default::default(), None).await?;
+			result.written += part.written;
+			result.size += part.size;
+		}
+		Ok(result)
+	}
+}
@@ -1,14 +1,60 @@
+use crate::commit::CnbCommit;
 use crate::common::JsonValue;
 use async_trait::async_trait;
+use crate::user::CnbUser;
 use crate::{CnbClientInner, get_repo_default_branch};
-use nipaw_core::types::repo::{CollaboratorPermission, CollaboratorResult, RepoInfo, RepoPath};
-use nipaw_core::{Error, Repo, Result};
-use reqwest::header;
+use nipaw_core::option::repo::{CreateOptions, ListOptions, UpdateOptions};
+use nipaw_core::types::key::DeployKeyInfo;
+use nipaw_core::types::release::DownloadResult;
+use nipaw_core::types::repo::{
+	ArchiveFormat, CollaboratorInfo, CollaboratorPermission, CollaboratorResult, ContributorInfo,
+	RepoInfo, RepoLanguages, RepoPath, SyncForkResult, Visibility,
+};
+use nipaw_core::types::user::UserInfo;
+use nipaw_core::{Error, Repo, Result, User, download, stats};
+use reqwest::{Method, header};
 use serde_json::Value;
+use std::collections::HashMap;
 use std::sync::Arc;
+use tokio::io::AsyncWrite;
 
+/// CNB未开放星标、关注、复刻与部署公钥等仓库接口
 pub struct CnbRepo(pub(crate) Arc<CnbClientInner>);
 
+impl CnbRepo {
+	/// 发送不关心响应内容的请求, 需要登录
+	async fn send_empty(&self, method: Method, url: String, body: Option<Value>) -> Result<()> {
+		let token = &self.0.config.token;
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let client = self.0.client.read().await;
+		let mut request = client.request(method, url).bearer_auth(token.as_ref().unwrap());
+		if let Some(body) = body {
+			request = request.json(&body);
+		}
+		request.send().await?.error_for_status()?;
+		Ok(())
+	}
+
+	/// 获取仓库成员列表
+	async fn get_members(
+		&self,
+		repo_path: &RepoPath,
+		params: HashMap<&str, String>,
+	) -> Result<Vec<CollaboratorInfo>> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let url = format!("{}/{}/{}/-/members", api_url, repo_path.owner, repo_path.repo);
+		let client = self.0.client.read().await;
+		let request = client.get(url).bearer_auth(token.as_ref().unwrap());
+		let members = request.query(&params).send().await?.json::<Vec<JsonValue>>().await?;
+		Ok(members.into_iter().map(|v| v.into()).collect())
+	}
+}
+
 #[async_trait]
 impl Repo for CnbRepo {
 	async fn info(&self, repo_path: RepoPath) -> Result<RepoInfo> {
@@ -54,6 +100,12 @@ impl Repo for CnbRepo {
 				CollaboratorPermission::Admin => "Master",
 				CollaboratorPermission::Push => "Developer",
 				CollaboratorPermission::Pull => "Reporter",
+				CollaboratorPermission::None => {
+					return Err(Error::InvalidParam {
+						param: "permission".to_string(),
+						reason: "cannot add a collaborator without access".to_string(),
+					});
+				}
 			},
 			None => "Guest",
 		};
@@ -73,4 +125,337 @@ impl Repo for CnbRepo {
 		};
 		Ok(collaborator)
 	}
+
+	async fn star(&self, _repo_path: RepoPath) -> Result<()> {
+		Err(Error::unsupported("CNB", "starring repositories"))
+	}
+
+	async fn unstar(&self, _repo_path: RepoPath) -> Result<()> {
+		Err(Error::unsupported("CNB", "unstarring repositories"))
+	}
+
+	async fn is_starred(&self, repo_path: RepoPath) -> Result<bool> {
+		// 没有单独的检查接口, 逐页查找当前用户的星标仓库
+		let full_name = format!("{}/{}", repo_path.owner, repo_path.repo);
+		let user = CnbUser(self.0.clone());
+		for page in 1.. {
+			let option = ListOptions { per_page: Some(100), page: Some(page) };
+			let repos = user.starred_repos(None, Some(option)).await?;
+			if repos.iter().any(|r| r.full_name.eq_ignore_ascii_case(&full_name)) {
+				return Ok(true);
+			}
+			if repos.len() < 100 {
+				break;
+			}
+		}
+		Ok(false)
+	}
+
+	async fn stargazers(
+		&self,
+		repo_path: RepoPath,
+		option: Option<ListOptions>,
+	) -> Result<Vec<UserInfo>> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let url = format!("{}/{}/{}/-/stars", api_url, repo_path.owner, repo_path.repo);
+		let client = self.0.client.read().await;
+		let request = client.get(url).bearer_auth(token.as_ref().unwrap());
+		let mut params: HashMap<&str, String> = HashMap::new();
+		if let Some(option) = option {
+			let per_page = option.per_page.unwrap_or(30).min(100);
+			params.insert("page_size", per_page.to_string());
+			let page = option.page.unwrap_or(1);
+			params.insert("page", page.to_string());
+		}
+		let res = request.query(&params).send().await?.json::<JsonValue>().await?;
+		// 返回值为`{ total, users }`
+		let user_infos = match res.0 {
+			Value::Object(mut obj) => obj.remove("users").unwrap_or_default(),
+			value => value,
+		};
+		Ok(JsonValue(user_infos).into_iter().map(|v| v.into()).collect())
+	}
+
+	async fn watch(&self, _repo_path: RepoPath) -> Result<()> {
+		Err(Error::unsupported("CNB", "watching repositories"))
+	}
+
+	async fn unwatch(&self, _repo_path: RepoPath) -> Result<()> {
+		Err(Error::unsupported("CNB", "unwatching repositories"))
+	}
+
+	async fn watchers(
+		&self,
+		_repo_path: RepoPath,
+		_option: Option<ListOptions>,
+	) -> Result<Vec<UserInfo>> {
+		Err(Error::unsupported("CNB", "listing watchers"))
+	}
+
+	async fn fork(
+		&self,
+		_repo_path: RepoPath,
+		_target_org: Option<&str>,
+		_name: Option<&str>,
+	) -> Result<RepoInfo> {
+		Err(Error::unsupported("CNB", "forking repositories"))
+	}
+
+	async fn forks(
+		&self,
+		repo_path: RepoPath,
+		option: Option<ListOptions>,
+	) -> Result<Vec<RepoInfo>> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let url = format!("{}/{}/{}/-/forks", api_url, repo_path.owner, repo_path.repo);
+		let client = self.0.client.read().await;
+		let request = client.get(url).bearer_auth(token.as_ref().unwrap());
+		let mut params: HashMap<&str, String> = HashMap::new();
+		if let Some(option) = option {
+			let per_page = option.per_page.unwrap_or(30).min(100);
+			params.insert("page_size", per_page.to_string());
+			let page = option.page.unwrap_or(1);
+			params.insert("page", page.to_string());
+		}
+		let res = request.query(&params).send().await?.json::<JsonValue>().await?;
+		// 返回值为`{ total, forks }`
+		let repo_infos = match res.0 {
+			Value::Object(mut obj) => obj.remove("forks").unwrap_or_default(),
+			value => value,
+		};
+		Ok(JsonValue(repo_infos).into_iter().map(|v| v.into()).collect())
+	}
+
+	async fn sync_fork(
+		&self,
+		_repo_path: RepoPath,
+		_branch: Option<&str>,
+	) -> Result<SyncForkResult> {
+		Err(Error::unsupported("CNB", "syncing forks with upstream"))
+	}
+
+	async fn create(
+		&self,
+		org_name: Option<&str>,
+		name: &str,
+		option: Option<CreateOptions>,
+	) -> Result<RepoInfo> {
+		let api_url = &self.0.config.api_url;
+		let Some(org_name) = org_name else {
+			return Err(Error::InvalidParam {
+				param: "org_name".to_string(),
+				reason: "CNB repositories must be created within an organization".to_string(),
+			});
+		};
+		let option = option.unwrap_or_default();
+		let visibility = match option.visibility {
+			Some(Visibility::Private) => "private",
+			Some(Visibility::Internal) => {
+				return Err(Error::unsupported("CNB", "internal visibility"));
+			}
+			_ => "public",
+		};
+		let mut body = serde_json::json!({ "name": name, "visibility": visibility });
+		if let Some(description) = option.description {
+			body["description"] = description.into();
+		}
+		if let Some(license) = option.license_template {
+			body["license"] = license.into();
+		}
+		let url = format!("{}/{}/-/repos", api_url, org_name);
+		self.send_empty(Method::POST, url, Some(body)).await?;
+		self.info(RepoPath::new(org_name, name)).await
+	}
+
+	async fn update(&self, repo_path: RepoPath, option: UpdateOptions) -> Result<RepoInfo> {
+		let api_url = &self.0.config.api_url;
+		if option.name.is_some() || option.default_branch.is_some() || option.visibility.is_some() {
+			return Err(Error::unsupported(
+				"CNB",
+				"updating repository name, default branch or visibility",
+			));
+		}
+		if option.has_issues.is_some() || option.has_wiki.is_some() || option.has_projects.is_some()
+		{
+			return Err(Error::unsupported("CNB", "toggling repository features"));
+		}
+		let mut body = serde_json::json!({});
+		if let Some(description) = option.description {
+			body["description"] = description.into();
+		}
+		if let Some(homepage) = option.homepage {
+			body["site"] = homepage.into();
+		}
+		let url = format!("{}/{}/{}", api_url, repo_path.owner, repo_path.repo);
+		self.send_empty(Method::PATCH, url, Some(body)).await?;
+		self.info(repo_path).await
+	}
+
+	async fn delete(&self, repo_path: RepoPath) -> Result<()> {
+		let api_url = &self.0.config.api_url;
+		let url = format!("{}/{}/{}", api_url, repo_path.owner, repo_path.repo);
+		self.send_empty(Method::DELETE, url, None).await
+	}
+
+	async fn set_archived(&self, repo_path: RepoPath, archived: bool) -> Result<()> {
+		let api_url = &self.0.config.api_url;
+		let action = if archived { "archive" } else { "unarchive" };
+		let url =
+			format!("{}/{}/{}/-/settings/{}", api_url, repo_path.owner, repo_path.repo, action);
+		self.send_empty(Method::POST, url, None).await
+	}
+
+	async fn transfer(
+		&self,
+		repo_path: RepoPath,
+		new_owner: &str,
+		new_name: Option<&str>,
+	) -> Result<RepoInfo> {
+		let api_url = &self.0.config.api_url;
+		if new_name.is_some_and(|name| name != repo_path.repo) {
+			return Err(Error::unsupported("CNB", "renaming repositories while transferring"));
+		}
+		let url = format!("{}/{}/{}/-/transfer", api_url, repo_path.owner, repo_path.repo);
+		let body = serde_json::json!({ "source": repo_path.to_string(), "target": new_owner });
+		self.send_empty(Method::POST, url, Some(body)).await?;
+		self.info(RepoPath::new(new_owner, repo_path.repo)).await
+	}
+
+	async fn collaborators(
+		&self,
+		repo_path: RepoPath,
+		option: Option<ListOptions>,
+	) -> Result<Vec<CollaboratorInfo>> {
+		let mut params: HashMap<&str, String> = HashMap::new();
+		if let Some(option) = option {
+			let per_page = option.per_page.unwrap_or(30).min(100);
+			params.insert("page_size", per_page.to_string());
+			let page = option.page.unwrap_or(1);
+			params.insert("page", page.to_string());
+		}
+		self.get_members(&repo_path, params).await
+	}
+
+	async fn remove_collaborator(&self, repo_path: RepoPath, user_name: &str) -> Result<()> {
+		let api_url = &self.0.config.api_url;
+		let url =
+			format!("{}/{}/{}/-/members/{}", api_url, repo_path.owner, repo_path.repo, user_name);
+		self.send_empty(Method::DELETE, url, None).await
+	}
+
+	async fn collaborator_permission(
+		&self,
+		repo_path: RepoPath,
+		user_name: &str,
+	) -> Result<CollaboratorPermission> {
+		// 没有单独查询成员的接口, 通过搜索成员列表获取
+		let mut params: HashMap<&str, String> = HashMap::new();
+		params.insert("search", user_name.to_string());
+		params.insert("page_size", "100".to_string());
+		let members = self.get_members(&repo_path, params).await?;
+		members
+			.into_iter()
+			.find(|member| member.login == user_name)
+			.map(|member| member.permission)
+			.ok_or_else(|| Error::NotFound {
+				resource: format!("collaborator {} of {}", user_name, repo_path),
+			})
+	}
+
+	async fn is_collaborator(&self, repo_path: RepoPath, user_name: &str) -> Result<bool> {
+		match self.collaborator_permission(repo_path, user_name).await {
+			Ok(_) => Ok(true),
+			Err(err) if err.is_not_found() => Ok(false),
+			Err(err) => Err(err),
+		}
+	}
+
+	async fn deploy_keys(
+		&self,
+		_repo_path: RepoPath,
+		_option: Option<ListOptions>,
+	) -> Result<Vec<DeployKeyInfo>> {
+		Err(Error::unsupported("CNB", "deploy keys"))
+	}
+
+	async fn add_deploy_key(
+		&self,
+		_repo_path: RepoPath,
+		_title: &str,
+		_key: &str,
+		_read_only: bool,
+	) -> Result<DeployKeyInfo> {
+		Err(Error::unsupported("CNB", "deploy keys"))
+	}
+
+	async fn remove_deploy_key(&self, _repo_path: RepoPath, _id: u64) -> Result<()> {
+		Err(Error::unsupported("CNB", "deploy keys"))
+	}
+
+	async fn topics(&self, repo_path: RepoPath) -> Result<Vec<String>> {
+		let repo_info = self.info(repo_path).await?;
+		Ok(repo_info.topics)
+	}
+
+	async fn set_topics(&self, repo_path: RepoPath, topics: Vec<String>) -> Result<Vec<String>> {
+		let api_url = &self.0.config.api_url;
+		let url = format!("{}/{}/{}", api_url, repo_path.owner, repo_path.repo);
+		let body = serde_json::json!({ "topics": topics });
+		self.send_empty(Method::PATCH, url, Some(body)).await?;
+		self.topics(repo_path).await
+	}
+
+	async fn languages(&self, repo_path: RepoPath) -> Result<RepoLanguages> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let url = format!("{}/{}/{}/-/languages", api_url, repo_path.owner, repo_path.repo);
+		let client = self.0.client.read().await;
+		let request = client.get(url).bearer_auth(token.as_ref().unwrap());
+		let res = request.send().await?.json::<JsonValue>().await?;
+		Ok(res.into())
+	}
+
+	async fn contributors(
+		&self,
+		repo_path: RepoPath,
+		option: Option<ListOptions>,
+	) -> Result<Vec<ContributorInfo>> {
+		// CNB没有贡献者接口, 从全部提交中按作者统计
+		let commit = CnbCommit(self.0.clone());
+		let commits = stats::collect_commits(&commit, &repo_path, None, None).await?;
+		Ok(stats::paginate(stats::contributors(&commits), option))
+	}
+
+	async fn archive(
+		&self,
+		repo_path: RepoPath,
+		reference: &str,
+		format: ArchiveFormat,
+		writer: &mut (dyn AsyncWrite + Unpin + Send),
+	) -> Result<DownloadResult> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		// 压缩格式由引用后的扩展名决定
+		let url = format!(
+			"{}/{}/{}/-/git/archive/{}.{}",
+			api_url,
+			repo_path.owner,
+			repo_path.repo,
+			reference,
+			format.extension()
+		);
+		let client = self.0.client.read().await;
+		let mut request = client.get(url);
+		if let Some(token) = token {
+			request = request.bearer_auth(token);
+		}
+		download::to_writer(request, writer, &Default::default(), None).await
+	}
 }
@@ -0,0 +1,72 @@
+use crate::CnbClientInner;
+use crate::common::JsonValue;
+use async_trait::async_trait;
+use nipaw_core::option::search::{SearchOrder, SearchQuery, SearchSort};
+use nipaw_core::types::issue::IssueInfo;
+use nipaw_core::types::repo::RepoInfo;
+use nipaw_core::types::search::{CodeInfo, SearchResult};
+use nipaw_core::types::user::UserInfo;
+use nipaw_core::{Error, Result, Search};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// CNB没有全局搜索接口, 只支持在指定组织内搜索仓库
+pub struct CnbSearch(pub(crate) Arc<CnbClientInner>);
+
+#[async_trait]
+impl Search for CnbSearch {
+	async fn repos(&self, query: SearchQuery) -> Result<SearchResult<RepoInfo>> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let Some(owner) = query.owner.as_ref() else {
+			return Err(Error::InvalidParam {
+				param: "owner".to_string(),
+				reason: "CNB only supports searching repositories within an organization"
+					.to_string(),
+			});
+		};
+		let url = format!("{}/{}/-/repos", api_url, owner);
+		let client = self.0.client.read().await;
+		let request = client.get(url).bearer_auth(token.as_ref().unwrap());
+		let mut params: HashMap<&str, String> = HashMap::new();
+		params.insert("search", query.keywords_text());
+		let order_by = match query.sort {
+			Some(SearchSort::Stars) => Some("stars"),
+			Some(SearchSort::Forks) => Some("forks"),
+			Some(SearchSort::Created) => Some("created_at"),
+			Some(SearchSort::Updated) => Some("last_updated_at"),
+			_ => None,
+		};
+		if let Some(order_by) = order_by {
+			params.insert("order_by", order_by.to_string());
+			params.insert("desc", (query.order != Some(SearchOrder::Asc)).to_string());
+		}
+		let per_page = query.per_page.unwrap_or(30).min(100);
+		params.insert("page_size", per_page.to_string());
+		let page = query.page.unwrap_or(1);
+		params.insert("page", page.to_string());
+		let repo_infos: Vec<JsonValue> =
+			request.query(&params).send().await?.json::<Vec<JsonValue>>().await?;
+		let mut items: Vec<RepoInfo> = repo_infos.into_iter().map(|v| v.into()).collect();
+		if let Some(language) = query.language.as_ref() {
+			items.retain(|repo| {
+				repo.language.as_ref().is_some_and(|l| l.eq_ignore_ascii_case(language))
+			});
+		}
+		Ok(SearchResult { total_count: items.len() as u64, incomplete: false, items })
+	}
+
+	async fn issues(&self, _query: SearchQuery) -> Result<SearchResult<IssueInfo>> {
+		Err(Error::unsupported("CNB", "issue search"))
+	}
+
+	async fn users(&self, _query: SearchQuery) -> Result<SearchResult<UserInfo>> {
+		Err(Error::unsupported("CNB", "user search"))
+	}
+
+	async fn code(&self, _query: SearchQuery) -> Result<SearchResult<CodeInfo>> {
+		Err(Error::unsupported("CNB", "code search"))
+	}
+}
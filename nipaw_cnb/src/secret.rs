@@ -0,0 +1,47 @@
+use async_trait::async_trait;
+use nipaw_core::option::repo::ListOptions;
+use nipaw_core::types::secret::{SecretInfo, SecretScope, SecretValue, VariableInfo};
+use nipaw_core::{Error, Result, Secret};
+
+/// CNB未开放持续集成密钥与变量的接口
+pub struct CnbSecret;
+
+#[async_trait]
+impl Secret for CnbSecret {
+	async fn list_secrets(
+		&self,
+		_scope: SecretScope,
+		_option: Option<ListOptions>,
+	) -> Result<Vec<SecretInfo>> {
+		Err(Error::unsupported("CNB", "CI secrets"))
+	}
+
+	async fn set_secret(
+		&self,
+		_scope: SecretScope,
+		_name: &str,
+		_value: &SecretValue,
+	) -> Result<()> {
+		Err(Error::unsupported("CNB", "CI secrets"))
+	}
+
+	async fn delete_secret(&self, _scope: SecretScope, _name: &str) -> Result<()> {
+		Err(Error::unsupported("CNB", "CI secrets"))
+	}
+
+	async fn list_variables(
+		&self,
+		_scope: SecretScope,
+		_option: Option<ListOptions>,
+	) -> Result<Vec<VariableInfo>> {
+		Err(Error::unsupported("CNB", "CI variables"))
+	}
+
+	async fn set_variable(&self, _scope: SecretScope, _name: &str, _value: &str) -> Result<()> {
+		Err(Error::unsupported("CNB", "CI variables"))
+	}
+
+	async fn delete_variable(&self, _scope: SecretScope, _name: &str) -> Result<()> {
+		Err(Error::unsupported("CNB", "CI variables"))
+	}
+}
@@ -0,0 +1,5 @@
+use crate::commit::CnbCommit;
+use nipaw_core::stats::CommitStats;
+
+/// CNB没有统计接口, 均从提交列表本地计算
+pub type CnbStats = CommitStats<CnbCommit>;
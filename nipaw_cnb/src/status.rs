@@ -0,0 +1,65 @@
+use crate::CnbClientInner;
+use crate::common::JsonValue;
+use async_trait::async_trait;
+use nipaw_core::option::status::{CreateOptions, ListOptions};
+use nipaw_core::types::repo::RepoPath;
+use nipaw_core::types::status::{CombinedStatusInfo, StatusInfo, StatusState};
+use nipaw_core::{Error, Result, Status};
+use std::sync::Arc;
+
+pub struct CnbStatus(pub(crate) Arc<CnbClientInner>);
+
+impl CnbStatus {
+	async fn get_commit_statuses(
+		&self,
+		repo_path: &RepoPath,
+		reference: &str,
+	) -> Result<JsonValue> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let url = format!(
+			"{}/{}/{}/-/git/commit-statuses/{}",
+			api_url, repo_path.owner, repo_path.repo, reference
+		);
+		let client = self.0.client.read().await;
+		let request = client.get(url).bearer_auth(token.as_ref().unwrap());
+		let res = request.send().await?.json::<JsonValue>().await?;
+		Ok(res)
+	}
+}
+
+#[async_trait]
+impl Status for CnbStatus {
+	async fn create(
+		&self,
+		_repo_path: RepoPath,
+		_sha: &str,
+		_state: StatusState,
+		_option: Option<CreateOptions>,
+	) -> Result<StatusInfo> {
+		// CNB的提交状态由云原生构建产生, 不支持外部写入
+		Err(Error::unsupported("CNB", "creating commit status"))
+	}
+
+	async fn list(
+		&self,
+		repo_path: RepoPath,
+		reference: &str,
+		option: Option<ListOptions>,
+	) -> Result<Vec<StatusInfo>> {
+		let combined_info: CombinedStatusInfo =
+			self.get_commit_statuses(&repo_path, reference).await?.into();
+		// 接口不分页, 在本地按选项截取
+		let option = option.unwrap_or_default();
+		let per_page = option.per_page.unwrap_or(30).min(100) as usize;
+		let page = option.page.unwrap_or(1).max(1) as usize;
+		Ok(combined_info.statuses.into_iter().skip((page - 1) * per_page).take(per_page).collect())
+	}
+
+	async fn combined(&self, repo_path: RepoPath, reference: &str) -> Result<CombinedStatusInfo> {
+		let res = self.get_commit_statuses(&repo_path, reference).await?;
+		Ok(res.into())
+	}
+}
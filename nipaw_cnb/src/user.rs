@@ -4,6 +4,7 @@ use crate::common::JsonValue;
 use chrono::{Datelike, Local};
 use nipaw_core::option::repo::ListOptions;
 use nipaw_core::types::{
+	org::OrgInfo,
 	repo::RepoInfo,
 	user::{ContributionResult, UserInfo},
 };
@@ -12,8 +13,40 @@ use serde_json::Value;
 use std::collections::HashMap;
 use std::sync::Arc;
 
+/// CNB未开放关注与取消关注用户的接口
 pub struct CnbUser(pub(crate) Arc<CnbClientInner>);
 
+impl CnbUser {
+	/// 获取分页的用户列表, 当`user_name`为[None]时使用当前登录用户
+	async fn get_user_list(
+		&self,
+		user_name: Option<&str>,
+		path: &str,
+		option: Option<ListOptions>,
+	) -> Result<Vec<UserInfo>> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let user_name = match user_name {
+			Some(name) => name.to_string(),
+			None => self.info(None).await?.login,
+		};
+		let url = format!("{}/users/{}/{}", api_url, user_name, path);
+		let client = self.0.client.read().await;
+		let request = client.get(url).bearer_auth(token.as_ref().unwrap());
+		let mut params: HashMap<&str, String> = HashMap::new();
+		if let Some(option) = option {
+			let per_page = option.per_page.unwrap_or(30).min(100);
+			params.insert("page_size", per_page.to_string());
+			let page = option.page.unwrap_or(1);
+			params.insert("page", page.to_string());
+		}
+		let user_infos = request.query(&params).send().await?.json::<Vec<JsonValue>>().await?;
+		Ok(user_infos.into_iter().map(|v| v.into()).collect())
+	}
+}
+
 #[async_trait]
 impl User for CnbUser {
 	async fn info(&self, user_name: Option<&str>) -> Result<UserInfo> {
@@ -113,4 +146,121 @@ impl User for CnbUser {
 		let repo_infos: Vec<JsonValue> = resp.json().await?;
 		Ok(repo_infos.into_iter().map(|v| v.into()).collect())
 	}
+
+	async fn starred_repos(
+		&self,
+		user_name: Option<&str>,
+		option: Option<ListOptions>,
+	) -> Result<Vec<RepoInfo>> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let url = if let Some(name) = user_name {
+			format!("{}/users/{}/stared-repos", api_url, name)
+		} else {
+			format!("{}/user/stared-repos", api_url)
+		};
+		let client = self.0.client.read().await;
+		let request = client.get(url).bearer_auth(token.as_ref().unwrap());
+		let mut params: HashMap<&str, String> = HashMap::new();
+		if let Some(option) = option {
+			let per_page = option.per_page.unwrap_or(30).min(100);
+			params.insert("page_size", per_page.to_string());
+			let page = option.page.unwrap_or(1);
+			params.insert("page", page.to_string());
+		}
+		let res = request.query(&params).send().await?.json::<JsonValue>().await?;
+		// 返回值为`{ total, repos }`
+		let repo_infos = match res.0 {
+			Value::Object(mut obj) => obj.remove("repos").unwrap_or_default(),
+			value => value,
+		};
+		Ok(JsonValue(repo_infos).into_iter().map(|v| v.into()).collect())
+	}
+
+	async fn followers(
+		&self,
+		user_name: Option<&str>,
+		option: Option<ListOptions>,
+	) -> Result<Vec<UserInfo>> {
+		self.get_user_list(user_name, "followers", option).await
+	}
+
+	async fn following(
+		&self,
+		user_name: Option<&str>,
+		option: Option<ListOptions>,
+	) -> Result<Vec<UserInfo>> {
+		self.get_user_list(user_name, "following", option).await
+	}
+
+	async fn follow(&self, _user_name: &str) -> Result<()> {
+		Err(Error::unsupported("CNB", "following users"))
+	}
+
+	async fn unfollow(&self, _user_name: &str) -> Result<()> {
+		Err(Error::unsupported("CNB", "unfollowing users"))
+	}
+
+	async fn is_following(&self, user_name: &str) -> Result<bool> {
+		// 没有单独的检查接口, 逐页查找当前用户的关注列表
+		let login = self.info(None).await?.login;
+		for page in 1.. {
+			let option = ListOptions { per_page: Some(100), page: Some(page) };
+			let users = self.get_user_list(Some(&login), "following", Some(option)).await?;
+			if users.iter().any(|u| u.login.eq_ignore_ascii_case(user_name)) {
+				return Ok(true);
+			}
+			if users.len() < 100 {
+				break;
+			}
+		}
+		Ok(false)
+	}
+
+	async fn org_list(
+		&self,
+		user_name: Option<&str>,
+		option: Option<ListOptions>,
+	) -> Result<Vec<OrgInfo>> {
+		let (token, api_url, base_url) =
+			(&self.0.config.token, &self.0.config.api_url, &self.0.config.base_url);
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let url = if let Some(name) = user_name {
+			format!("{}/users/{}/groups", api_url, name)
+		} else {
+			format!("{}/user/groups", api_url)
+		};
+		let client = self.0.client.read().await;
+		let request = client.get(url).bearer_auth(token.as_ref().unwrap());
+		let mut params: HashMap<&str, String> = HashMap::new();
+		if let Some(option) = option {
+			let per_page = option.per_page.unwrap_or(30).min(100);
+			params.insert("page_size", per_page.to_string());
+			let page = option.page.unwrap_or(1);
+			params.insert("page", page.to_string());
+		}
+		let groups = request.query(&params).send().await?.json::<Vec<JsonValue>>().await?;
+		let org_infos = groups
+			.into_iter()
+			.map(|mut group| {
+				// 组织列表以`path`作为组织名, 且不包含头像
+				let obj = group.0.as_object_mut().unwrap();
+				let login = obj
+					.get("login")
+					.or(obj.get("path"))
+					.and_then(|v| v.as_str())
+					.unwrap_or_default()
+					.to_string();
+				let avatar_url = format!("{}/{}/-/logos/l", base_url, login);
+				obj.insert("avatar_url".to_string(), Value::String(avatar_url));
+				obj.insert("login".to_string(), Value::String(login));
+				group.into()
+			})
+			.collect();
+		Ok(org_infos)
+	}
 }
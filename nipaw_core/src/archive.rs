@@ -0,0 +1,383 @@
+//! 源码压缩包解压
+//!
+//! 各平台的源码压缩包都会把文件放在一个顶层目录下, 如`repo-main/`, 解压时统一去掉该目录
+
+use crate::types::repo::ArchiveFormat;
+use crate::{Error, Result};
+use flate2::read::GzDecoder;
+use std::collections::HashSet;
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::{Component, Path, PathBuf};
+
+/// 将压缩包解压到`dest`目录, 并去掉压缩包内的顶层目录
+///
+/// 这是阻塞操作, 在异步环境中应放到阻塞线程中执行
+///
+/// ## 参数
+/// * `archive` - 压缩包路径
+/// * `format` - 压缩格式
+/// * `dest` - 目标目录, 不存在时会自动创建
+///
+/// ## 返回
+/// 解压出的文件与符号链接相对于`dest`的路径
+///
+/// 符号链接的目标超出`dest`或经过其他符号链接时返回错误, 非Unix平台不支持符号链接
+pub fn extract(archive: &Path, format: ArchiveFormat, dest: &Path) -> Result<Vec<PathBuf>> {
+	fs::create_dir_all(dest)?;
+	let file = File::open(archive)?;
+	match format {
+		ArchiveFormat::TarGz => extract_tar_gz(file, dest),
+		ArchiveFormat::Zip => extract_zip(file, dest),
+	}
+}
+
+fn extract_tar_gz(file: File, dest: &Path) -> Result<Vec<PathBuf>> {
+	let mut archive = tar::Archive::new(GzDecoder::new(file));
+	let mut files = Vec::new();
+	let mut links = Vec::new();
+	for entry in archive.entries()? {
+		let mut entry = entry?;
+		let entry_type = entry.header().entry_type();
+		// GitHub的tar包带有记录提交SHA的全局扩展头, 不是实际文件
+		if !(entry_type.is_file() || entry_type.is_dir() || entry_type.is_symlink()) {
+			continue;
+		}
+		let Some(relative) = strip_prefix(&entry.path()?) else {
+			continue;
+		};
+		if entry_type.is_dir() {
+			fs::create_dir_all(dest.join(&relative))?;
+		} else if entry_type.is_symlink() {
+			let target =
+				entry.link_name()?.ok_or_else(|| invalid_archive("symlink without target"))?;
+			links.push((relative, target.into_owned()));
+		} else {
+			let mode = entry.header().mode().ok();
+			write_file(&mut entry, &dest.join(&relative), mode)?;
+			files.push(relative);
+		}
+	}
+	create_links(dest, links, &mut files)?;
+	Ok(files)
+}
+
+fn extract_zip(file: File, dest: &Path) -> Result<Vec<PathBuf>> {
+	let mut archive = zip::ZipArchive::new(file).map_err(zip_error)?;
+	let mut files = Vec::new();
+	let mut links = Vec::new();
+	for index in 0..archive.len() {
+		let mut entry = archive.by_index(index).map_err(zip_error)?;
+		let Some(relative) = entry.enclosed_name().as_deref().and_then(strip_prefix) else {
+			continue;
+		};
+		if entry.is_dir() {
+			fs::create_dir_all(dest.join(&relative))?;
+		} else if entry.is_symlink() {
+			// 符号链接的目标保存在条目内容中
+			let mut target = String::new();
+			entry.read_to_string(&mut target)?;
+			links.push((relative, PathBuf::from(target)));
+		} else {
+			let mode = entry.unix_mode();
+			write_file(&mut entry, &dest.join(&relative), mode)?;
+			files.push(relative);
+		}
+	}
+	create_links(dest, links, &mut files)?;
+	Ok(files)
+}
+
+/// 在普通文件写入完成后创建符号链接, 避免后续文件经由符号链接写到`dest`之外
+///
+/// 创建前先用压缩包内的全部符号链接校验每个目标, 结果与条目顺序无关
+fn create_links(
+	dest: &Path,
+	links: Vec<(PathBuf, PathBuf)>,
+	files: &mut Vec<PathBuf>,
+) -> Result<()> {
+	let link_paths: HashSet<&Path> = links.iter().map(|(link, _)| link.as_path()).collect();
+	for (link, target) in &links {
+		check_link_target(dest, &link_paths, link, target)?;
+	}
+	for (link, target) in links {
+		let path = dest.join(&link);
+		if let Some(parent) = path.parent() {
+			fs::create_dir_all(parent)?;
+		}
+		#[cfg(unix)]
+		{
+			std::os::unix::fs::symlink(&target, &path)?;
+			files.push(link);
+		}
+		#[cfg(not(unix))]
+		{
+			let _ = (target, files);
+			return Err(invalid_archive(&format!(
+				"symlink {} is not supported on this platform",
+				link.display()
+			)));
+		}
+	}
+	Ok(())
+}
+
+/// 校验符号链接: 链接自身不能位于其他符号链接之下, 目标必须为相对路径,
+/// 解析后不能超出`dest`, 也不能经过压缩包内或`dest`中已有的其他符号链接
+fn check_link_target(
+	dest: &Path,
+	link_paths: &HashSet<&Path>,
+	link: &Path,
+	target: &Path,
+) -> Result<()> {
+	let unsafe_link = || invalid_archive(&format!("unsafe symlink {}", link.display()));
+	let is_link = |path: &Path| {
+		link_paths.contains(path)
+			|| fs::symlink_metadata(dest.join(path)).is_ok_and(|m| m.is_symlink())
+	};
+	let parent = link.parent().unwrap_or(Path::new(""));
+	if parent.ancestors().any(|p| !p.as_os_str().is_empty() && is_link(p)) {
+		return Err(unsafe_link());
+	}
+	let mut resolved = parent.to_path_buf();
+	for component in target.components() {
+		match component {
+			Component::Normal(part) => {
+				resolved.push(part);
+				if is_link(&resolved) {
+					return Err(unsafe_link());
+				}
+			}
+			Component::CurDir => {}
+			Component::ParentDir => {
+				if !resolved.pop() {
+					return Err(unsafe_link());
+				}
+			}
+			_ => return Err(unsafe_link()),
+		}
+	}
+	Ok(())
+}
+
+/// 去掉顶层目录, 路径为空或包含`..`、绝对路径等不安全的部分时返回[None]
+fn strip_prefix(path: &Path) -> Option<PathBuf> {
+	let mut components = path.components();
+	// 顶层目录必须为普通目录名, 绝对路径的第一部分是根目录
+	let Some(Component::Normal(_)) = components.next() else {
+		return None;
+	};
+	let mut relative = PathBuf::new();
+	for component in components {
+		match component {
+			Component::Normal(part) => relative.push(part),
+			Component::CurDir => {}
+			_ => return None,
+		}
+	}
+	(!relative.as_os_str().is_empty()).then_some(relative)
+}
+
+fn write_file(reader: &mut impl Read, path: &Path, mode: Option<u32>) -> Result<()> {
+	if let Some(parent) = path.parent() {
+		fs::create_dir_all(parent)?;
+	}
+	let mut file = File::create(path)?;
+	io::copy(reader, &mut file)?;
+	#[cfg(unix)]
+	if let Some(mode) = mode {
+		use std::os::unix::fs::PermissionsExt;
+		fs::set_permissions(path, fs::Permissions::from_mode(mode & 0o777))?;
+	}
+	#[cfg(not(unix))]
+	let _ = mode;
+	Ok(())
+}
+
+fn zip_error(err: zip::result::ZipError) -> Error {
+	match err {
+		zip::result::ZipError::Io(err) => Error::IoError(err),
+		err => invalid_archive(&err.to_string()),
+	}
+}
+
+fn invalid_archive(reason: &str) -> Error {
+	Error::InvalidParam { param: "archive".to_string(), reason: reason.to_string() }
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use flate2::Compression;
+	use flate2::write::GzEncoder;
+	use std::io::Write;
+
+	/// 测试用的临时目录, 离开作用域时删除
+	struct TempDir(PathBuf);
+
+	impl TempDir {
+		fn new(name: &str) -> Self {
+			let path =
+				std::env::temp_dir().join(format!("nipaw-archive-{}-{}", name, std::process::id()));
+			let _ = fs::remove_dir_all(&path);
+			fs::create_dir_all(&path).unwrap();
+			TempDir(path)
+		}
+	}
+
+	impl Drop for TempDir {
+		fn drop(&mut self) {
+			let _ = fs::remove_dir_all(&self.0);
+		}
+	}
+
+	/// 构造tar.gz, 直接写入条目名以绕过`tar::Builder`的路径检查
+	fn tar_gz(path: &Path, entries: &[(&str, tar::EntryType, &[u8])]) {
+		let mut builder = tar::Builder::new(GzEncoder::new(Vec::new(), Compression::fast()));
+		for (name, entry_type, data) in entries {
+			let mut header = tar::Header::new_gnu();
+			let gnu = header.as_gnu_mut().unwrap();
+			gnu.name[..name.len()].copy_from_slice(name.as_bytes());
+			header.set_entry_type(*entry_type);
+			header.set_mode(0o644);
+			if entry_type.is_symlink() {
+				header.set_link_name_literal(data).unwrap();
+				header.set_size(0);
+				header.set_cksum();
+				builder.append(&header, io::empty()).unwrap();
+			} else {
+				header.set_size(data.len() as u64);
+				header.set_cksum();
+				builder.append(&header, *data).unwrap();
+			}
+		}
+		let data = builder.into_inner().unwrap().finish().unwrap();
+		fs::write(path, data).unwrap();
+	}
+
+	fn zip(path: &Path, entries: &[(&str, &[u8])], links: &[(&str, &str)]) {
+		let mut writer = zip::ZipWriter::new(File::create(path).unwrap());
+		let options = zip::write::SimpleFileOptions::default()
+			.compression_method(zip::CompressionMethod::Stored);
+		for (name, data) in entries {
+			writer.start_file(*name, options).unwrap();
+			writer.write_all(data).unwrap();
+		}
+		for (name, target) in links {
+			writer.add_symlink(*name, *target, options).unwrap();
+		}
+		writer.finish().unwrap();
+	}
+
+	#[test]
+	fn strip_prefix_rejects_unsafe_paths() {
+		assert_eq!(strip_prefix(Path::new("repo-main/src/lib.rs")), Some("src/lib.rs".into()));
+		assert_eq!(strip_prefix(Path::new("repo-main/./a")), Some("a".into()));
+		assert_eq!(strip_prefix(Path::new("repo-main")), None);
+		assert_eq!(strip_prefix(Path::new("repo-main/../evil")), None);
+		assert_eq!(strip_prefix(Path::new("repo-main/a/../../evil")), None);
+		assert_eq!(strip_prefix(Path::new("/etc/passwd")), None);
+	}
+
+	#[test]
+	fn tar_skips_traversal_entries() {
+		let dir = TempDir::new("tar-traversal");
+		let archive = dir.0.join("a.tar.gz");
+		tar_gz(
+			&archive,
+			&[
+				("repo-main/ok.txt", tar::EntryType::Regular, b"ok"),
+				("repo-main/../../evil.txt", tar::EntryType::Regular, b"evil"),
+				("/abs/evil.txt", tar::EntryType::Regular, b"evil"),
+			],
+		);
+		let dest = dir.0.join("out");
+		let files = extract(&archive, ArchiveFormat::TarGz, &dest).unwrap();
+		assert_eq!(files, [PathBuf::from("ok.txt")]);
+		assert_eq!(fs::read(dest.join("ok.txt")).unwrap(), b"ok");
+		assert!(!dir.0.join("evil.txt").exists());
+		assert!(!std::env::temp_dir().join("evil.txt").exists());
+	}
+
+	#[test]
+	fn zip_skips_traversal_entries() {
+		let dir = TempDir::new("zip-traversal");
+		let archive = dir.0.join("a.zip");
+		zip(&archive, &[("repo-main/ok.txt", b"ok"), ("repo-main/../../evil.txt", b"evil")], &[]);
+		let dest = dir.0.join("out");
+		let files = extract(&archive, ArchiveFormat::Zip, &dest).unwrap();
+		assert_eq!(files, [PathBuf::from("ok.txt")]);
+		assert!(!dir.0.join("evil.txt").exists());
+	}
+
+	#[cfg(unix)]
+	#[test]
+	fn symlinks_are_recreated_inside_dest() {
+		let dir = TempDir::new("symlink");
+		let archive = dir.0.join("a.tar.gz");
+		tar_gz(
+			&archive,
+			&[
+				("repo-main/docs/guide.md", tar::EntryType::Regular, b"guide"),
+				("repo-main/README.md", tar::EntryType::Symlink, b"docs/guide.md"),
+				("repo-main/docs/index.md", tar::EntryType::Symlink, b"../docs/guide.md"),
+			],
+		);
+		let dest = dir.0.join("out");
+		extract(&archive, ArchiveFormat::TarGz, &dest).unwrap();
+		assert_eq!(fs::read(dest.join("README.md")).unwrap(), b"guide");
+		assert_eq!(fs::read(dest.join("docs/index.md")).unwrap(), b"guide");
+
+		let archive = dir.0.join("a.zip");
+		zip(&archive, &[("repo-main/a.txt", b"a")], &[("repo-main/b.txt", "a.txt")]);
+		let dest = dir.0.join("zip-out");
+		extract(&archive, ArchiveFormat::Zip, &dest).unwrap();
+		assert_eq!(fs::read(dest.join("b.txt")).unwrap(), b"a");
+	}
+
+	#[test]
+	fn escaping_symlinks_are_rejected() {
+		let dir = TempDir::new("symlink-escape");
+		for (index, target) in ["../../outside", "/etc/passwd", "sub/../../x"].iter().enumerate() {
+			let archive = dir.0.join(format!("{}.tar.gz", index));
+			tar_gz(&archive, &[("repo-main/link", tar::EntryType::Symlink, target.as_bytes())]);
+			assert!(extract(&archive, ArchiveFormat::TarGz, &dir.0.join("out")).is_err());
+		}
+		// 经过另一个符号链接向上跳出
+		let archive = dir.0.join("chain.tar.gz");
+		tar_gz(
+			&archive,
+			&[
+				("repo-main/self", tar::EntryType::Symlink, b"."),
+				("repo-main/up", tar::EntryType::Symlink, b"self/.."),
+			],
+		);
+		assert!(extract(&archive, ArchiveFormat::TarGz, &dir.0.join("chain")).is_err());
+		// 顺序相反时同样拒绝
+		let archive = dir.0.join("chain-reversed.tar.gz");
+		tar_gz(
+			&archive,
+			&[
+				("repo-main/up", tar::EntryType::Symlink, b"self/.."),
+				("repo-main/self", tar::EntryType::Symlink, b"."),
+			],
+		);
+		let dest = dir.0.join("chain-reversed");
+		assert!(extract(&archive, ArchiveFormat::TarGz, &dest).is_err());
+		assert!(!dest.join("up").exists());
+		// 链接位于另一个符号链接之下
+		let archive = dir.0.join("nested.tar.gz");
+		tar_gz(
+			&archive,
+			&[
+				("repo-main/self/x", tar::EntryType::Symlink, b"y"),
+				("repo-main/self", tar::EntryType::Symlink, b"."),
+			],
+		);
+		assert!(extract(&archive, ArchiveFormat::TarGz, &dir.0.join("nested")).is_err());
+
+		let archive = dir.0.join("a.zip");
+		zip(&archive, &[], &[("repo-main/link", "../../outside")]);
+		assert!(extract(&archive, ArchiveFormat::Zip, &dir.0.join("zip-out")).is_err());
+	}
+}
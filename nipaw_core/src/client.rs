@@ -10,6 +10,26 @@ mod issue;
 pub use issue::Issue;
 mod release;
 pub use release::Release;
+mod search;
+pub use search::Search;
+mod status;
+pub use status::Status;
+mod key;
+pub use key::Key;
+mod stats;
+pub use stats::Stats;
+mod traffic;
+pub use traffic::Traffic;
+mod notification;
+pub use notification::Notifications;
+mod gist;
+pub use gist::Gist;
+mod pipeline;
+pub use pipeline::Pipeline;
+mod secret;
+pub use secret::Secret;
+mod git;
+pub use git::Git;
 
 use crate::Result;
 
@@ -37,6 +57,16 @@ pub trait Provider: Send + Sync {
 	type Commit: Commit;
 	type Issue: Issue;
 	type Release: Release;
+	type Search: Search;
+	type Status: Status;
+	type Key: Key;
+	type Stats: Stats;
+	type Traffic: Traffic;
+	type Notifications: Notifications;
+	type Gist: Gist;
+	type Pipeline: Pipeline;
+	type Secret: Secret;
+	type Git: Git;
 
 	fn user(&self) -> Self::User;
 	fn org(&self) -> Self::Org;
@@ -44,6 +74,16 @@ pub trait Provider: Send + Sync {
 	fn commit(&self) -> Self::Commit;
 	fn issue(&self) -> Self::Issue;
 	fn release(&self) -> Self::Release;
+	fn search(&self) -> Self::Search;
+	fn status(&self) -> Self::Status;
+	fn key(&self) -> Self::Key;
+	fn stats(&self) -> Self::Stats;
+	fn traffic(&self) -> Self::Traffic;
+	fn notifications(&self) -> Self::Notifications;
+	fn gist(&self) -> Self::Gist;
+	fn pipeline(&self) -> Self::Pipeline;
+	fn secret(&self) -> Self::Secret;
+	fn git(&self) -> Self::Git;
 }
 
 pub trait Client: Config + Provider {}
@@ -1,5 +1,5 @@
 use crate::option::commit::ListOptions;
-use crate::types::commit::{CommitInfo, CommitListInfo};
+use crate::types::commit::{CommitInfo, CommitListInfo, CompareInfo};
 use crate::types::repo::RepoPath;
 use async_trait::async_trait;
 
@@ -24,4 +24,24 @@ pub trait Commit {
 		repo_path: RepoPath,
 		option: Option<ListOptions>,
 	) -> crate::Result<Vec<CommitListInfo>>;
+
+	/// 比较两个引用之间的差异
+	///
+	/// # 参数
+	/// * `repo_path` - 仓库路径
+	/// * `base` - 基准引用, 可以是分支、标签或提交SHA
+	/// * `head` - 目标引用, 可以是分支、标签或提交SHA
+	async fn compare(
+		&self,
+		repo_path: RepoPath,
+		base: &str,
+		head: &str,
+	) -> crate::Result<CompareInfo>;
+
+	/// 获取提交的统一差异格式文本, 可使用[DiffFile::parse](crate::types::diff::DiffFile::parse)解析
+	///
+	/// # 参数
+	/// * `repo_path` - 仓库路径
+	/// * `sha` - 提交ID
+	async fn diff(&self, repo_path: RepoPath, sha: &str) -> crate::Result<String>;
 }
@@ -0,0 +1,70 @@
+use crate::option::gist::{CreateOptions, UpdateOptions};
+use crate::option::repo::ListOptions;
+use crate::types::gist::GistInfo;
+use async_trait::async_trait;
+
+/// 代码片段, 即GitHub Gists与Gitee代码片段
+#[async_trait]
+pub trait Gist {
+	/// 获取用户的代码片段列表, 列表中的文件不包含内容
+	///
+	/// ## 参数
+	/// - `user_name` - 用户名, 为[None]时获取当前登录用户的代码片段
+	/// - `option` - 列表选项, 详见 [ListOptions]
+	///
+	async fn list(
+		&self,
+		user_name: Option<&str>,
+		option: Option<ListOptions>,
+	) -> crate::Result<Vec<GistInfo>>;
+
+	/// 获取代码片段及其文件内容
+	///
+	/// ## 参数
+	/// - `id` - 代码片段ID
+	///
+	async fn info(&self, id: &str) -> crate::Result<GistInfo>;
+
+	/// 创建代码片段, 需要登录
+	///
+	/// ## 参数
+	/// - `option` - 创建选项, 详见 [CreateOptions]
+	///
+	async fn create(&self, option: CreateOptions) -> crate::Result<GistInfo>;
+
+	/// 更新代码片段, 需要登录
+	///
+	/// ## 参数
+	/// - `id` - 代码片段ID
+	/// - `option` - 更新选项, 详见 [UpdateOptions]
+	///
+	async fn update(&self, id: &str, option: UpdateOptions) -> crate::Result<GistInfo>;
+
+	/// 删除代码片段, 需要登录
+	///
+	/// ## 参数
+	/// - `id` - 代码片段ID
+	///
+	async fn delete(&self, id: &str) -> crate::Result<()>;
+
+	/// 收藏代码片段, 需要登录
+	///
+	/// ## 参数
+	/// - `id` - 代码片段ID
+	///
+	async fn star(&self, id: &str) -> crate::Result<()>;
+
+	/// 取消收藏代码片段, 需要登录
+	///
+	/// ## 参数
+	/// - `id` - 代码片段ID
+	///
+	async fn unstar(&self, id: &str) -> crate::Result<()>;
+
+	/// Fork代码片段到当前用户, 需要登录
+	///
+	/// ## 参数
+	/// - `id` - 代码片段ID
+	///
+	async fn fork(&self, id: &str) -> crate::Result<GistInfo>;
+}
@@ -0,0 +1,148 @@
+use crate::option::git::{FileChange, TreeEntryOptions};
+use crate::types::git::{GitCommit, GitRef, GitTree};
+use crate::types::repo::RepoPath;
+use async_trait::async_trait;
+
+/// 底层Git数据, 包括引用、树、数据对象与提交
+///
+/// 引用名可以带或不带`refs/`前缀, 如`heads/main`与`refs/heads/main`等价
+#[async_trait]
+pub trait Git {
+	/// 获取引用
+	///
+	/// Gitee与GitCode只支持分支引用, CNB未开放, 返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// ## 参数
+	/// * `repo_path` - 仓库路径
+	/// * `reference` - 引用名, 如`heads/main`、`tags/v1.0.0`
+	///
+	async fn get_ref(&self, repo_path: RepoPath, reference: &str) -> crate::Result<GitRef>;
+
+	/// 创建引用, 需要登录
+	///
+	/// Gitee与GitCode只支持创建分支, CNB未开放, 返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// ## 参数
+	/// * `repo_path` - 仓库路径
+	/// * `reference` - 引用名
+	/// * `sha` - 指向的对象SHA
+	///
+	async fn create_ref(
+		&self,
+		repo_path: RepoPath,
+		reference: &str,
+		sha: &str,
+	) -> crate::Result<GitRef>;
+
+	/// 更新引用, 需要登录
+	///
+	/// Gitee、GitCode与CNB未开放, 返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// ## 参数
+	/// * `repo_path` - 仓库路径
+	/// * `reference` - 引用名
+	/// * `sha` - 新的对象SHA
+	/// * `force` - 是否允许非快进更新
+	///
+	async fn update_ref(
+		&self,
+		repo_path: RepoPath,
+		reference: &str,
+		sha: &str,
+		force: bool,
+	) -> crate::Result<GitRef>;
+
+	/// 删除引用, 需要登录
+	///
+	/// Gitee、GitCode与CNB未开放, 返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// ## 参数
+	/// * `repo_path` - 仓库路径
+	/// * `reference` - 引用名
+	///
+	async fn delete_ref(&self, repo_path: RepoPath, reference: &str) -> crate::Result<()>;
+
+	/// 获取树对象
+	///
+	/// CNB未开放, 返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// ## 参数
+	/// * `repo_path` - 仓库路径
+	/// * `sha` - 树对象SHA, 也可以是分支名或提交SHA
+	/// * `recursive` - 是否递归获取子目录
+	///
+	async fn tree(&self, repo_path: RepoPath, sha: &str, recursive: bool)
+	-> crate::Result<GitTree>;
+
+	/// 创建数据对象, 返回对象SHA, 需要登录
+	///
+	/// Gitee、GitCode与CNB未开放, 返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// ## 参数
+	/// * `repo_path` - 仓库路径
+	/// * `content` - 文件内容
+	///
+	async fn create_blob(&self, repo_path: RepoPath, content: &[u8]) -> crate::Result<String>;
+
+	/// 创建树对象, 需要登录
+	///
+	/// Gitee、GitCode与CNB未开放, 返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// ## 参数
+	/// * `repo_path` - 仓库路径
+	/// * `base_tree` - 基础树对象SHA, 未列出的路径沿用基础树的内容
+	/// * `entries` - 树条目, 参考[TreeEntryOptions]
+	///
+	async fn create_tree(
+		&self,
+		repo_path: RepoPath,
+		base_tree: Option<&str>,
+		entries: Vec<TreeEntryOptions>,
+	) -> crate::Result<GitTree>;
+
+	/// 获取提交对象
+	///
+	/// CNB未开放, 返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// ## 参数
+	/// * `repo_path` - 仓库路径
+	/// * `sha` - 提交SHA
+	///
+	async fn get_commit(&self, repo_path: RepoPath, sha: &str) -> crate::Result<GitCommit>;
+
+	/// 创建提交对象, 不会移动任何引用, 需要登录
+	///
+	/// Gitee、GitCode与CNB未开放, 返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// ## 参数
+	/// * `repo_path` - 仓库路径
+	/// * `message` - 提交信息
+	/// * `tree` - 树对象SHA
+	/// * `parents` - 父提交SHA列表
+	///
+	async fn create_commit(
+		&self,
+		repo_path: RepoPath,
+		message: &str,
+		tree: &str,
+		parents: Vec<String>,
+	) -> crate::Result<GitCommit>;
+
+	/// 在分支上以一次提交完成多个文件的修改, 需要登录
+	///
+	/// GitCode与CNB未开放, 返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// ## 参数
+	/// * `repo_path` - 仓库路径
+	/// * `branch` - 分支名
+	/// * `message` - 提交信息
+	/// * `changes` - 文件修改, 参考[FileChange]
+	///
+	async fn commit_files(
+		&self,
+		repo_path: RepoPath,
+		branch: &str,
+		message: &str,
+		changes: Vec<FileChange>,
+	) -> crate::Result<GitCommit>;
+}
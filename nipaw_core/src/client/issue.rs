@@ -1,5 +1,5 @@
-use crate::option::issue::{CreateOptions, ListOptions, UpdateOptions};
-use crate::types::issue::IssueInfo;
+use crate::option::issue::{CreateOptions, EventListOptions, ListOptions, UpdateOptions};
+use crate::types::issue::{IssueEventInfo, IssueInfo, TimelineItem};
 use crate::types::repo::RepoPath;
 use async_trait::async_trait;
 
@@ -52,4 +52,82 @@ pub trait Issue {
 		issue_number: &str,
 		options: Option<UpdateOptions>,
 	) -> crate::Result<IssueInfo>;
+
+	/// 获取issue的事件列表, 如添加标签、分配、关闭等
+	///
+	/// GitCode与CNB未开放事件接口, 返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// ## 参数
+	/// - `repo_path` - 仓库路径
+	/// - `issue_number` - issue编号
+	/// - `options` - 分页选项, 详见 [EventListOptions]
+	///
+	async fn events(
+		&self,
+		repo_path: RepoPath,
+		issue_number: &str,
+		options: Option<EventListOptions>,
+	) -> crate::Result<Vec<IssueEventInfo>>;
+
+	/// 获取issue的时间线, 评论与事件按发生时间升序合并
+	///
+	/// 没有事件接口的平台只返回评论
+	///
+	/// ## 参数
+	/// - `repo_path` - 仓库路径
+	/// - `issue_number` - issue编号
+	/// - `options` - 分页选项, 详见 [EventListOptions]
+	///
+	async fn timeline(
+		&self,
+		repo_path: RepoPath,
+		issue_number: &str,
+		options: Option<EventListOptions>,
+	) -> crate::Result<Vec<TimelineItem>>;
+
+	/// 为issue添加负责人
+	///
+	/// ## 参数
+	/// - `repo_path` - 仓库路径
+	/// - `issue_number` - issue编号
+	/// - `assignees` - 负责人用户名列表
+	///
+	async fn add_assignees(
+		&self,
+		repo_path: RepoPath,
+		issue_number: &str,
+		assignees: Vec<String>,
+	) -> crate::Result<IssueInfo>;
+
+	/// 移除issue的负责人
+	///
+	/// ## 参数
+	/// - `repo_path` - 仓库路径
+	/// - `issue_number` - issue编号
+	/// - `assignees` - 负责人用户名列表
+	///
+	async fn remove_assignees(
+		&self,
+		repo_path: RepoPath,
+		issue_number: &str,
+		assignees: Vec<String>,
+	) -> crate::Result<IssueInfo>;
+
+	/// 锁定issue, 锁定后只有协作者可以评论
+	///
+	/// 只有GitHub开放了锁定接口, 其他平台返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// ## 参数
+	/// - `repo_path` - 仓库路径
+	/// - `issue_number` - issue编号
+	///
+	async fn lock(&self, repo_path: RepoPath, issue_number: &str) -> crate::Result<()>;
+
+	/// 解锁issue, 平台支持情况同[Issue::lock]
+	///
+	/// ## 参数
+	/// - `repo_path` - 仓库路径
+	/// - `issue_number` - issue编号
+	///
+	async fn unlock(&self, repo_path: RepoPath, issue_number: &str) -> crate::Result<()>;
 }
@@ -0,0 +1,63 @@
+use crate::option::repo::ListOptions;
+use crate::types::key::{GpgKeyInfo, SshKeyInfo};
+use async_trait::async_trait;
+
+/// 当前登录用户的SSH与GPG公钥, 均需要登录
+#[async_trait]
+pub trait Key {
+	/// 获取SSH公钥列表
+	///
+	/// CNB未开放, 返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// ## 参数
+	/// - `option` - 分页选项, 详见 [ListOptions]
+	///
+	async fn ssh_keys(&self, option: Option<ListOptions>) -> crate::Result<Vec<SshKeyInfo>>;
+
+	/// 添加SSH公钥, 发送前会在本地校验公钥格式
+	///
+	/// CNB未开放, 返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// ## 参数
+	/// - `title` - 公钥标题
+	/// - `key` - OpenSSH格式的公钥
+	///
+	async fn add_ssh_key(&self, title: &str, key: &str) -> crate::Result<SshKeyInfo>;
+
+	/// 删除SSH公钥
+	///
+	/// CNB未开放, 返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// ## 参数
+	/// - `id` - 公钥ID
+	///
+	async fn delete_ssh_key(&self, id: u64) -> crate::Result<()>;
+
+	/// 获取GPG公钥列表
+	///
+	/// 只有GitHub开放, Gitee、GitCode与CNB返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// ## 参数
+	/// - `option` - 分页选项, 详见 [ListOptions]
+	///
+	async fn gpg_keys(&self, option: Option<ListOptions>) -> crate::Result<Vec<GpgKeyInfo>>;
+
+	/// 添加GPG公钥, 发送前会在本地校验公钥格式
+	///
+	/// 只有GitHub开放, Gitee、GitCode与CNB返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// ## 参数
+	/// - `title` - 公钥名称
+	/// - `key` - ASCII armor格式的公钥
+	///
+	async fn add_gpg_key(&self, title: Option<&str>, key: &str) -> crate::Result<GpgKeyInfo>;
+
+	/// 删除GPG公钥
+	///
+	/// 只有GitHub开放, Gitee、GitCode与CNB返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// ## 参数
+	/// - `id` - 公钥ID
+	///
+	async fn delete_gpg_key(&self, id: u64) -> crate::Result<()>;
+}
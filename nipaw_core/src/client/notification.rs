@@ -0,0 +1,53 @@
+use crate::option::notification::ListOptions;
+use crate::types::notification::NotificationInfo;
+use crate::types::repo::RepoPath;
+use async_trait::async_trait;
+
+/// 当前登录用户的通知, 均需要登录
+#[async_trait]
+pub trait Notifications {
+	/// 获取通知列表
+	///
+	/// GitCode与CNB未开放, 返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// ## 参数
+	/// - `option` - 通知列表选项, 详见 [ListOptions]
+	///
+	async fn list(&self, option: Option<ListOptions>) -> crate::Result<Vec<NotificationInfo>>;
+
+	/// 将通知标记为已读
+	///
+	/// GitCode与CNB未开放, 返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// ## 参数
+	/// - `thread_id` - 通知ID
+	///
+	async fn mark_read(&self, thread_id: &str) -> crate::Result<()>;
+
+	/// 将全部通知标记为已读
+	///
+	/// GitCode与CNB未开放, 返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// ## 参数
+	/// - `repo_path` - 仓库路径, 为[None]时标记所有仓库的通知
+	///
+	async fn mark_all_read(&self, repo_path: Option<RepoPath>) -> crate::Result<()>;
+
+	/// 订阅通知, 之后的动态都会收到通知
+	///
+	/// 只有GitHub开放, Gitee、GitCode与CNB返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// ## 参数
+	/// - `thread_id` - 通知ID
+	///
+	async fn subscribe(&self, thread_id: &str) -> crate::Result<()>;
+
+	/// 取消订阅通知, 直到再次参与前不再收到通知
+	///
+	/// 只有GitHub开放, Gitee、GitCode与CNB返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// ## 参数
+	/// - `thread_id` - 通知ID
+	///
+	async fn unsubscribe(&self, thread_id: &str) -> crate::Result<()>;
+}
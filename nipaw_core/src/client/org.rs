@@ -1,6 +1,7 @@
 use crate::option::repo::ListOptions;
-use crate::types::org::OrgInfo;
+use crate::types::org::{MemberRole, OrgInfo, OrgMember, TeamInfo, TeamRepoInfo};
 use crate::types::repo::RepoInfo;
+use crate::types::user::UserInfo;
 use async_trait::async_trait;
 
 #[async_trait]
@@ -31,4 +32,133 @@ pub trait Org {
 	/// * `org_name` - 组织名
 	///
 	async fn avatar_url(&self, org_name: &str) -> crate::Result<String>;
+
+	/// 获取组织成员列表
+	///
+	/// # 参数
+	///
+	/// * `org_name` - 组织名
+	/// * `options` - 分页选项, 详见 [ListOptions]
+	///
+	async fn members(
+		&self,
+		org_name: &str,
+		options: Option<ListOptions>,
+	) -> crate::Result<Vec<OrgMember>>;
+
+	/// 添加或邀请用户加入组织, 需要登录
+	///
+	/// # 参数
+	///
+	/// * `org_name` - 组织名
+	/// * `user_name` - 用户名
+	/// * `role` - 成员角色, 默认为 `Member`
+	///
+	async fn add_member(
+		&self,
+		org_name: &str,
+		user_name: &str,
+		role: Option<MemberRole>,
+	) -> crate::Result<()>;
+
+	/// 将用户移出组织, 需要登录
+	///
+	/// # 参数
+	///
+	/// * `org_name` - 组织名
+	/// * `user_name` - 用户名
+	///
+	async fn remove_member(&self, org_name: &str, user_name: &str) -> crate::Result<()>;
+
+	/// 用户是否为组织成员
+	///
+	/// # 参数
+	///
+	/// * `org_name` - 组织名
+	/// * `user_name` - 用户名
+	///
+	async fn is_member(&self, org_name: &str, user_name: &str) -> crate::Result<bool>;
+
+	/// 获取组织的团队列表
+	///
+	/// 只有GitHub的组织有团队, Gitee、GitCode与CNB返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// # 参数
+	///
+	/// * `org_name` - 组织名
+	/// * `options` - 分页选项, 详见 [ListOptions]
+	///
+	async fn teams(
+		&self,
+		org_name: &str,
+		options: Option<ListOptions>,
+	) -> crate::Result<Vec<TeamInfo>>;
+
+	/// 获取团队成员列表
+	///
+	/// 只有GitHub的组织有团队, Gitee、GitCode与CNB返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// # 参数
+	///
+	/// * `org_name` - 组织名
+	/// * `team_slug` - 团队标识
+	/// * `options` - 分页选项, 详见 [ListOptions]
+	///
+	async fn team_members(
+		&self,
+		org_name: &str,
+		team_slug: &str,
+		options: Option<ListOptions>,
+	) -> crate::Result<Vec<UserInfo>>;
+
+	/// 添加用户到团队, 需要登录
+	///
+	/// 只有GitHub的组织有团队, Gitee、GitCode与CNB返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// # 参数
+	///
+	/// * `org_name` - 组织名
+	/// * `team_slug` - 团队标识
+	/// * `user_name` - 用户名
+	///
+	async fn add_team_member(
+		&self,
+		org_name: &str,
+		team_slug: &str,
+		user_name: &str,
+	) -> crate::Result<()>;
+
+	/// 将用户移出团队, 需要登录
+	///
+	/// 只有GitHub的组织有团队, Gitee、GitCode与CNB返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// # 参数
+	///
+	/// * `org_name` - 组织名
+	/// * `team_slug` - 团队标识
+	/// * `user_name` - 用户名
+	///
+	async fn remove_team_member(
+		&self,
+		org_name: &str,
+		team_slug: &str,
+		user_name: &str,
+	) -> crate::Result<()>;
+
+	/// 获取团队可访问的仓库及权限
+	///
+	/// 只有GitHub的组织有团队, Gitee、GitCode与CNB返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// # 参数
+	///
+	/// * `org_name` - 组织名
+	/// * `team_slug` - 团队标识
+	/// * `options` - 分页选项, 详见 [ListOptions]
+	///
+	async fn team_repos(
+		&self,
+		org_name: &str,
+		team_slug: &str,
+		options: Option<ListOptions>,
+	) -> crate::Result<Vec<TeamRepoInfo>>;
 }
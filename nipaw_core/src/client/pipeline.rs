@@ -0,0 +1,76 @@
+use crate::option::pipeline::{ListOptions, TriggerOptions};
+use crate::types::pipeline::PipelineRun;
+use crate::types::release::DownloadResult;
+use crate::types::repo::RepoPath;
+use async_trait::async_trait;
+use tokio::io::AsyncWrite;
+
+/// 持续集成流水线, 即GitHub Actions的工作流运行与CNB的云原生构建
+#[async_trait]
+pub trait Pipeline {
+	/// 获取仓库的运行列表, 不包含任务详情
+	///
+	/// ## 参数
+	/// * `repo_path` - 仓库路径
+	/// * `option` - 列表选项, 参考[ListOptions]
+	///
+	async fn list(
+		&self,
+		repo_path: RepoPath,
+		option: Option<ListOptions>,
+	) -> crate::Result<Vec<PipelineRun>>;
+
+	/// 获取运行详情, 包括任务与步骤
+	///
+	/// ## 参数
+	/// * `repo_path` - 仓库路径
+	/// * `run_id` - 运行ID
+	///
+	async fn info(&self, repo_path: RepoPath, run_id: &str) -> crate::Result<PipelineRun>;
+
+	/// 重新运行, 需要登录
+	///
+	/// ## 参数
+	/// * `repo_path` - 仓库路径
+	/// * `run_id` - 运行ID
+	///
+	async fn rerun(&self, repo_path: RepoPath, run_id: &str) -> crate::Result<()>;
+
+	/// 取消运行, 需要登录
+	///
+	/// ## 参数
+	/// * `repo_path` - 仓库路径
+	/// * `run_id` - 运行ID
+	///
+	async fn cancel(&self, repo_path: RepoPath, run_id: &str) -> crate::Result<()>;
+
+	/// 手动触发运行, 需要登录
+	///
+	/// 平台返回运行ID时返回该ID, GitHub的`workflow_dispatch`不返回运行ID
+	///
+	/// ## 参数
+	/// * `repo_path` - 仓库路径
+	/// * `workflow` - GitHub为工作流文件名或ID, CNB为触发事件名, 如`api_trigger`
+	/// * `option` - 触发选项, 参考[TriggerOptions]
+	///
+	async fn trigger(
+		&self,
+		repo_path: RepoPath,
+		workflow: &str,
+		option: TriggerOptions,
+	) -> crate::Result<Option<String>>;
+
+	/// 下载运行日志到`writer`, GitHub为zip压缩包, CNB为依次拼接的各流水线日志
+	///
+	/// ## 参数
+	/// * `repo_path` - 仓库路径
+	/// * `run_id` - 运行ID
+	/// * `writer` - 写入目标
+	///
+	async fn download_logs(
+		&self,
+		repo_path: RepoPath,
+		run_id: &str,
+		writer: &mut (dyn AsyncWrite + Unpin + Send),
+	) -> crate::Result<DownloadResult>;
+}
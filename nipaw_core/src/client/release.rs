@@ -1,8 +1,10 @@
 use crate::Result;
-use crate::option::release::UpdateOption;
-use crate::types::release::ReleaseInfo;
+use crate::option::release::{DownloadOptions, UpdateOption};
+use crate::types::release::{DownloadResult, ReleaseInfo};
 use crate::types::repo::RepoPath;
 use async_trait::async_trait;
+use std::path::Path;
+use tokio::io::AsyncWrite;
 
 #[async_trait]
 pub trait Release {
@@ -56,4 +58,44 @@ pub trait Release {
 		tag_name: &str,
 		option: UpdateOption,
 	) -> Result<ReleaseInfo>;
+
+	/// 下载Release附件到`writer`
+	///
+	/// 通过当前客户端(包括代理与访问令牌)下载, 会处理各平台的重定向与私有仓库鉴权
+	///
+	/// ## 参数
+	/// * `repo_path` - 仓库路径
+	/// * `tag_name` - 标签名称
+	/// * `asset_name` - 附件文件名
+	/// * `writer` - 写入目标
+	/// * `option` - 下载选项, 参考[DownloadOptions]
+	///
+	async fn download_asset(
+		&self,
+		repo_path: RepoPath,
+		tag_name: &str,
+		asset_name: &str,
+		writer: &mut (dyn AsyncWrite + Unpin + Send),
+		option: Option<DownloadOptions>,
+	) -> Result<DownloadResult>;
+
+	/// 下载Release附件到文件
+	///
+	/// 文件已存在时从文件末尾续传
+	///
+	/// ## 参数
+	/// * `repo_path` - 仓库路径
+	/// * `tag_name` - 标签名称
+	/// * `asset_name` - 附件文件名
+	/// * `path` - 文件路径
+	/// * `option` - 下载选项, 参考[DownloadOptions]
+	///
+	async fn download_asset_to_file(
+		&self,
+		repo_path: RepoPath,
+		tag_name: &str,
+		asset_name: &str,
+		path: &Path,
+		option: Option<DownloadOptions>,
+	) -> Result<DownloadResult>;
 }
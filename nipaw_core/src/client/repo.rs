@@ -1,5 +1,13 @@
-use crate::types::repo::{CollaboratorPermission, CollaboratorResult, RepoInfo, RepoPath};
+use crate::option::repo::{CreateOptions, ListOptions, UpdateOptions};
+use crate::types::key::DeployKeyInfo;
+use crate::types::release::DownloadResult;
+use crate::types::repo::{
+	ArchiveFormat, CollaboratorInfo, CollaboratorPermission, CollaboratorResult, ContributorInfo,
+	RepoInfo, RepoLanguages, RepoPath, SyncForkResult,
+};
+use crate::types::user::UserInfo;
 use async_trait::async_trait;
+use tokio::io::AsyncWrite;
 
 #[async_trait]
 pub trait Repo {
@@ -25,4 +33,333 @@ pub trait Repo {
 		user_name: &str,
 		permission: Option<CollaboratorPermission>,
 	) -> crate::Result<CollaboratorResult>;
+
+	/// 获取仓库协作者列表
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径
+	/// * `option` - 分页选项, 详见 [ListOptions]
+	///
+	async fn collaborators(
+		&self,
+		repo_path: RepoPath,
+		option: Option<ListOptions>,
+	) -> crate::Result<Vec<CollaboratorInfo>>;
+
+	/// 移除仓库协作者, 需要登录
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径
+	/// * `user_name` - 协作者用户名
+	///
+	async fn remove_collaborator(&self, repo_path: RepoPath, user_name: &str) -> crate::Result<()>;
+
+	/// 获取用户在仓库中的权限
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径
+	/// * `user_name` - 用户名
+	///
+	async fn collaborator_permission(
+		&self,
+		repo_path: RepoPath,
+		user_name: &str,
+	) -> crate::Result<CollaboratorPermission>;
+
+	/// 用户是否为仓库协作者
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径
+	/// * `user_name` - 用户名
+	///
+	async fn is_collaborator(&self, repo_path: RepoPath, user_name: &str) -> crate::Result<bool>;
+
+	/// 为仓库添加星标, 需要登录
+	///
+	/// CNB未开放, 返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径
+	///
+	async fn star(&self, repo_path: RepoPath) -> crate::Result<()>;
+
+	/// 取消仓库星标, 需要登录
+	///
+	/// CNB未开放, 返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径
+	///
+	async fn unstar(&self, repo_path: RepoPath) -> crate::Result<()>;
+
+	/// 当前用户是否已为仓库添加星标
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径
+	///
+	async fn is_starred(&self, repo_path: RepoPath) -> crate::Result<bool>;
+
+	/// 获取仓库的星标用户列表
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径
+	/// * `option` - 分页选项, 详见 [ListOptions]
+	///
+	async fn stargazers(
+		&self,
+		repo_path: RepoPath,
+		option: Option<ListOptions>,
+	) -> crate::Result<Vec<UserInfo>>;
+
+	/// 关注仓库, 需要登录
+	///
+	/// CNB未开放, 返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径
+	///
+	async fn watch(&self, repo_path: RepoPath) -> crate::Result<()>;
+
+	/// 取消关注仓库, 需要登录
+	///
+	/// CNB未开放, 返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径
+	///
+	async fn unwatch(&self, repo_path: RepoPath) -> crate::Result<()>;
+
+	/// 获取仓库的关注者列表
+	///
+	/// CNB未开放, 返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径
+	/// * `option` - 分页选项, 详见 [ListOptions]
+	///
+	async fn watchers(
+		&self,
+		repo_path: RepoPath,
+		option: Option<ListOptions>,
+	) -> crate::Result<Vec<UserInfo>>;
+
+	/// fork仓库, 需要登录
+	///
+	/// CNB未开放, 返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径
+	/// * `target_org` - fork到的组织, 为空时fork到当前用户下
+	/// * `name` - fork后的仓库名称, 为空时与原仓库相同
+	///
+	async fn fork(
+		&self,
+		repo_path: RepoPath,
+		target_org: Option<&str>,
+		name: Option<&str>,
+	) -> crate::Result<RepoInfo>;
+
+	/// 获取仓库的fork列表
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径
+	/// * `option` - 分页选项, 详见 [ListOptions]
+	///
+	async fn forks(
+		&self,
+		repo_path: RepoPath,
+		option: Option<ListOptions>,
+	) -> crate::Result<Vec<RepoInfo>>;
+
+	/// 将fork仓库的分支与上游仓库同步, 需要登录
+	///
+	/// 只有GitHub开放, Gitee、GitCode与CNB返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - fork仓库路径
+	/// * `branch` - 需要同步的分支, 为空时使用默认分支
+	///
+	async fn sync_fork(
+		&self,
+		repo_path: RepoPath,
+		branch: Option<&str>,
+	) -> crate::Result<SyncForkResult>;
+
+	/// 创建仓库, 需要登录
+	///
+	/// # 参数
+	///
+	/// * `org_name` - 组织名称, 为空时创建在当前用户下
+	/// * `name` - 仓库名称
+	/// * `option` - 创建仓库选项, 详见 [CreateOptions]
+	///
+	async fn create(
+		&self,
+		org_name: Option<&str>,
+		name: &str,
+		option: Option<CreateOptions>,
+	) -> crate::Result<RepoInfo>;
+
+	/// 更新仓库设置, 需要登录
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径
+	/// * `option` - 更新仓库选项, 详见 [UpdateOptions]
+	///
+	async fn update(&self, repo_path: RepoPath, option: UpdateOptions) -> crate::Result<RepoInfo>;
+
+	/// 删除仓库, 需要登录
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径
+	///
+	async fn delete(&self, repo_path: RepoPath) -> crate::Result<()>;
+
+	/// 归档或取消归档仓库, 归档后仓库变为只读, 需要登录
+	///
+	/// Gitee与GitCode未开放, 返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径
+	/// * `archived` - 是否归档
+	///
+	async fn set_archived(&self, repo_path: RepoPath, archived: bool) -> crate::Result<()>;
+
+	/// 转移仓库到其他用户或组织, 需要登录
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径
+	/// * `new_owner` - 新的所有者
+	/// * `new_name` - 转移后的仓库名称, 为空时保持不变
+	///
+	async fn transfer(
+		&self,
+		repo_path: RepoPath,
+		new_owner: &str,
+		new_name: Option<&str>,
+	) -> crate::Result<RepoInfo>;
+
+	/// 获取仓库部署公钥列表, 需要登录
+	///
+	/// CNB未开放, 返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径
+	/// * `option` - 列表选项, 详见 [ListOptions]
+	///
+	async fn deploy_keys(
+		&self,
+		repo_path: RepoPath,
+		option: Option<ListOptions>,
+	) -> crate::Result<Vec<DeployKeyInfo>>;
+
+	/// 添加仓库部署公钥, 发送前会在本地校验公钥格式, 需要登录
+	///
+	/// CNB未开放; Gitee的部署公钥只有拉取权限, `read_only`为`false`时返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径
+	/// * `title` - 公钥标题
+	/// * `key` - SSH公钥内容
+	/// * `read_only` - 是否只读, 为`false`时允许推送
+	///
+	async fn add_deploy_key(
+		&self,
+		repo_path: RepoPath,
+		title: &str,
+		key: &str,
+		read_only: bool,
+	) -> crate::Result<DeployKeyInfo>;
+
+	/// 删除仓库部署公钥, 需要登录
+	///
+	/// CNB未开放, 返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径
+	/// * `id` - 部署公钥ID
+	///
+	async fn remove_deploy_key(&self, repo_path: RepoPath, id: u64) -> crate::Result<()>;
+
+	/// 获取仓库主题列表
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径
+	///
+	async fn topics(&self, repo_path: RepoPath) -> crate::Result<Vec<String>>;
+
+	/// 替换仓库的全部主题, 需要登录
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径
+	/// * `topics` - 新的主题列表, 为空时清空主题
+	///
+	async fn set_topics(
+		&self,
+		repo_path: RepoPath,
+		topics: Vec<String>,
+	) -> crate::Result<Vec<String>>;
+
+	/// 获取仓库语言占比
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径
+	///
+	async fn languages(&self, repo_path: RepoPath) -> crate::Result<RepoLanguages>;
+
+	/// 获取仓库贡献者列表, 按提交数从多到少排列
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径
+	/// * `option` - 列表选项, 详见 [ListOptions]
+	///
+	async fn contributors(
+		&self,
+		repo_path: RepoPath,
+		option: Option<ListOptions>,
+	) -> crate::Result<Vec<ContributorInfo>>;
+
+	/// 下载仓库在指定引用下的源码压缩包到`writer`, 私有仓库需要登录
+	///
+	/// 压缩包内的文件位于一个顶层目录下, 可使用[crate::archive::extract]解压并去掉该目录
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径
+	/// * `reference` - 分支、标签或提交SHA
+	/// * `format` - 压缩格式, 详见 [ArchiveFormat]
+	/// * `writer` - 写入目标
+	///
+	async fn archive(
+		&self,
+		repo_path: RepoPath,
+		reference: &str,
+		format: ArchiveFormat,
+		writer: &mut (dyn AsyncWrite + Unpin + Send),
+	) -> crate::Result<DownloadResult>;
 }
@@ -0,0 +1,45 @@
+use crate::option::search::SearchQuery;
+use crate::types::issue::IssueInfo;
+use crate::types::repo::RepoInfo;
+use crate::types::search::{CodeInfo, SearchResult};
+use crate::types::user::UserInfo;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait Search {
+	/// 搜索仓库
+	///
+	/// CNB只能在`owner`指定的组织内搜索, 未指定时返回[InvalidParam](crate::Error::InvalidParam)
+	///
+	/// ## 参数
+	/// - `query` - 搜索条件, 详见 [SearchQuery]
+	///
+	async fn repos(&self, query: SearchQuery) -> crate::Result<SearchResult<RepoInfo>>;
+
+	/// 搜索议题
+	///
+	/// CNB未开放, 返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// ## 参数
+	/// - `query` - 搜索条件, 详见 [SearchQuery]
+	///
+	async fn issues(&self, query: SearchQuery) -> crate::Result<SearchResult<IssueInfo>>;
+
+	/// 搜索用户
+	///
+	/// CNB未开放, 返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// ## 参数
+	/// - `query` - 搜索条件, 详见 [SearchQuery]
+	///
+	async fn users(&self, query: SearchQuery) -> crate::Result<SearchResult<UserInfo>>;
+
+	/// 搜索代码
+	///
+	/// 只有GitHub开放, Gitee、GitCode与CNB返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// ## 参数
+	/// - `query` - 搜索条件, 详见 [SearchQuery]
+	///
+	async fn code(&self, query: SearchQuery) -> crate::Result<SearchResult<CodeInfo>>;
+}
@@ -0,0 +1,70 @@
+use crate::option::repo::ListOptions;
+use crate::types::secret::{SecretInfo, SecretScope, SecretValue, VariableInfo};
+use async_trait::async_trait;
+
+/// 持续集成的密钥与变量, 需要登录
+#[async_trait]
+pub trait Secret {
+	/// 获取密钥列表, 只包含名称与时间
+	///
+	/// ## 参数
+	/// * `scope` - 仓库或组织, 参考[SecretScope]
+	/// * `option` - 列表选项, 参考[ListOptions]
+	///
+	async fn list_secrets(
+		&self,
+		scope: SecretScope,
+		option: Option<ListOptions>,
+	) -> crate::Result<Vec<SecretInfo>>;
+
+	/// 创建或更新密钥, 需要时在本地加密后再上传
+	///
+	/// ## 参数
+	/// * `scope` - 仓库或组织
+	/// * `name` - 密钥名称
+	/// * `value` - 密钥的值
+	///
+	async fn set_secret(
+		&self,
+		scope: SecretScope,
+		name: &str,
+		value: &SecretValue,
+	) -> crate::Result<()>;
+
+	/// 删除密钥
+	///
+	/// ## 参数
+	/// * `scope` - 仓库或组织
+	/// * `name` - 密钥名称
+	///
+	async fn delete_secret(&self, scope: SecretScope, name: &str) -> crate::Result<()>;
+
+	/// 获取变量列表
+	///
+	/// ## 参数
+	/// * `scope` - 仓库或组织
+	/// * `option` - 列表选项, 参考[ListOptions]
+	///
+	async fn list_variables(
+		&self,
+		scope: SecretScope,
+		option: Option<ListOptions>,
+	) -> crate::Result<Vec<VariableInfo>>;
+
+	/// 创建或更新变量
+	///
+	/// ## 参数
+	/// * `scope` - 仓库或组织
+	/// * `name` - 变量名称
+	/// * `value` - 变量值
+	///
+	async fn set_variable(&self, scope: SecretScope, name: &str, value: &str) -> crate::Result<()>;
+
+	/// 删除变量
+	///
+	/// ## 参数
+	/// * `scope` - 仓库或组织
+	/// * `name` - 变量名称
+	///
+	async fn delete_variable(&self, scope: SecretScope, name: &str) -> crate::Result<()>;
+}
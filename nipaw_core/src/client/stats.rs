@@ -0,0 +1,49 @@
+use crate::option::stats::StatsOptions;
+use crate::types::repo::RepoPath;
+use crate::types::stats::{CodeFrequency, PunchCard, WeeklyCommitActivity};
+use async_trait::async_trait;
+
+/// 仓库提交统计, 平台没有统计接口时从提交列表本地计算
+#[async_trait]
+pub trait Stats {
+	/// 获取每周提交数
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径
+	/// * `option` - 统计选项, 详见 [StatsOptions]
+	///
+	async fn commit_activity(
+		&self,
+		repo_path: RepoPath,
+		option: Option<StatsOptions>,
+	) -> crate::Result<Vec<WeeklyCommitActivity>>;
+
+	/// 获取每周新增与删除的行数
+	///
+	/// 本地计算时需要逐个获取提交详情, 时间范围较大时请求较多
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径
+	/// * `option` - 统计选项, 详见 [StatsOptions]
+	///
+	async fn code_frequency(
+		&self,
+		repo_path: RepoPath,
+		option: Option<StatsOptions>,
+	) -> crate::Result<Vec<CodeFrequency>>;
+
+	/// 获取按星期与小时分布的提交数
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径
+	/// * `option` - 统计选项, 详见 [StatsOptions]
+	///
+	async fn punch_card(
+		&self,
+		repo_path: RepoPath,
+		option: Option<StatsOptions>,
+	) -> crate::Result<Vec<PunchCard>>;
+}
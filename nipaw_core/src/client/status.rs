@@ -0,0 +1,51 @@
+use crate::option::status::{CreateOptions, ListOptions};
+use crate::types::repo::RepoPath;
+use crate::types::status::{CombinedStatusInfo, StatusInfo, StatusState};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait Status {
+	/// 为提交创建一个状态
+	///
+	/// ## 参数
+	/// - `repo_path` - 仓库路径
+	/// - `sha` - 提交SHA
+	/// - `state` - 状态
+	/// - `option` - 创建状态选项, 详见 [CreateOptions]
+	///
+	async fn create(
+		&self,
+		repo_path: RepoPath,
+		sha: &str,
+		state: StatusState,
+		option: Option<CreateOptions>,
+	) -> crate::Result<StatusInfo>;
+
+	/// 获取引用的所有状态, 按创建时间倒序
+	///
+	/// ## 参数
+	/// - `repo_path` - 仓库路径
+	/// - `reference` - 提交SHA、分支或标签
+	/// - `option` - 列表选项, 详见 [ListOptions]
+	///
+	async fn list(
+		&self,
+		repo_path: RepoPath,
+		reference: &str,
+		option: Option<ListOptions>,
+	) -> crate::Result<Vec<StatusInfo>>;
+
+	/// 获取引用的合并状态
+	///
+	/// 任一检查失败或出错时为失败, 存在等待中的检查时为等待中, 否则为成功
+	///
+	/// ## 参数
+	/// - `repo_path` - 仓库路径
+	/// - `reference` - 提交SHA、分支或标签
+	///
+	async fn combined(
+		&self,
+		repo_path: RepoPath,
+		reference: &str,
+	) -> crate::Result<CombinedStatusInfo>;
+}
@@ -0,0 +1,37 @@
+use crate::types::repo::RepoPath;
+use crate::types::traffic::{TrafficPath, TrafficPer, TrafficReferrer, TrafficSeries};
+use async_trait::async_trait;
+
+/// 仓库流量统计, 需要仓库的推送权限, 平台未开放时返回[Unsupported](crate::Error::Unsupported)
+#[async_trait]
+pub trait Traffic {
+	/// 获取最近14天的访问量
+	///
+	/// ## 参数
+	/// - `repo_path` - 仓库路径
+	/// - `per` - 按天或按周汇总
+	///
+	async fn views(&self, repo_path: RepoPath, per: TrafficPer) -> crate::Result<TrafficSeries>;
+
+	/// 获取最近14天的克隆量
+	///
+	/// ## 参数
+	/// - `repo_path` - 仓库路径
+	/// - `per` - 按天或按周汇总
+	///
+	async fn clones(&self, repo_path: RepoPath, per: TrafficPer) -> crate::Result<TrafficSeries>;
+
+	/// 获取最近14天访问量最多的来源站点
+	///
+	/// ## 参数
+	/// - `repo_path` - 仓库路径
+	///
+	async fn referrers(&self, repo_path: RepoPath) -> crate::Result<Vec<TrafficReferrer>>;
+
+	/// 获取最近14天访问量最多的页面
+	///
+	/// ## 参数
+	/// - `repo_path` - 仓库路径
+	///
+	async fn paths(&self, repo_path: RepoPath) -> crate::Result<Vec<TrafficPath>>;
+}
@@ -1,4 +1,5 @@
 use crate::option::repo::ListOptions;
+use crate::types::org::OrgInfo;
 use crate::types::repo::RepoInfo;
 use crate::types::user::{ContributionResult, UserInfo};
 use async_trait::async_trait;
@@ -49,4 +50,92 @@ pub trait User {
 		user_name: Option<&str>,
 		option: Option<ListOptions>,
 	) -> crate::Result<Vec<RepoInfo>>;
+
+	/// 获取用户添加星标的仓库列表
+	///
+	/// 当参数`user_name`未为[None],则获取当前用户的星标仓库列表
+	///
+	/// # 参数
+	///
+	/// * `user_name` - 用户名
+	/// * `option` - 分页选项, 详见 [ListOptions]
+	///
+	async fn starred_repos(
+		&self,
+		user_name: Option<&str>,
+		option: Option<ListOptions>,
+	) -> crate::Result<Vec<RepoInfo>>;
+
+	/// 获取用户的关注者列表
+	///
+	/// 当参数`user_name`未为[None],则获取当前用户的关注者列表
+	///
+	/// # 参数
+	///
+	/// * `user_name` - 用户名
+	/// * `option` - 分页选项, 详见 [ListOptions]
+	///
+	async fn followers(
+		&self,
+		user_name: Option<&str>,
+		option: Option<ListOptions>,
+	) -> crate::Result<Vec<UserInfo>>;
+
+	/// 获取用户正在关注的用户列表
+	///
+	/// 当参数`user_name`未为[None],则获取当前用户正在关注的用户列表
+	///
+	/// # 参数
+	///
+	/// * `user_name` - 用户名
+	/// * `option` - 分页选项, 详见 [ListOptions]
+	///
+	async fn following(
+		&self,
+		user_name: Option<&str>,
+		option: Option<ListOptions>,
+	) -> crate::Result<Vec<UserInfo>>;
+
+	/// 关注用户, 需要登录
+	///
+	/// CNB未开放, 返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// # 参数
+	///
+	/// * `user_name` - 用户名
+	///
+	async fn follow(&self, user_name: &str) -> crate::Result<()>;
+
+	/// 取消关注用户, 需要登录
+	///
+	/// CNB未开放, 返回[Unsupported](crate::Error::Unsupported)
+	///
+	/// # 参数
+	///
+	/// * `user_name` - 用户名
+	///
+	async fn unfollow(&self, user_name: &str) -> crate::Result<()>;
+
+	/// 当前用户是否已关注该用户
+	///
+	/// # 参数
+	///
+	/// * `user_name` - 用户名
+	///
+	async fn is_following(&self, user_name: &str) -> crate::Result<bool>;
+
+	/// 获取用户所属的组织列表
+	///
+	/// 当参数`user_name`未为[None],则获取当前用户所属的组织列表
+	///
+	/// # 参数
+	///
+	/// * `user_name` - 用户名
+	/// * `option` - 分页选项, 详见 [ListOptions]
+	///
+	async fn org_list(
+		&self,
+		user_name: Option<&str>,
+		option: Option<ListOptions>,
+	) -> crate::Result<Vec<OrgInfo>>;
 }
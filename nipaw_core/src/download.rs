@@ -0,0 +1,204 @@
+//! 下载辅助函数
+//!
+//! 各平台只负责构造带鉴权的请求, 续传、进度与校验在这里统一处理
+
+use crate::option::release::{Checksum, DownloadOptions, ProgressCallback};
+use crate::types::release::{DownloadProgress, DownloadResult};
+use crate::{Error, Result};
+use reqwest::StatusCode;
+use reqwest::header::RANGE;
+use reqwest_middleware::RequestBuilder;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::path::Path;
+use tokio::fs::OpenOptions;
+use tokio::io::{AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+/// 将请求的响应写入`writer`
+///
+/// `options.offset`大于0时视为续传, 只写入`offset`之后的内容;
+/// 需要校验时会重新下载完整内容以计算摘要
+///
+/// ## 参数
+/// * `request` - 已设置鉴权的请求
+/// * `writer` - 写入目标
+/// * `options` - 下载选项
+/// * `expected` - 期望的SHA-256摘要, 为[None]时不校验
+pub async fn to_writer<W>(
+	request: RequestBuilder,
+	writer: &mut W,
+	options: &DownloadOptions,
+	expected: Option<String>,
+) -> Result<DownloadResult>
+where
+	W: AsyncWrite + Unpin + Send + ?Sized,
+{
+	let hasher = expected.as_ref().map(|_| Sha256::new());
+	let range = expected.is_none();
+	transfer(request, writer, options.offset, hasher, range, options.progress.as_ref(), expected)
+		.await
+}
+
+/// 将请求的响应写入文件, 文件已存在时从文件末尾续传
+///
+/// ## 参数
+/// * `request` - 已设置鉴权的请求
+/// * `path` - 文件路径
+/// * `options` - 下载选项, 其中`offset`会被文件长度覆盖
+/// * `expected` - 期望的SHA-256摘要, 为[None]时不校验
+pub async fn to_file(
+	request: RequestBuilder,
+	path: &Path,
+	options: &DownloadOptions,
+	expected: Option<String>,
+) -> Result<DownloadResult> {
+	let mut file = OpenOptions::new().create(true).read(true).append(true).open(path).await?;
+	let offset = file.metadata().await?.len();
+	let hasher = match expected {
+		Some(_) => {
+			let mut hasher = Sha256::new();
+			let mut buf = vec![0u8; 64 * 1024];
+			loop {
+				let n = file.read(&mut buf).await?;
+				if n == 0 {
+					break;
+				}
+				hasher.update(&buf[..n]);
+			}
+			Some(hasher)
+		}
+		None => None,
+	};
+	transfer(request, &mut file, offset, hasher, true, options.progress.as_ref(), expected).await
+}
+
+/// 构造附件下载请求, 同时解析出期望的SHA-256摘要
+///
+/// ## 参数
+/// * `release` - 发布信息的原始JSON, 附件位于`assets`字段
+/// * `asset_name` - 附件名称
+/// * `checksum` - 校验方式, 为[None]时不校验
+/// * `asset_url` - 从附件信息中取出下载地址
+/// * `build` - 为下载地址构造带鉴权的请求
+pub async fn asset_request<U, B>(
+	release: &Value,
+	asset_name: &str,
+	checksum: Option<&Checksum>,
+	asset_url: U,
+	build: B,
+) -> Result<(RequestBuilder, Option<String>)>
+where
+	U: Fn(&Value) -> Option<String>,
+	B: Fn(String) -> RequestBuilder,
+{
+	let find_url = |name: &str| {
+		release
+			.get("assets")
+			.and_then(|v| v.as_array())
+			.and_then(|arr| {
+				arr.iter().find(|v| v.get("name").and_then(|n| n.as_str()) == Some(name))
+			})
+			.and_then(&asset_url)
+			.ok_or(Error::NotFound { resource: name.to_string() })
+	};
+	let expected = match checksum {
+		Some(Checksum::Sha256(digest)) => Some(digest.to_owned()),
+		Some(Checksum::Sibling) => {
+			let sibling_name = format!("{}.sha256", asset_name);
+			let content = build(find_url(&sibling_name)?).send().await?.text().await?;
+			let digest = parse_sha256(&content, asset_name).ok_or(Error::InvalidParam {
+				param: "checksum".to_string(),
+				reason: format!("{} does not contain a sha256 digest", sibling_name),
+			})?;
+			Some(digest)
+		}
+		None => None,
+	};
+	Ok((build(find_url(asset_name)?), expected))
+}
+
+/// 从`.sha256`文件内容中解析出摘要
+///
+/// 支持`sha256sum`的输出格式, 多行时优先匹配`file_name`对应的行
+pub fn parse_sha256(content: &str, file_name: &str) -> Option<String> {
+	let is_digest = |s: &str| s.len() == 64 && s.chars().all(|c| c.is_ascii_hexdigit());
+	let mut lines = content.lines().filter_map(|line| {
+		let mut parts = line.split_whitespace();
+		let digest = parts.next().filter(|s| is_digest(s))?;
+		let name = parts.next().map(|s| s.trim_start_matches('*'));
+		Some((digest, name))
+	});
+	let first = lines.next()?;
+	let (digest, _) = std::iter::once(first)
+		.chain(lines)
+		.find(|(_, name)| *name == Some(file_name))
+		.unwrap_or(first);
+	Some(digest.to_lowercase())
+}
+
+async fn transfer<W>(
+	request: RequestBuilder,
+	writer: &mut W,
+	offset: u64,
+	mut hasher: Option<Sha256>,
+	range: bool,
+	progress: Option<&ProgressCallback>,
+	expected: Option<String>,
+) -> Result<DownloadResult>
+where
+	W: AsyncWrite + Unpin + Send + ?Sized,
+{
+	let request = if range && offset > 0 {
+		request.header(RANGE, format!("bytes={}-", offset))
+	} else {
+		request
+	};
+	let response = request.send().await?;
+	if range && offset > 0 && response.status() == StatusCode::RANGE_NOT_SATISFIABLE {
+		let sha256 = verify(hasher, expected)?;
+		return Ok(DownloadResult { written: 0, size: offset, resumed: true, sha256 });
+	}
+	let mut response = response.error_for_status()?;
+	let resumed = offset > 0 && response.status() == StatusCode::PARTIAL_CONTENT;
+	let total = response.content_length().map(|len| if resumed { len + offset } else { len });
+
+	// 服务端忽略Range时返回完整内容, 跳过已写入的部分
+	let mut skip = if resumed { 0 } else { offset };
+	let mut downloaded = if resumed { offset } else { 0 };
+	let mut written = 0u64;
+	while let Some(chunk) = response.chunk().await? {
+		let n = skip.min(chunk.len() as u64) as usize;
+		skip -= n as u64;
+		// 未发送Range时摘要需要覆盖完整内容, 否则跳过的部分已计入或无需校验
+		if let Some(hasher) = hasher.as_mut()
+			&& !range
+		{
+			hasher.update(&chunk[..n]);
+		}
+		let data = &chunk[n..];
+		writer.write_all(data).await?;
+		if let Some(hasher) = hasher.as_mut() {
+			hasher.update(data);
+		}
+		written += data.len() as u64;
+		downloaded += chunk.len() as u64;
+		if let Some(progress) = progress {
+			progress(DownloadProgress { downloaded, total });
+		}
+	}
+	writer.flush().await?;
+	let sha256 = verify(hasher, expected)?;
+	Ok(DownloadResult { written, size: downloaded, resumed, sha256 })
+}
+
+fn verify(hasher: Option<Sha256>, expected: Option<String>) -> Result<Option<String>> {
+	let (Some(hasher), Some(expected)) = (hasher, expected) else {
+		return Ok(None);
+	};
+	let actual = format!("{:x}", hasher.finalize());
+	let expected = expected.trim().to_lowercase();
+	if actual != expected {
+		return Err(Error::ChecksumMismatch { expected, actual });
+	}
+	Ok(Some(actual))
+}
@@ -23,6 +23,9 @@ pub enum Error {
 	#[error("json serialize/deserialize error: {0}")]
 	JsonError(#[from] serde_json::Error),
 
+	#[error("io error: {0}")]
+	IoError(#[from] std::io::Error),
+
 	#[error("not found: {resource}")]
 	NotFound {
 		resource: String
@@ -43,5 +46,44 @@ pub enum Error {
 	Timeout {
 		duration: std::time::Duration
 	},
+
+	#[error("{platform} does not support {feature}")]
+	Unsupported {
+		platform: String,
+		feature: String
+	},
+
+	#[error("{resource} is still being computed, retry later")]
+	NotReady {
+		resource: String
+	},
+
+	#[error("checksum mismatch: expected {expected}, got {actual}")]
+	ChecksumMismatch {
+		expected: String,
+		actual: String
+	},
 }
 
+
+impl Error {
+	/// 构造平台不支持某项功能的错误
+	///
+	/// ## 参数
+	/// * `platform` - 平台名称, 如`Gitee`
+	/// * `feature` - 功能描述, 如`issue lock`
+	pub fn unsupported(platform: &str, feature: &str) -> Self {
+		Error::Unsupported { platform: platform.to_string(), feature: feature.to_string() }
+	}
+
+	/// 是否为资源不存在, 包括由中间件返回的404错误
+	pub fn is_not_found(&self) -> bool {
+		match self {
+			Error::NotFound { .. } => true,
+			Error::MiddlewareError(reqwest_middleware::Error::Middleware(err)) => {
+				matches!(err.downcast_ref::<Error>(), Some(Error::NotFound { .. }))
+			}
+			_ => false,
+		}
+	}
+}
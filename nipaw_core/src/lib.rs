@@ -1,7 +1,13 @@
+pub mod archive;
 mod client;
+pub mod download;
 pub mod error;
+pub mod stats;
 
-pub use client::{Client, Commit, Config, Issue, Org, Provider, Proxy, Release, Repo, Token, User};
+pub use client::{
+	Client, Commit, Config, Gist, Git, Issue, Key, Notifications, Org, Pipeline, Provider, Proxy,
+	Release, Repo, Search, Secret, Stats, Status, Token, Traffic, User,
+};
 pub use error::Error;
 pub mod option;
 pub mod types;
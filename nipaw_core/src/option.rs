@@ -1,7 +1,14 @@
 pub mod commit;
+pub mod gist;
+pub mod git;
 pub mod issue;
+pub mod notification;
+pub mod pipeline;
 pub mod release;
 pub mod repo;
+pub mod search;
+pub mod stats;
+pub mod status;
 pub(super) const fn default_per_page() -> Option<u32> {
 	Some(30)
 }
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+pub struct CreateOptions {
+	/// 代码片段描述
+	pub description: Option<String>,
+	/// 是否公开, 默认为`false`
+	pub public: Option<bool>,
+	/// 文件名与文件内容, 至少包含一个文件
+	pub files: HashMap<String, String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+pub struct UpdateOptions {
+	/// 代码片段描述
+	pub description: Option<String>,
+	/// 需要修改的文件名与新内容, 内容为[None]时删除该文件
+	pub files: HashMap<String, Option<String>>,
+}
@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct TreeEntryOptions {
+	/// 相对于树根的路径
+	pub path: String,
+	/// 文件模式, 默认`100644`
+	pub mode: Option<String>,
+	/// 文件内容, 与`sha`二选一
+	pub content: Option<String>,
+	/// 已有对象的SHA, 与`content`二选一, 两者都为[None]时表示删除该路径
+	pub sha: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FileChange {
+	/// 文件路径
+	pub path: String,
+	/// 新的文件内容, 为[None]时删除该文件
+	pub content: Option<FileContent>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub enum FileContent {
+	/// 文本内容
+	Text(String),
+	/// 二进制内容, 以base64上传
+	Binary(Vec<u8>),
+}
+
+impl FileContent {
+	pub fn as_bytes(&self) -> &[u8] {
+		match self {
+			FileContent::Text(text) => text.as_bytes(),
+			FileContent::Binary(bytes) => bytes,
+		}
+	}
+}
+
+impl From<String> for FileContent {
+	fn from(value: String) -> Self {
+		FileContent::Text(value)
+	}
+}
+
+impl From<&str> for FileContent {
+	fn from(value: &str) -> Self {
+		FileContent::Text(value.to_string())
+	}
+}
+
+impl From<Vec<u8>> for FileContent {
+	fn from(value: Vec<u8>) -> Self {
+		FileContent::Binary(value)
+	}
+}
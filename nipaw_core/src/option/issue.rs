@@ -37,3 +37,13 @@ pub struct ListOptions {
 	/// 状态
 	pub state: Option<StateType>,
 }
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct EventListOptions {
+	/// 每页数量，默认 30，最大 100
+	#[serde(default = "default_per_page")]
+	pub per_page: Option<u32>,
+	/// 页码，默认 1
+	#[serde(default = "default_page")]
+	pub page: Option<u32>,
+}
@@ -0,0 +1,22 @@
+use super::{default_page, default_per_page};
+use crate::types::repo::RepoPath;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+pub struct ListOptions {
+	/// 每页数量，默认 30，最大 100
+	#[serde(default = "default_per_page")]
+	pub per_page: Option<u32>,
+	/// 页码，默认 1
+	#[serde(default = "default_page")]
+	pub page: Option<u32>,
+	/// 是否只获取未读通知, 默认为`true`
+	pub unread: Option<bool>,
+	/// 是否只获取直接参与或被提及的通知
+	pub participating: Option<bool>,
+	/// 只获取此时间之后更新的通知
+	pub since: Option<DateTime<Utc>>,
+	/// 只获取此仓库的通知
+	pub repo_path: Option<RepoPath>,
+}
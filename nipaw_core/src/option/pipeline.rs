@@ -0,0 +1,28 @@
+use super::{default_page, default_per_page};
+use crate::types::pipeline::PipelineStatus;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+pub struct ListOptions {
+	/// 每页数量，默认 30，最大 100
+	#[serde(default = "default_per_page")]
+	pub per_page: Option<u32>,
+	/// 页码，默认 1
+	#[serde(default = "default_page")]
+	pub page: Option<u32>,
+	/// 筛选分支
+	pub branch: Option<String>,
+	/// 筛选状态, 不能为[PipelineStatus::Unknown]
+	pub status: Option<PipelineStatus>,
+	/// 筛选触发事件, 如`push`、`pull_request`
+	pub event: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+pub struct TriggerOptions {
+	/// 触发的分支或标签
+	pub reference: String,
+	/// 输入参数, GitHub为工作流的`inputs`, CNB为构建的环境变量
+	pub inputs: HashMap<String, String>,
+}
@@ -1,4 +1,7 @@
+use crate::types::release::DownloadProgress;
 use serde::{Deserialize, Serialize};
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
 
 #[derive(Debug, Deserialize, Serialize, Default)]
 pub struct UpdateOption {
@@ -7,3 +10,36 @@ pub struct UpdateOption {
 	/// 发行正文
 	pub body: Option<String>,
 }
+
+/// 下载进度回调
+pub type ProgressCallback = Arc<dyn Fn(DownloadProgress) + Send + Sync>;
+
+#[derive(Clone, Default)]
+pub struct DownloadOptions {
+	/// 已下载的字节数, 大于0时通过`Range`请求续传
+	///
+	/// 下载到文件时会使用文件的现有长度
+	pub offset: u64,
+	/// 校验方式, 默认不校验
+	pub checksum: Option<Checksum>,
+	/// 下载进度回调
+	pub progress: Option<ProgressCallback>,
+}
+
+impl Debug for DownloadOptions {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("DownloadOptions")
+			.field("offset", &self.offset)
+			.field("checksum", &self.checksum)
+			.field("progress", &self.progress.is_some())
+			.finish()
+	}
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Checksum {
+	/// 使用给定的SHA-256摘要, 16进制
+	Sha256(String),
+	/// 使用同一Release中名为`<附件名>.sha256`的附件
+	Sibling,
+}
@@ -1,4 +1,5 @@
 use super::{default_page, default_per_page};
+use crate::types::repo::Visibility;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -17,3 +18,39 @@ impl Default for ListOptions {
 		Self { per_page: default_per_page(), page: default_page() }
 	}
 }
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+pub struct CreateOptions {
+	/// 仓库描述
+	pub description: Option<String>,
+	/// 主页地址
+	pub homepage: Option<String>,
+	/// 可见性, 默认为公开
+	pub visibility: Option<Visibility>,
+	/// 是否使用README初始化仓库
+	pub auto_init: Option<bool>,
+	/// .gitignore模板名称, 如`Rust`
+	pub gitignore_template: Option<String>,
+	/// 开源许可证模板名称, 如`MIT`
+	pub license_template: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+pub struct UpdateOptions {
+	/// 仓库名称
+	pub name: Option<String>,
+	/// 仓库描述
+	pub description: Option<String>,
+	/// 主页地址
+	pub homepage: Option<String>,
+	/// 默认分支
+	pub default_branch: Option<String>,
+	/// 可见性
+	pub visibility: Option<Visibility>,
+	/// 是否启用issue
+	pub has_issues: Option<bool>,
+	/// 是否启用wiki
+	pub has_wiki: Option<bool>,
+	/// 是否启用项目看板
+	pub has_projects: Option<bool>,
+}
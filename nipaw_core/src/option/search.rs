@@ -0,0 +1,138 @@
+use super::{default_page, default_per_page};
+use crate::types::issue::StateType;
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString, IntoStaticStr};
+
+/// 搜索条件, 由各平台转换为自身的搜索语法
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SearchQuery {
+	/// 关键字
+	pub keywords: Vec<String>,
+	/// 编程语言
+	pub language: Option<String>,
+	/// 用户或组织
+	pub owner: Option<String>,
+	/// 议题状态, 仅搜索议题时有效
+	pub state: Option<StateType>,
+	/// 排序字段, 平台不支持的字段会被忽略
+	pub sort: Option<SearchSort>,
+	/// 排序方向
+	pub order: Option<SearchOrder>,
+	/// 每页数量，默认 30，最大 100
+	#[serde(default = "default_per_page")]
+	pub per_page: Option<u32>,
+	/// 页码，默认 1
+	#[serde(default = "default_page")]
+	pub page: Option<u32>,
+}
+
+impl Default for SearchQuery {
+	#[inline]
+	fn default() -> Self {
+		Self {
+			keywords: Vec::new(),
+			language: None,
+			owner: None,
+			state: None,
+			sort: None,
+			order: None,
+			per_page: default_per_page(),
+			page: default_page(),
+		}
+	}
+}
+
+impl SearchQuery {
+	pub fn new(keyword: impl Into<String>) -> Self {
+		Self::default().keyword(keyword)
+	}
+
+	/// 追加关键字
+	pub fn keyword(mut self, keyword: impl Into<String>) -> Self {
+		self.keywords.push(keyword.into());
+		self
+	}
+
+	/// 限定编程语言
+	pub fn language(mut self, language: impl Into<String>) -> Self {
+		self.language = Some(language.into());
+		self
+	}
+
+	/// 限定用户或组织
+	pub fn owner(mut self, owner: impl Into<String>) -> Self {
+		self.owner = Some(owner.into());
+		self
+	}
+
+	/// 限定议题状态
+	pub fn state(mut self, state: StateType) -> Self {
+		self.state = Some(state);
+		self
+	}
+
+	/// 设置排序字段
+	pub fn sort(mut self, sort: SearchSort) -> Self {
+		self.sort = Some(sort);
+		self
+	}
+
+	/// 设置排序方向
+	pub fn order(mut self, order: SearchOrder) -> Self {
+		self.order = Some(order);
+		self
+	}
+
+	/// 设置每页数量
+	pub fn per_page(mut self, per_page: u32) -> Self {
+		self.per_page = Some(per_page);
+		self
+	}
+
+	/// 设置页码
+	pub fn page(mut self, page: u32) -> Self {
+		self.page = Some(page);
+		self
+	}
+
+	/// 以空格连接的关键字
+	pub fn keywords_text(&self) -> String {
+		self.keywords.join(" ")
+	}
+}
+
+#[derive(
+	Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Display, EnumString, IntoStaticStr,
+)]
+pub enum SearchSort {
+	#[strum(serialize = "stars")]
+	/// 星标数, 仅仓库
+	Stars,
+	#[strum(serialize = "forks")]
+	/// fork数, 仅仓库
+	Forks,
+	#[strum(serialize = "comments")]
+	/// 评论数, 仅议题
+	Comments,
+	#[strum(serialize = "followers")]
+	/// 关注者数, 仅用户
+	Followers,
+	#[strum(serialize = "created")]
+	/// 创建时间
+	Created,
+	#[strum(serialize = "updated")]
+	/// 更新时间
+	Updated,
+}
+
+#[derive(
+	Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Display, EnumString, IntoStaticStr,
+)]
+pub enum SearchOrder {
+	#[strum(serialize = "asc")]
+	/// 升序
+	Asc,
+	#[strum(serialize = "desc")]
+	/// 降序
+	Desc,
+}
@@ -0,0 +1,10 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+pub struct StatsOptions {
+	/// 统计开始时间, 默认为结束时间前52周
+	pub since: Option<DateTime<Utc>>,
+	/// 统计结束时间, 默认为当前时间
+	pub until: Option<DateTime<Utc>>,
+}
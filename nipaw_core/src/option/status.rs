@@ -0,0 +1,22 @@
+use super::{default_page, default_per_page};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+pub struct CreateOptions {
+	/// 状态的上下文标识, 默认为`default`
+	pub context: Option<String>,
+	/// 状态描述
+	pub description: Option<String>,
+	/// 详情链接
+	pub target_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+pub struct ListOptions {
+	/// 每页数量，默认 30，最大 100
+	#[serde(default = "default_per_page")]
+	pub per_page: Option<u32>,
+	/// 页码，默认 1
+	#[serde(default = "default_page")]
+	pub page: Option<u32>,
+}
@@ -0,0 +1,209 @@
+//! 统计辅助函数
+//!
+//! 平台没有统计接口时, 从分页的提交列表在本地计算统计数据
+
+use crate::{Commit, Result, Stats};
+use crate::option::commit::ListOptions;
+use crate::option::repo::ListOptions as RepoListOptions;
+use crate::option::stats::StatsOptions;
+use crate::types::commit::CommitListInfo;
+use crate::types::repo::{ContributorInfo, RepoPath};
+use crate::types::stats::{CodeFrequency, PunchCard, WeeklyCommitActivity};
+use async_trait::async_trait;
+use chrono::{DateTime, Datelike, Duration, Timelike, Utc};
+use std::collections::{BTreeMap, HashMap};
+
+/// 从提交列表本地计算统计数据, 供没有统计接口的平台使用
+///
+/// `C`为平台的提交接口
+pub struct CommitStats<C>(pub C);
+
+#[async_trait]
+impl<C> Stats for CommitStats<C>
+where
+	C: Commit + Send + Sync,
+{
+	async fn commit_activity(
+		&self,
+		repo_path: RepoPath,
+		option: Option<StatsOptions>,
+	) -> Result<Vec<WeeklyCommitActivity>> {
+		let (since, until) = time_range(&option.unwrap_or_default());
+		let commits = collect_commits(&self.0, &repo_path, Some(since), Some(until)).await?;
+		Ok(commit_activity(&commits, since, until))
+	}
+
+	async fn code_frequency(
+		&self,
+		repo_path: RepoPath,
+		option: Option<StatsOptions>,
+	) -> Result<Vec<CodeFrequency>> {
+		let (since, until) = time_range(&option.unwrap_or_default());
+		let commits = collect_commits(&self.0, &repo_path, Some(since), Some(until)).await?;
+		code_frequency(&self.0, &repo_path, &commits).await
+	}
+
+	async fn punch_card(
+		&self,
+		repo_path: RepoPath,
+		option: Option<StatsOptions>,
+	) -> Result<Vec<PunchCard>> {
+		let (since, until) = time_range(&option.unwrap_or_default());
+		let commits = collect_commits(&self.0, &repo_path, Some(since), Some(until)).await?;
+		Ok(punch_card(&commits))
+	}
+}
+
+/// 获取统计的时间范围, 未指定时为最近52周
+pub fn time_range(option: &StatsOptions) -> (DateTime<Utc>, DateTime<Utc>) {
+	let until = option.until.unwrap_or_else(Utc::now);
+	let since = option.since.unwrap_or(until - Duration::weeks(52));
+	(since, until)
+}
+
+/// 获取时间所在周的起始时间, 即周日零点
+pub fn week_start(time: DateTime<Utc>) -> DateTime<Utc> {
+	let days = time.weekday().num_days_from_sunday() as i64;
+	let date = time.date_naive() - Duration::days(days);
+	date.and_hms_opt(0, 0, 0).unwrap().and_utc()
+}
+
+/// 获取时间范围内的全部提交
+///
+/// ## 参数
+/// * `commit` - 平台的提交接口
+/// * `repo_path` - 仓库路径
+/// * `since` - 开始时间, 为[None]时不限制
+/// * `until` - 结束时间, 为[None]时不限制
+pub async fn collect_commits<C>(
+	commit: &C,
+	repo_path: &RepoPath,
+	since: Option<DateTime<Utc>>,
+	until: Option<DateTime<Utc>>,
+) -> Result<Vec<CommitListInfo>>
+where
+	C: Commit + Sync + ?Sized,
+{
+	let mut commits: Vec<CommitListInfo> = Vec::new();
+	for page in 1.. {
+		let option = ListOptions {
+			per_page: Some(100),
+			page: Some(page),
+			since,
+			until,
+			..Default::default()
+		};
+		let list = commit.list(repo_path.clone(), Some(option)).await?;
+		// 平台可能限制或忽略分页大小, 以空页作为结束; 忽略页码时会重复返回第一页
+		let first = list.first().map(|c| c.sha.as_str());
+		if first.is_none() || first == commits.first().map(|c| c.sha.as_str()) {
+			break;
+		}
+		commits.extend(list);
+	}
+	Ok(commits)
+}
+
+/// 按周统计提交数, 没有提交的周也会包含在结果中
+pub fn commit_activity(
+	commits: &[CommitListInfo],
+	since: DateTime<Utc>,
+	until: DateTime<Utc>,
+) -> Vec<WeeklyCommitActivity> {
+	let mut weeks: BTreeMap<DateTime<Utc>, [u64; 7]> = BTreeMap::new();
+	let mut week = week_start(since);
+	while week <= until {
+		weeks.insert(week, [0; 7]);
+		week += Duration::weeks(1);
+	}
+	for commit in commits {
+		let date = commit.commit.author.date;
+		if let Some(days) = weeks.get_mut(&week_start(date)) {
+			days[date.weekday().num_days_from_sunday() as usize] += 1;
+		}
+	}
+	weeks
+		.into_iter()
+		.map(|(week, days)| WeeklyCommitActivity {
+			week,
+			total: days.iter().sum(),
+			days: days.to_vec(),
+		})
+		.collect()
+}
+
+/// 统计每个星期与小时的提交数, 结果固定为`7 * 24`项
+pub fn punch_card(commits: &[CommitListInfo]) -> Vec<PunchCard> {
+	let mut counts = [[0u64; 24]; 7];
+	for commit in commits {
+		let date = commit.commit.author.date;
+		counts[date.weekday().num_days_from_sunday() as usize][date.hour() as usize] += 1;
+	}
+	counts
+		.iter()
+		.enumerate()
+		.flat_map(|(weekday, hours)| {
+			hours.iter().enumerate().map(move |(hour, &commits)| PunchCard {
+				weekday: weekday as u8,
+				hour: hour as u8,
+				commits,
+			})
+		})
+		.collect()
+}
+
+/// 逐个获取提交详情, 按周统计新增与删除的行数
+///
+/// ## 参数
+/// * `commit` - 平台的提交接口
+/// * `repo_path` - 仓库路径
+/// * `commits` - 需要统计的提交
+pub async fn code_frequency<C>(
+	commit: &C,
+	repo_path: &RepoPath,
+	commits: &[CommitListInfo],
+) -> Result<Vec<CodeFrequency>>
+where
+	C: Commit + Sync + ?Sized,
+{
+	let mut weeks: BTreeMap<DateTime<Utc>, (u64, u64)> = BTreeMap::new();
+	for item in commits {
+		let info = commit.info(repo_path.clone(), Some(item.sha.as_str())).await?;
+		let entry = weeks.entry(week_start(item.commit.author.date)).or_default();
+		entry.0 += info.stats.additions;
+		entry.1 += info.stats.deletions;
+	}
+	Ok(weeks
+		.into_iter()
+		.map(|(week, (additions, deletions))| CodeFrequency { week, additions, deletions })
+		.collect())
+}
+
+/// 按作者统计提交数, 结果按提交数从多到少排列
+pub fn contributors(commits: &[CommitListInfo]) -> Vec<ContributorInfo> {
+	let mut authors: HashMap<String, ContributorInfo> = HashMap::new();
+	for commit in commits {
+		let author = &commit.commit.author;
+		let key = author.email.clone().unwrap_or_else(|| author.name.clone());
+		let contributor = authors.entry(key).or_insert_with(|| ContributorInfo {
+			login: author.name.clone(),
+			email: author.email.clone(),
+			avatar_url: Some(author.avatar_url.clone()).filter(|s| !s.is_empty()),
+			contributions: 0,
+		});
+		contributor.contributions += 1;
+	}
+	let mut contributors: Vec<ContributorInfo> = authors.into_values().collect();
+	contributors.sort_by(|a, b| b.contributions.cmp(&a.contributions).then(a.login.cmp(&b.login)));
+	contributors
+}
+
+/// 对本地计算的完整列表分页
+pub fn paginate<T>(items: Vec<T>, option: Option<RepoListOptions>) -> Vec<T> {
+	let Some(option) = option else {
+		return items;
+	};
+	let per_page = option.per_page.unwrap_or(30).clamp(1, 100) as usize;
+	let page = option.page.unwrap_or(1).max(1) as usize;
+	items.into_iter().skip((page - 1) * per_page).take(per_page).collect()
+}
@@ -1,6 +1,17 @@
 pub mod commit;
+pub mod diff;
+pub mod gist;
+pub mod git;
 pub mod issue;
+pub mod key;
+pub mod notification;
 pub mod org;
+pub mod pipeline;
 pub mod release;
 pub mod repo;
+pub mod search;
+pub mod secret;
+pub mod stats;
+pub mod status;
+pub mod traffic;
 pub mod user;
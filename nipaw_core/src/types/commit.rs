@@ -21,6 +21,18 @@ pub struct CommitListInfo {
 	pub commit: CommitData,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompareInfo {
+	/// head领先base的提交数
+	pub ahead_by: u64,
+	/// head落后base的提交数
+	pub behind_by: u64,
+	/// base与head之间的提交
+	pub commits: Vec<CommitListInfo>,
+	/// 文件差异状态
+	pub files: Vec<FileInfo>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CommitData {
 	/// 作者信息
@@ -65,6 +77,39 @@ pub struct FileInfo {
 	pub deletions: u64,
 	/// 修改行数
 	pub changes: u64,
+	/// 文件差异内容, 二进制文件或差异过大时为[None]
+	pub patch: Option<String>,
+	/// 重命名前的文件名
+	pub previous_file_name: Option<String>,
+	/// 文件的blob SHA
+	pub sha: Option<String>,
+	/// 文件原始内容URL
+	pub raw_url: Option<String>,
+}
+
+impl FileInfo {
+	/// 将文件差异转换为带文件头的统一差异格式, 没有差异内容时为[None]
+	pub fn to_unified_diff(&self) -> Option<String> {
+		let patch = self.patch.as_deref()?;
+		let old_name = self.previous_file_name.as_deref().unwrap_or(&self.file_name);
+		let old_path = match self.status {
+			FileStatus::Added => "/dev/null".to_string(),
+			_ => format!("a/{}", old_name),
+		};
+		let new_path = match self.status {
+			FileStatus::Deleted => "/dev/null".to_string(),
+			_ => format!("b/{}", self.file_name),
+		};
+		let mut diff = format!(
+			"diff --git a/{} b/{}\n--- {}\n+++ {}\n",
+			old_name, self.file_name, old_path, new_path
+		);
+		diff.push_str(patch);
+		if !patch.ends_with('\n') {
+			diff.push('\n');
+		}
+		Some(diff)
+	}
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -0,0 +1,498 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiffFile {
+	/// 变更前的文件路径, 新增文件为[None]
+	pub old_path: Option<String>,
+	/// 变更后的文件路径, 删除文件为[None]
+	pub new_path: Option<String>,
+	/// 差异块
+	pub hunks: Vec<DiffHunk>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiffHunk {
+	/// 变更前的起始行号
+	pub old_start: u32,
+	/// 变更前的行数
+	pub old_lines: u32,
+	/// 变更后的起始行号
+	pub new_start: u32,
+	/// 变更后的行数
+	pub new_lines: u32,
+	/// `@@`之后的上下文说明, 通常为所在函数
+	pub header: Option<String>,
+	/// 差异行
+	pub lines: Vec<DiffLine>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiffLine {
+	/// 行类型
+	pub kind: DiffLineKind,
+	/// 行内容, 不包含前缀符号
+	pub content: String,
+	/// 在变更前文件中的行号, 新增行为[None]
+	pub old_line: Option<u32>,
+	/// 在变更后文件中的行号, 删除行为[None]
+	pub new_line: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DiffLineKind {
+	/// 上下文行
+	Context,
+	/// 新增行
+	Addition,
+	/// 删除行
+	Deletion,
+}
+
+impl DiffFile {
+	/// 解析统一差异格式(unified diff)文本, 支持`git diff`输出的多文件差异
+	pub fn parse(diff: &str) -> Vec<DiffFile> {
+		let mut files: Vec<DiffFile> = Vec::new();
+		let mut patch = String::new();
+		let mut current: Option<DiffFile> = None;
+		let mut in_header = false;
+		// 当前差异块剩余的变更前、变更后行数, 耗尽后才识别文件头,
+		// 避免把内容为`-- x`的删除行与`++ y`的新增行当作文件头
+		let (mut old_remaining, mut new_remaining) = (0u32, 0u32);
+		let mut lines = diff.lines().peekable();
+		while let Some(line) = lines.next() {
+			if old_remaining > 0 || new_remaining > 0 {
+				let counted = match line.chars().next() {
+					Some('+') => {
+						new_remaining = new_remaining.saturating_sub(1);
+						true
+					}
+					Some('-') => {
+						old_remaining = old_remaining.saturating_sub(1);
+						true
+					}
+					Some(' ') | None => {
+						old_remaining = old_remaining.saturating_sub(1);
+						new_remaining = new_remaining.saturating_sub(1);
+						true
+					}
+					Some('\\') => true,
+					Some(_) => false,
+				};
+				if counted {
+					patch.push_str(line);
+					patch.push('\n');
+					continue;
+				}
+				// 行数与内容不符时以内容为准, 结束当前差异块
+				(old_remaining, new_remaining) = (0, 0);
+			}
+			if let Some(paths) = line.strip_prefix("diff --git ") {
+				if let Some(mut file) = current.take() {
+					file.hunks = DiffHunk::parse(&patch);
+					files.push(file);
+				}
+				patch.clear();
+				let (old_path, new_path) = split_git_paths(paths);
+				current = Some(DiffFile { old_path, new_path, hunks: Vec::new() });
+				in_header = true;
+				continue;
+			}
+			if let Some(path) = line.strip_prefix("--- ")
+				&& (in_header || lines.peek().is_some_and(|next| next.starts_with("+++ ")))
+			{
+				// 没有`diff --git`头的普通统一差异格式
+				if !in_header {
+					if let Some(mut file) = current.take() {
+						file.hunks = DiffHunk::parse(&patch);
+						files.push(file);
+					}
+					patch.clear();
+					current = Some(DiffFile { old_path: None, new_path: None, hunks: Vec::new() });
+					in_header = true;
+				}
+				if let Some(file) = current.as_mut() {
+					file.old_path = parse_path(path);
+				}
+				continue;
+			}
+			if let Some(path) = line.strip_prefix("+++ ")
+				&& in_header
+			{
+				if let Some(file) = current.as_mut() {
+					file.new_path = parse_path(path);
+				}
+				continue;
+			}
+			if line.starts_with("@@") {
+				in_header = false;
+				if let Some(hunk) = parse_hunk_header(line) {
+					(old_remaining, new_remaining) = (hunk.old_lines, hunk.new_lines);
+				}
+			}
+			if in_header {
+				let Some(file) = current.as_mut() else {
+					continue;
+				};
+				if line.starts_with("new file mode") {
+					file.old_path = None;
+				} else if line.starts_with("deleted file mode") {
+					file.new_path = None;
+				} else if let Some(path) =
+					line.strip_prefix("rename from ").or_else(|| line.strip_prefix("copy from "))
+				{
+					file.old_path = Some(unquote(path).0);
+				} else if let Some(path) =
+					line.strip_prefix("rename to ").or_else(|| line.strip_prefix("copy to "))
+				{
+					file.new_path = Some(unquote(path).0);
+				}
+				continue;
+			}
+			patch.push_str(line);
+			patch.push('\n');
+		}
+		if let Some(mut file) = current.take() {
+			file.hunks = DiffHunk::parse(&patch);
+			files.push(file);
+		}
+		files
+	}
+}
+
+impl DiffHunk {
+	/// 解析单个文件的差异块, 例如[FileInfo](crate::types::commit::FileInfo)中的`patch`
+	pub fn parse(patch: &str) -> Vec<DiffHunk> {
+		let mut hunks: Vec<DiffHunk> = Vec::new();
+		let (mut old_line, mut new_line) = (0u32, 0u32);
+		for line in patch.lines() {
+			if line.starts_with("@@") {
+				if let Some(hunk) = parse_hunk_header(line) {
+					old_line = hunk.old_start;
+					new_line = hunk.new_start;
+					hunks.push(hunk);
+				}
+				continue;
+			}
+			let Some(hunk) = hunks.last_mut() else {
+				continue;
+			};
+			let (kind, content) = match line.chars().next() {
+				Some('+') => (DiffLineKind::Addition, &line[1..]),
+				Some('-') => (DiffLineKind::Deletion, &line[1..]),
+				Some(' ') => (DiffLineKind::Context, &line[1..]),
+				Some('\\') => continue,
+				None => (DiffLineKind::Context, ""),
+				Some(_) => (DiffLineKind::Context, line),
+			};
+			let (old, new) = match kind {
+				DiffLineKind::Addition => (None, Some(new_line)),
+				DiffLineKind::Deletion => (Some(old_line), None),
+				DiffLineKind::Context => (Some(old_line), Some(new_line)),
+			};
+			if old.is_some() {
+				old_line += 1;
+			}
+			if new.is_some() {
+				new_line += 1;
+			}
+			hunk.lines.push(DiffLine {
+				kind,
+				content: content.to_string(),
+				old_line: old,
+				new_line: new,
+			});
+		}
+		hunks
+	}
+}
+
+/// 解析`@@ -a,b +c,d @@ header`
+fn parse_hunk_header(line: &str) -> Option<DiffHunk> {
+	let rest = line.strip_prefix("@@ ")?;
+	let (ranges, header) = rest.split_once(" @@")?;
+	let (old, new) = ranges.split_once(' ')?;
+	let parse_range = |range: &str| -> Option<(u32, u32)> {
+		match range.split_once(',') {
+			Some((start, lines)) => Some((start.parse().ok()?, lines.parse().ok()?)),
+			None => Some((range.parse().ok()?, 1)),
+		}
+	};
+	let (old_start, old_lines) = parse_range(old.strip_prefix('-')?)?;
+	let (new_start, new_lines) = parse_range(new.strip_prefix('+')?)?;
+	let header = header.trim();
+	Some(DiffHunk {
+		old_start,
+		old_lines,
+		new_start,
+		new_lines,
+		header: (!header.is_empty()).then(|| header.to_string()),
+		lines: Vec::new(),
+	})
+}
+
+fn parse_path(path: &str) -> Option<String> {
+	let path = if path.starts_with('"') {
+		unquote(path).0
+	} else {
+		path.split('\t').next().unwrap_or(path).trim().to_string()
+	};
+	if path == "/dev/null" {
+		return None;
+	}
+	Some(strip_side(&path).to_string())
+}
+
+fn strip_side(path: &str) -> &str {
+	path.strip_prefix("a/").or_else(|| path.strip_prefix("b/")).unwrap_or(path)
+}
+
+/// 解析`diff --git a/x b/y`中的路径
+///
+/// 路径可能被引号包裹并使用C风格转义; 未转义的路径中可能包含` b/`,
+/// 此时优先选择前后路径相同的分割位置
+fn split_git_paths(paths: &str) -> (Option<String>, Option<String>) {
+	if paths.starts_with('"') {
+		let (old, rest) = unquote(paths);
+		let new = rest.trim_start();
+		let new = if new.starts_with('"') { unquote(new).0 } else { new.to_string() };
+		return (Some(strip_side(&old).to_string()), Some(strip_side(&new).to_string()));
+	}
+	if paths.ends_with('"')
+		&& let Some(index) = paths.rfind(" \"")
+	{
+		let (old, new) = (&paths[..index], unquote(&paths[index + 1..]).0);
+		return (Some(strip_side(old).to_string()), Some(strip_side(&new).to_string()));
+	}
+	let mut first = None;
+	for (index, _) in paths.match_indices(" b/") {
+		let (old, new) = (strip_side(&paths[..index]), &paths[index + 3..]);
+		if old == new {
+			return (Some(old.to_string()), Some(new.to_string()));
+		}
+		first.get_or_insert((old, new));
+	}
+	match first {
+		Some((old, new)) => (Some(old.to_string()), Some(new.to_string())),
+		None => (None, None),
+	}
+}
+
+/// 解析被引号包裹的C风格转义路径, 返回路径与剩余内容; 未被引号包裹时原样返回
+fn unquote(text: &str) -> (String, &str) {
+	let Some(quoted) = text.strip_prefix('"') else {
+		return (text.to_string(), "");
+	};
+	let mut bytes: Vec<u8> = Vec::new();
+	let mut chars = quoted.char_indices();
+	while let Some((index, c)) = chars.next() {
+		match c {
+			'"' => return (String::from_utf8_lossy(&bytes).into_owned(), &quoted[index + 1..]),
+			'\\' => {
+				let Some((_, escaped)) = chars.next() else {
+					break;
+				};
+				match escaped {
+					'a' => bytes.push(0x07),
+					'b' => bytes.push(0x08),
+					't' => bytes.push(b'\t'),
+					'n' => bytes.push(b'\n'),
+					'v' => bytes.push(0x0b),
+					'f' => bytes.push(0x0c),
+					'r' => bytes.push(b'\r'),
+					'0'..='7' => {
+						// 非ASCII字符以3位八进制的UTF-8字节表示
+						let mut value = escaped.to_digit(8).unwrap_or_default();
+						for _ in 0..2 {
+							let Some((_, digit)) = chars.clone().next() else {
+								break;
+							};
+							let Some(digit) = digit.to_digit(8) else {
+								break;
+							};
+							value = value * 8 + digit;
+							chars.next();
+						}
+						bytes.push(value as u8);
+					}
+					other => {
+						let mut buf = [0u8; 4];
+						bytes.extend_from_slice(other.encode_utf8(&mut buf).as_bytes());
+					}
+				}
+			}
+			other => {
+				let mut buf = [0u8; 4];
+				bytes.extend_from_slice(other.encode_utf8(&mut buf).as_bytes());
+			}
+		}
+	}
+	(String::from_utf8_lossy(&bytes).into_owned(), "")
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn paths(file: &DiffFile) -> (Option<&str>, Option<&str>) {
+		(file.old_path.as_deref(), file.new_path.as_deref())
+	}
+
+	#[test]
+	fn parse_multi_file() {
+		let diff = "\
+diff --git a/src/lib.rs b/src/lib.rs
+index 1111111..2222222 100644
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -1,3 +1,3 @@ mod a;
+ fn a() {}
+-fn b() {}
++fn c() {}
+ fn d() {}
+diff --git a/new.txt b/new.txt
+new file mode 100644
+index 0000000..3333333
+--- /dev/null
++++ b/new.txt
+@@ -0,0 +1,2 @@
++hello
++world
+diff --git a/old.txt b/old.txt
+deleted file mode 100644
+index 4444444..0000000
+--- a/old.txt
++++ /dev/null
+@@ -1 +0,0 @@
+-bye
+";
+		let files = DiffFile::parse(diff);
+		assert_eq!(files.len(), 3);
+		assert_eq!(paths(&files[0]), (Some("src/lib.rs"), Some("src/lib.rs")));
+		let hunk = &files[0].hunks[0];
+		assert_eq!(hunk.header.as_deref(), Some("mod a;"));
+		let kinds: Vec<_> = hunk.lines.iter().map(|l| l.kind).collect();
+		assert_eq!(
+			kinds,
+			[
+				DiffLineKind::Context,
+				DiffLineKind::Deletion,
+				DiffLineKind::Addition,
+				DiffLineKind::Context
+			]
+		);
+		assert_eq!((hunk.lines[2].old_line, hunk.lines[2].new_line), (None, Some(2)));
+		assert_eq!((hunk.lines[3].old_line, hunk.lines[3].new_line), (Some(3), Some(3)));
+		assert_eq!(paths(&files[1]), (None, Some("new.txt")));
+		assert_eq!(files[1].hunks[0].lines.len(), 2);
+		assert_eq!(paths(&files[2]), (Some("old.txt"), None));
+		assert_eq!(files[2].hunks[0].lines[0].content, "bye");
+	}
+
+	#[test]
+	fn parse_rename() {
+		let diff = "\
+diff --git a/docs/a b/x.md b/docs/c.md
+similarity index 90%
+rename from docs/a b/x.md
+rename to docs/c.md
+index 1111111..2222222 100644
+--- a/docs/a b/x.md
++++ b/docs/c.md
+@@ -1 +1 @@
+-title
++Title
+diff --git a/same.txt b/renamed.txt
+similarity index 100%
+rename from same.txt
+rename to renamed.txt
+";
+		let files = DiffFile::parse(diff);
+		assert_eq!(files.len(), 2);
+		assert_eq!(paths(&files[0]), (Some("docs/a b/x.md"), Some("docs/c.md")));
+		assert_eq!(files[0].hunks.len(), 1);
+		assert_eq!(paths(&files[1]), (Some("same.txt"), Some("renamed.txt")));
+		assert!(files[1].hunks.is_empty());
+	}
+
+	#[test]
+	fn parse_binary() {
+		let diff = "\
+diff --git a/logo.png b/logo.png
+index 1111111..2222222 100644
+Binary files a/logo.png and b/logo.png differ
+diff --git a/a.txt b/a.txt
+--- a/a.txt
++++ b/a.txt
+@@ -1 +1 @@
+-a
++b
+";
+		let files = DiffFile::parse(diff);
+		assert_eq!(files.len(), 2);
+		assert_eq!(paths(&files[0]), (Some("logo.png"), Some("logo.png")));
+		assert!(files[0].hunks.is_empty());
+		assert_eq!(files[1].hunks[0].lines.len(), 2);
+	}
+
+	#[test]
+	fn parse_no_newline() {
+		let diff = "\
+--- a/a.txt
++++ b/a.txt
+@@ -1,2 +1,2 @@
+ keep
+-old
+\\ No newline at end of file
++new
+\\ No newline at end of file
+";
+		let files = DiffFile::parse(diff);
+		assert_eq!(files.len(), 1);
+		let lines = &files[0].hunks[0].lines;
+		assert_eq!(lines.len(), 3);
+		assert_eq!(lines[1].content, "old");
+		assert_eq!(lines[2].content, "new");
+		assert_eq!(lines[2].new_line, Some(2));
+	}
+
+	#[test]
+	fn parse_header_like_content() {
+		// 删除内容为`-- x`的行与新增内容为`++ y`的行
+		let diff = "\
+--- a/notes.md
++++ b/notes.md
+@@ -1,2 +1,2 @@
+ intro
+--- x
++++ y
+--- a/other.md
++++ b/other.md
+@@ -1 +1 @@
+-1
++2
+";
+		let files = DiffFile::parse(diff);
+		assert_eq!(files.len(), 2);
+		let lines = &files[0].hunks[0].lines;
+		assert_eq!(lines.len(), 3);
+		assert_eq!((lines[1].kind, lines[1].content.as_str()), (DiffLineKind::Deletion, "-- x"));
+		assert_eq!((lines[2].kind, lines[2].content.as_str()), (DiffLineKind::Addition, "++ y"));
+		assert_eq!(paths(&files[1]), (Some("other.md"), Some("other.md")));
+	}
+
+	#[test]
+	fn parse_quoted_paths() {
+		let diff = "\
+diff --git \"a/\\344\\270\\255\\346\\226\\207.txt\" \"b/tab\\there.txt\"
+similarity index 100%
+rename from \"\\344\\270\\255\\346\\226\\207.txt\"
+rename to \"tab\\there.txt\"
+";
+		let files = DiffFile::parse(diff);
+		assert_eq!(paths(&files[0]), (Some("中文.txt"), Some("tab\there.txt")));
+		assert_eq!(
+			split_git_paths("a/x b/y \"b/q\\\"z\""),
+			(Some("x b/y".to_string()), Some("q\"z".to_string()))
+		);
+	}
+}
@@ -0,0 +1,36 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GistInfo {
+	/// 代码片段ID
+	pub id: String,
+	/// 代码片段描述
+	pub description: Option<String>,
+	/// 是否公开
+	pub public: bool,
+	/// 所有者用户名
+	pub owner: Option<String>,
+	/// 网页地址
+	pub html_url: String,
+	/// 文件列表, 按文件名排序
+	pub files: Vec<GistFile>,
+	/// 创建时间
+	pub created_at: DateTime<Utc>,
+	/// 更新时间
+	pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GistFile {
+	/// 文件名
+	pub filename: String,
+	/// 文件语言
+	pub language: Option<String>,
+	/// 文件大小, 单位为字节
+	pub size: u64,
+	/// 原始文件地址
+	pub raw_url: Option<String>,
+	/// 文件内容, 列表接口不返回内容, 文件过大时会被截断
+	pub content: Option<String>,
+}
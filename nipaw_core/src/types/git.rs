@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitRef {
+	/// 引用全名, 如`refs/heads/main`
+	pub name: String,
+	/// 指向的对象SHA
+	pub sha: String,
+	/// 指向的对象类型, 如`commit`、`tag`
+	pub object_type: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitTree {
+	/// 树对象SHA
+	pub sha: String,
+	/// 条目过多时平台会截断结果
+	pub truncated: bool,
+	/// 树条目, 递归获取时包含所有子目录的条目
+	pub entries: Vec<GitTreeEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitTreeEntry {
+	/// 相对于树根的路径
+	pub path: String,
+	/// 文件模式, 如`100644`、`100755`、`040000`
+	pub mode: String,
+	/// 条目类型, 如`blob`、`tree`、`commit`
+	pub entry_type: String,
+	/// 对象SHA
+	pub sha: String,
+	/// 文件大小, 只有`blob`有值
+	pub size: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitCommit {
+	/// 提交SHA
+	pub sha: String,
+	/// 提交信息
+	pub message: String,
+	/// 提交的树对象SHA
+	pub tree: String,
+	/// 父提交SHA列表
+	pub parents: Vec<String>,
+	/// 网页地址
+	pub html_url: Option<String>,
+}
@@ -22,6 +22,18 @@ pub struct IssueInfo {
 	pub updated_at: DateTime<Utc>,
 	/// 议题关闭时间
 	pub closed_at: Option<DateTime<Utc>>,
+	/// 负责人信息
+	pub assignees: Vec<UserInfo>,
+	/// 评论数量
+	pub comments: u64,
+	/// 里程碑名称
+	pub milestone: Option<String>,
+	/// 议题网页地址
+	pub html_url: Option<String>,
+	/// 是否已锁定, 只有GitHub返回锁定状态, 其他平台为空
+	pub locked: Option<bool>,
+	/// 是否为拉取请求, GitHub的议题列表会包含拉取请求
+	pub is_pull_request: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Display, EnumString, IntoStaticStr)]
@@ -51,3 +63,73 @@ pub struct LabelInfo {
 	/// 颜色,16进制
 	pub color: String,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueCommentInfo {
+	/// 评论ID
+	pub id: String,
+	/// 评论者信息
+	pub user: Option<UserInfo>,
+	/// 评论内容
+	pub body: String,
+	/// 创建时间
+	pub created_at: DateTime<Utc>,
+	/// 更新时间
+	pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueEventInfo {
+	/// 事件ID
+	pub id: Option<String>,
+	/// 操作者信息, 系统产生的事件可能为[None]
+	pub actor: Option<UserInfo>,
+	/// 事件内容
+	pub event: IssueEvent,
+	/// 发生时间
+	pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IssueEvent {
+	/// 添加标签
+	Labeled { label: String },
+	/// 移除标签
+	Unlabeled { label: String },
+	/// 分配负责人
+	Assigned { assignee: String },
+	/// 取消分配负责人
+	Unassigned { assignee: String },
+	/// 关闭, 由提交关闭时带有提交SHA
+	Closed { commit_id: Option<String> },
+	/// 重新打开
+	Reopened,
+	/// 修改标题
+	Renamed { from: String, to: String },
+	/// 被提交或其他议题引用
+	Referenced { commit_id: Option<String> },
+	/// 设置里程碑
+	Milestoned { milestone: String },
+	/// 移除里程碑
+	Demilestoned { milestone: String },
+	/// 其他事件, 值为平台返回的原始事件名
+	Other(String),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum TimelineItem {
+	/// 评论
+	Comment(IssueCommentInfo),
+	/// 事件
+	Event(IssueEventInfo),
+}
+
+impl TimelineItem {
+	/// 评论或事件的发生时间
+	pub fn created_at(&self) -> DateTime<Utc> {
+		match self {
+			TimelineItem::Comment(comment) => comment.created_at,
+			TimelineItem::Event(event) => event.created_at,
+		}
+	}
+}
@@ -0,0 +1,55 @@
+use crate::types::repo::RepoPath;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationInfo {
+	/// 通知ID
+	pub id: String,
+	/// 通知对象的类型
+	pub subject_type: SubjectType,
+	/// 通知标题
+	pub title: String,
+	/// 通知所属的仓库
+	pub repo_path: RepoPath,
+	/// 收到通知的原因, 如`mention`、`assign`, 各平台取值不同
+	pub reason: Option<String>,
+	/// 是否未读
+	pub unread: bool,
+	/// 更新时间
+	pub updated_at: DateTime<Utc>,
+	/// 通知对象的网页地址
+	pub html_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SubjectType {
+	/// 议题
+	Issue,
+	/// 拉取请求
+	PullRequest,
+	/// 提交
+	Commit,
+	/// 发行版
+	Release,
+	/// 讨论
+	Discussion,
+	/// 其他类型
+	Other,
+}
+
+impl SubjectType {
+	/// 从平台返回的类型名称转换, 忽略大小写与分隔符
+	pub fn parse(value: &str) -> Self {
+		let value: String =
+			value.chars().filter(|c| c.is_alphanumeric()).collect::<String>().to_lowercase();
+		match value.as_str() {
+			"issue" => Self::Issue,
+			"pullrequest" | "pull" | "pr" => Self::PullRequest,
+			"commit" => Self::Commit,
+			"release" => Self::Release,
+			"discussion" => Self::Discussion,
+			_ => Self::Other,
+		}
+	}
+}
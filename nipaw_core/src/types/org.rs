@@ -1,3 +1,4 @@
+use crate::types::repo::{CollaboratorPermission, RepoInfo};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -15,3 +16,39 @@ pub struct OrgInfo {
 	/// 组织关注数
 	pub follow_count: u64,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrgMember {
+	/// 成员用户名
+	pub login: String,
+	/// 成员头像URL
+	pub avatar_url: String,
+	/// 成员角色
+	pub role: MemberRole,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MemberRole {
+	/// 管理员, 包括组织所有者
+	Admin,
+	/// 普通成员
+	Member,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TeamInfo {
+	/// 团队标识, 用于调用团队相关接口
+	pub slug: String,
+	/// 团队名称
+	pub name: String,
+	/// 团队描述
+	pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TeamRepoInfo {
+	/// 仓库信息
+	pub repo: RepoInfo,
+	/// 团队对仓库的权限
+	pub permission: CollaboratorPermission,
+}
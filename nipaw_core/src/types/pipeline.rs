@@ -0,0 +1,101 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PipelineRun {
+	/// 运行ID, GitHub为运行ID, CNB为构建号`sn`
+	pub id: String,
+	/// 工作流或构建名称
+	pub name: Option<String>,
+	/// 运行序号
+	pub number: Option<u64>,
+	/// 触发的分支
+	pub branch: Option<String>,
+	/// 触发的提交SHA
+	pub sha: Option<String>,
+	/// 触发事件, 如`push`、`pull_request`
+	pub event: Option<String>,
+	/// 运行状态
+	pub status: PipelineStatus,
+	/// 网页地址
+	pub html_url: Option<String>,
+	/// 创建时间
+	pub created_at: Option<DateTime<Utc>>,
+	/// 更新时间
+	pub updated_at: Option<DateTime<Utc>>,
+	/// 任务列表, 只有获取运行详情时返回
+	pub jobs: Vec<PipelineJob>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PipelineJob {
+	/// 任务ID
+	pub id: String,
+	/// 任务名称
+	pub name: String,
+	/// 任务状态
+	pub status: PipelineStatus,
+	/// 开始时间
+	pub started_at: Option<DateTime<Utc>>,
+	/// 结束时间
+	pub completed_at: Option<DateTime<Utc>>,
+	/// 步骤列表
+	pub steps: Vec<PipelineStep>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PipelineStep {
+	/// 步骤名称
+	pub name: String,
+	/// 步骤序号
+	pub number: u64,
+	/// 步骤状态
+	pub status: PipelineStatus,
+	/// 开始时间
+	pub started_at: Option<DateTime<Utc>>,
+	/// 结束时间
+	pub completed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PipelineStatus {
+	/// 排队中
+	Queued,
+	/// 等待审批或资源
+	Waiting,
+	/// 运行中
+	Running,
+	/// 成功
+	Success,
+	/// 失败, 包括超时
+	Failure,
+	/// 已取消
+	Cancelled,
+	/// 已跳过
+	Skipped,
+	/// 无法识别的状态
+	Unknown,
+}
+
+impl PipelineStatus {
+	/// 由平台返回的状态与结论转换, 运行结束时以结论为准
+	///
+	/// ## 参数
+	/// * `status` - 状态, 如`queued`、`in_progress`、`completed`
+	/// * `conclusion` - 结论, 如`success`、`failure`, 未结束时为[None]
+	pub fn parse(status: &str, conclusion: Option<&str>) -> Self {
+		let status = conclusion.filter(|c| !c.is_empty()).unwrap_or(status);
+		match status.to_lowercase().as_str() {
+			"queued" | "pending" | "requested" | "created" => Self::Queued,
+			"waiting" | "action_required" | "blocked" => Self::Waiting,
+			"in_progress" | "running" | "start" => Self::Running,
+			"success" | "completed" | "passed" => Self::Success,
+			"failure" | "failed" | "error" | "timed_out" | "startup_failure" | "timeout" => {
+				Self::Failure
+			}
+			"cancelled" | "canceled" | "cancel" | "stale" => Self::Cancelled,
+			"skipped" | "neutral" | "skip" => Self::Skipped,
+			_ => Self::Unknown,
+		}
+	}
+}
@@ -36,3 +36,23 @@ pub struct AssetsInfo {
 	/// 资源下载地址
 	pub url: String,
 }
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct DownloadProgress {
+	/// 已下载字节数, 包含续传前的部分
+	pub downloaded: u64,
+	/// 总字节数, 服务端未返回长度时为[None]
+	pub total: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DownloadResult {
+	/// 本次写入的字节数
+	pub written: u64,
+	/// 文件总字节数
+	pub size: u64,
+	/// 是否为续传
+	pub resumed: bool,
+	/// 校验通过的SHA-256摘要, 未校验时为[None]
+	pub sha256: Option<String>,
+}
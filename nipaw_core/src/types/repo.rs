@@ -1,5 +1,6 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt::Display;
 use strum::{Display, EnumString, IntoStaticStr};
 
@@ -19,8 +20,12 @@ pub struct RepoInfo {
 	pub fork: bool,
 	/// 仓库fork数量
 	pub fork_count: u64,
+	/// fork仓库的上游仓库, 非fork仓库或平台未返回时为[None]
+	pub parent: Option<RepoPath>,
 	/// 仓库语言
 	pub language: Option<String>,
+	/// 仓库主题
+	pub topics: Vec<String>,
 	/// 仓库星标数量
 	pub star_count: u64,
 	/// 仓库默认分支
@@ -34,7 +39,7 @@ pub struct RepoInfo {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Display, EnumString, IntoStaticStr)]
-/// 只有`public`和`private`
+/// `internal`仅部分平台支持, 如GitHub企业组织与Gitee的内部公开
 pub enum Visibility {
 	/// 公开
 	#[serde(rename = "public")]
@@ -42,6 +47,9 @@ pub enum Visibility {
 	#[serde(rename = "private")]
 	/// 私有
 	Private,
+	/// 内部公开
+	#[serde(rename = "internal")]
+	Internal,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -53,6 +61,16 @@ pub struct CollaboratorResult {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CollaboratorInfo {
+	/// 协作者用户名
+	pub login: String,
+	/// 协作者头像URL
+	pub avatar_url: String,
+	/// 协作者权限
+	pub permission: CollaboratorPermission,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum CollaboratorPermission {
 	/// 管理权限
 	Admin,
@@ -60,9 +78,67 @@ pub enum CollaboratorPermission {
 	Push,
 	/// 拉取权限
 	Pull,
+	/// 无访问权限
+	None,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncForkResult {
+	/// 同步说明
+	pub message: Option<String>,
+	/// 合并方式, 如`fast-forward`、`merge`, 已是最新时为`none`
+	pub merge_type: Option<String>,
+	/// 同步来源的上游分支
+	pub base_branch: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContributorInfo {
+	/// 贡献者用户名, 平台只返回提交作者时为作者名称
+	pub login: String,
+	/// 贡献者邮箱
+	pub email: Option<String>,
+	/// 贡献者头像URL
+	pub avatar_url: Option<String>,
+	/// 提交数
+	pub contributions: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepoLanguages {
+	/// 统计单位, 取决于平台返回的格式
+	pub unit: LanguageUnit,
+	/// 语言与对应的字节数或百分比
+	pub languages: HashMap<String, f64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LanguageUnit {
+	/// 字节数
+	Bytes,
+	/// 百分比, 取值范围为`0-100`
+	Percent,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ArchiveFormat {
+	/// gzip压缩的tar包
+	TarGz,
+	/// zip压缩包
+	Zip,
+}
+
+impl ArchiveFormat {
+	/// 文件扩展名, 不含前导的`.`
+	pub fn extension(&self) -> &'static str {
+		match self {
+			ArchiveFormat::TarGz => "tar.gz",
+			ArchiveFormat::Zip => "zip",
+		}
+	}
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct RepoPath {
 	/// 仓库所有者
 	pub owner: String,
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchResult<T> {
+	/// 匹配的结果总数, 平台未返回总数时为本页结果数
+	pub total_count: u64,
+	/// 结果是否不完整, 搜索超时时平台只返回部分结果
+	pub incomplete: bool,
+	/// 本页结果
+	pub items: Vec<T>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodeInfo {
+	/// 文件名
+	pub name: String,
+	/// 文件路径
+	pub path: String,
+	/// 文件的blob SHA
+	pub sha: Option<String>,
+	/// 仓库所有者
+	pub owner: String,
+	/// 仓库名称
+	pub repo: String,
+	/// 文件网页URL
+	pub html_url: Option<String>,
+}
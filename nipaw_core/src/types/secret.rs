@@ -0,0 +1,79 @@
+use crate::types::repo::RepoPath;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum SecretScope {
+	/// 仓库级别
+	Repo(RepoPath),
+	/// 组织级别, 值为组织名
+	Org(String),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SecretInfo {
+	/// 密钥名称, 平台不会返回密钥的值
+	pub name: String,
+	/// 组织密钥的可见范围, 如`all`、`private`、`selected`, 仓库密钥为[None]
+	pub visibility: Option<String>,
+	/// 创建时间
+	pub created_at: Option<DateTime<Utc>>,
+	/// 更新时间
+	pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VariableInfo {
+	/// 变量名称
+	pub name: String,
+	/// 变量值
+	pub value: String,
+	/// 组织变量的可见范围, 仓库变量为[None]
+	pub visibility: Option<String>,
+	/// 创建时间
+	pub created_at: Option<DateTime<Utc>>,
+	/// 更新时间
+	pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// 密钥的值
+///
+/// 不实现序列化, `Debug`与`Display`只输出占位符, 只能通过[SecretValue::expose]读取原值
+#[derive(Clone)]
+pub struct SecretValue(String);
+
+impl SecretValue {
+	pub fn new(value: impl Into<String>) -> Self {
+		Self(value.into())
+	}
+
+	/// 读取原值, 只应在加密或发送请求时调用
+	pub fn expose(&self) -> &str {
+		&self.0
+	}
+}
+
+impl From<String> for SecretValue {
+	fn from(value: String) -> Self {
+		Self(value)
+	}
+}
+
+impl From<&str> for SecretValue {
+	fn from(value: &str) -> Self {
+		Self(value.to_string())
+	}
+}
+
+impl fmt::Debug for SecretValue {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str("SecretValue(***)")
+	}
+}
+
+impl fmt::Display for SecretValue {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str("***")
+	}
+}
@@ -0,0 +1,32 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeeklyCommitActivity {
+	/// 周起始时间, 即当周周日零点(UTC)
+	pub week: DateTime<Utc>,
+	/// 当周提交总数
+	pub total: u64,
+	/// 从周日到周六每天的提交数
+	pub days: Vec<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodeFrequency {
+	/// 周起始时间, 即当周周日零点(UTC)
+	pub week: DateTime<Utc>,
+	/// 新增的行数
+	pub additions: u64,
+	/// 删除的行数
+	pub deletions: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PunchCard {
+	/// 星期, `0`为周日
+	pub weekday: u8,
+	/// 小时, 取值范围为`0-23`(UTC)
+	pub hour: u8,
+	/// 提交数
+	pub commits: u64,
+}
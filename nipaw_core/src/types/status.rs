@@ -0,0 +1,78 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString, IntoStaticStr};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusInfo {
+	/// 状态ID
+	pub id: Option<u64>,
+	/// 状态
+	pub state: StatusState,
+	/// 状态的上下文标识, 用于区分不同的检查
+	pub context: String,
+	/// 状态描述
+	pub description: Option<String>,
+	/// 详情链接
+	pub target_url: Option<String>,
+	/// 创建者登录名
+	pub creator: Option<String>,
+	/// 创建时间
+	pub created_at: Option<DateTime<Utc>>,
+	/// 更新时间
+	pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CombinedStatusInfo {
+	/// 合并后的状态
+	pub state: StatusState,
+	/// 提交SHA
+	pub sha: String,
+	/// 状态总数
+	pub total_count: u64,
+	/// 每个上下文的最新状态
+	pub statuses: Vec<StatusInfo>,
+}
+
+impl CombinedStatusInfo {
+	/// 由状态列表计算合并状态, `statuses`需按创建时间倒序排列
+	///
+	/// 每个上下文只保留最新的状态
+	pub fn from_statuses(sha: String, statuses: Vec<StatusInfo>) -> Self {
+		let mut latest: Vec<StatusInfo> = Vec::new();
+		for status in statuses {
+			if !latest.iter().any(|s| s.context == status.context) {
+				latest.push(status);
+			}
+		}
+		let state = if latest
+			.iter()
+			.any(|s| matches!(s.state, StatusState::Failure | StatusState::Error))
+		{
+			StatusState::Failure
+		} else if latest.is_empty() || latest.iter().any(|s| s.state == StatusState::Pending) {
+			StatusState::Pending
+		} else {
+			StatusState::Success
+		};
+		Self { state, sha, total_count: latest.len() as u64, statuses: latest }
+	}
+}
+
+#[derive(
+	Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Display, EnumString, IntoStaticStr,
+)]
+pub enum StatusState {
+	#[strum(serialize = "pending")]
+	/// 等待中
+	Pending,
+	#[strum(serialize = "success")]
+	/// 成功
+	Success,
+	#[strum(serialize = "failure")]
+	/// 失败
+	Failure,
+	#[strum(serialize = "error")]
+	/// 出错
+	Error,
+}
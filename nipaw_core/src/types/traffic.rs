@@ -0,0 +1,68 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString, IntoStaticStr};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrafficSeries {
+	/// 统计周期内的总次数
+	pub count: u64,
+	/// 统计周期内的独立访客数
+	pub uniques: u64,
+	/// 按天或按周的明细
+	pub items: Vec<TrafficPoint>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrafficPoint {
+	/// 当天或当周的起始时间
+	pub timestamp: DateTime<Utc>,
+	/// 次数
+	pub count: u64,
+	/// 独立访客数
+	pub uniques: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrafficReferrer {
+	/// 来源站点
+	pub referrer: String,
+	/// 访问次数
+	pub count: u64,
+	/// 独立访客数
+	pub uniques: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrafficPath {
+	/// 页面路径
+	pub path: String,
+	/// 页面标题
+	pub title: String,
+	/// 访问次数
+	pub count: u64,
+	/// 独立访客数
+	pub uniques: u64,
+}
+
+#[derive(
+	Debug,
+	Clone,
+	Copy,
+	Default,
+	PartialEq,
+	Eq,
+	Serialize,
+	Deserialize,
+	Display,
+	EnumString,
+	IntoStaticStr,
+)]
+pub enum TrafficPer {
+	#[default]
+	#[strum(serialize = "day")]
+	/// 按天
+	Day,
+	#[strum(serialize = "week")]
+	/// 按周
+	Week,
+}
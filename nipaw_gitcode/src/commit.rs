@@ -2,7 +2,7 @@ use crate::GitCodeClientInner;
 use async_trait::async_trait;
 use crate::common::JsonValue;
 use nipaw_core::option::commit::ListOptions;
-use nipaw_core::types::commit::{CommitInfo, CommitListInfo, FileInfo};
+use nipaw_core::types::commit::{CommitInfo, CommitListInfo, CompareInfo, FileInfo};
 use nipaw_core::types::repo::RepoPath;
 use nipaw_core::{Commit, Error, Result};
 use serde_json::Value;
@@ -12,12 +12,13 @@ use std::sync::Arc;
 pub struct GitCodeCommit(pub(crate) Arc<GitCodeClientInner>);
 
 impl GitCodeCommit {
-	pub(crate) async fn get_file_info(
+	pub(crate) async fn get_compare_info(
 		&self,
-		repo_path: RepoPath,
+		repo_path: &RepoPath,
 		base: &str,
 		head: &str,
-	) -> Result<Vec<FileInfo>> {
+		straight: bool,
+	) -> Result<JsonValue> {
 		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
 		let token = token.as_ref().ok_or(Error::TokenEmpty)?;
 		let client = self.0.client.read().await;
@@ -25,8 +26,19 @@ impl GitCodeCommit {
 			"{}/repos/{}/{}/compare/{}...{}",
 			api_url, repo_path.owner, repo_path.repo, base, head
 		);
-		let resp = client.get(&url).query(&[("straight", true)]).bearer_auth(token).send().await?;
+		let resp =
+			client.get(&url).query(&[("straight", straight)]).bearer_auth(token).send().await?;
 		let json = resp.json::<JsonValue>().await?;
+		Ok(json)
+	}
+
+	pub(crate) async fn get_file_info(
+		&self,
+		repo_path: RepoPath,
+		base: &str,
+		head: &str,
+	) -> Result<Vec<FileInfo>> {
+		let json = self.get_compare_info(&repo_path, base, head, true).await?;
 		let files = json
 			.0
 			.get("files")
@@ -170,4 +182,50 @@ impl Commit for GitCodeCommit {
 		let res = request.query(&params).send().await?.json::<Vec<JsonValue>>().await?;
 		Ok(res.into_iter().map(|v| v.into()).collect())
 	}
+
+	async fn compare(&self, repo_path: RepoPath, base: &str, head: &str) -> Result<CompareInfo> {
+		let mut compare_info = self.get_compare_info(&repo_path, base, head, false).await?;
+		// GitCode不返回领先/落后数, 反向比较一次得到落后的提交数
+		let behind_by = self
+			.get_compare_info(&repo_path, head, base, false)
+			.await?
+			.0
+			.get("commits")
+			.and_then(|v| v.as_array())
+			.map(|arr| arr.len())
+			.unwrap_or(0);
+		if let Some(obj) = compare_info.0.as_object_mut() {
+			obj.insert("behind_by".to_string(), Value::Number(behind_by.into()));
+		}
+		if let Some(commits) = compare_info.0.get_mut("commits").and_then(|v| v.as_array_mut()) {
+			commits.iter_mut().for_each(fill_avatar_url);
+		}
+		Ok(compare_info.into())
+	}
+
+	async fn diff(&self, repo_path: RepoPath, sha: &str) -> Result<String> {
+		// GitCode没有返回原始差异的接口, 由各文件的差异内容拼接
+		let commit_info = self.info(repo_path, Some(sha)).await?;
+		Ok(commit_info.files.iter().filter_map(|file| file.to_unified_diff()).collect())
+	}
+}
+
+/// 将平台账号的头像写入提交数据的作者与提交者中, 未关联账号时使用默认头像
+fn fill_avatar_url(commit: &mut Value) {
+	let default_avatar_url = "https://cdn-static.gitcode.com/doc/avatar-5.png";
+	for role in ["author", "committer"] {
+		let avatar_url = commit
+			.get(role)
+			.and_then(|v| v.get("avatar_url"))
+			.and_then(|v| v.as_str())
+			.unwrap_or(default_avatar_url)
+			.to_string();
+		if let Some(user) = commit
+			.get_mut("commit")
+			.and_then(|commit| commit.get_mut(role))
+			.and_then(|user| user.as_object_mut())
+		{
+			user.insert("avatar_url".to_string(), Value::String(avatar_url));
+		}
+	}
 }
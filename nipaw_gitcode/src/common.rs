@@ -1,10 +1,14 @@
 mod commit;
+mod git;
 mod issue;
+mod key;
 mod org;
 mod release;
 mod repo;
 mod user;
 
+pub(crate) use git::branch_ref;
+
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
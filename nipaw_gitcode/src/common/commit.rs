@@ -1,6 +1,7 @@
 use crate::common::JsonValue;
 use nipaw_core::types::commit::{
-	CommitData, CommitInfo, CommitListInfo, FileInfo, FileStatus, StatsInfo, UserInfo,
+	CommitData, CommitInfo, CommitListInfo, CompareInfo, FileInfo, FileStatus, StatsInfo,
+	UserInfo,
 };
 
 impl From<JsonValue> for CommitInfo {
@@ -32,6 +33,30 @@ impl From<JsonValue> for CommitListInfo {
 	}
 }
 
+impl From<JsonValue> for CompareInfo {
+	fn from(value: JsonValue) -> Self {
+		let compare_info = value.0;
+		let commits: Vec<CommitListInfo> = compare_info
+			.get("commits")
+			.and_then(|v| v.as_array())
+			.map(|arr| arr.iter().map(|v| JsonValue(v.clone()).into()).collect())
+			.unwrap_or_default();
+		Self {
+			ahead_by: compare_info
+				.get("ahead_by")
+				.and_then(|v| v.as_u64())
+				.unwrap_or(commits.len() as u64),
+			behind_by: compare_info.get("behind_by").and_then(|v| v.as_u64()).unwrap_or(0),
+			commits,
+			files: compare_info
+				.get("files")
+				.and_then(|v| v.as_array())
+				.map(|arr| arr.iter().map(|v| JsonValue(v.clone()).into()).collect())
+				.unwrap_or_default(),
+		}
+	}
+}
+
 impl From<JsonValue> for CommitData {
 	fn from(value: JsonValue) -> Self {
 		let commit_data = value.0;
@@ -98,6 +123,18 @@ impl From<JsonValue> for FileInfo {
 				.get("changes")
 				.and_then(|v| v.as_u64())
 				.unwrap_or(additions + deletions),
+			patch: file_info
+				.get("patch")
+				.or_else(|| file_info.get("diff"))
+				.and_then(|v| v.as_str())
+				.map(|s| s.to_string()),
+			previous_file_name: file_info
+				.get("previous_filename")
+				.or_else(|| file_info.get("previous_file_name"))
+				.and_then(|v| v.as_str())
+				.map(|s| s.to_string()),
+			sha: file_info.get("sha").and_then(|v| v.as_str()).map(|s| s.to_string()),
+			raw_url: file_info.get("raw_url").and_then(|v| v.as_str()).map(|s| s.to_string()),
 		}
 	}
 }
@@ -0,0 +1,85 @@
+use crate::common::JsonValue;
+use nipaw_core::types::git::{GitCommit, GitRef, GitTree, GitTreeEntry};
+
+/// 由分支信息转换为引用
+pub(crate) fn branch_ref(value: JsonValue) -> GitRef {
+	let branch = value.0;
+	GitRef {
+		name: format!(
+			"refs/heads/{}",
+			branch.get("name").and_then(|v| v.as_str()).unwrap_or_default()
+		),
+		sha: branch
+			.get("commit")
+			.and_then(|v| v.get("sha"))
+			.and_then(|v| v.as_str())
+			.unwrap_or_default()
+			.to_string(),
+		object_type: "commit".to_string(),
+	}
+}
+
+impl From<JsonValue> for GitTree {
+	fn from(value: JsonValue) -> Self {
+		let tree = value.0;
+		Self {
+			sha: tree.get("sha").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+			truncated: tree.get("truncated").and_then(|v| v.as_bool()).unwrap_or(false),
+			entries: tree
+				.get("tree")
+				.and_then(|v| v.as_array())
+				.map(|entries| {
+					entries
+						.iter()
+						.map(|entry| {
+							let get_str = |key: &str| {
+								entry
+									.get(key)
+									.and_then(|v| v.as_str())
+									.unwrap_or_default()
+									.to_string()
+							};
+							GitTreeEntry {
+								path: get_str("path"),
+								mode: get_str("mode"),
+								entry_type: get_str("type"),
+								sha: get_str("sha"),
+								size: entry.get("size").and_then(|v| v.as_u64()),
+							}
+						})
+						.collect()
+				})
+				.unwrap_or_default(),
+		}
+	}
+}
+
+impl From<JsonValue> for GitCommit {
+	fn from(value: JsonValue) -> Self {
+		let commit = value.0;
+		// 提交详情接口把提交信息与树对象放在`commit`字段下
+		let detail = commit.get("commit").unwrap_or(&commit);
+		Self {
+			sha: commit.get("sha").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+			message: detail.get("message").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+			tree: detail
+				.get("tree")
+				.and_then(|v| v.get("sha"))
+				.and_then(|v| v.as_str())
+				.unwrap_or_default()
+				.to_string(),
+			parents: commit
+				.get("parents")
+				.and_then(|v| v.as_array())
+				.map(|parents| {
+					parents
+						.iter()
+						.filter_map(|p| p.get("sha").and_then(|v| v.as_str()))
+						.map(|sha| sha.to_string())
+						.collect()
+				})
+				.unwrap_or_default(),
+			html_url: commit.get("html_url").and_then(|v| v.as_str()).map(|s| s.to_string()),
+		}
+	}
+}
@@ -1,6 +1,7 @@
 use crate::common::JsonValue;
 use chrono::Utc;
-use nipaw_core::types::issue::{IssueInfo, LabelInfo, StateType, UserInfo};
+use nipaw_core::types::issue::{IssueCommentInfo, IssueInfo, LabelInfo, StateType, UserInfo};
+use serde_json::Value;
 
 impl From<JsonValue> for IssueInfo {
 	fn from(issue: JsonValue) -> Self {
@@ -38,6 +39,16 @@ impl From<JsonValue> for IssueInfo {
 				.get("closed_at")
 				.and_then(|v| v.as_str())
 				.and_then(|s| s.parse::<chrono::DateTime<Utc>>().ok()),
+			assignees: parse_assignees(&issue_info),
+			comments: issue_info.get("comments").and_then(|v| v.as_u64()).unwrap_or_default(),
+			milestone: issue_info
+				.get("milestone")
+				.and_then(|v| v.get("title"))
+				.and_then(|v| v.as_str())
+				.map(|s| s.to_string()),
+			html_url: issue_info.get("html_url").and_then(|v| v.as_str()).map(|s| s.to_string()),
+			locked: None,
+			is_pull_request: false,
 		}
 	}
 }
@@ -76,3 +87,48 @@ impl From<JsonValue> for Vec<LabelInfo> {
 			.unwrap_or_default()
 	}
 }
+
+/// 解析可能缺失的用户信息
+fn parse_user(user: Option<&Value>) -> Option<UserInfo> {
+	let user = user?;
+	Some(UserInfo {
+		login: user.get("login")?.as_str()?.to_string(),
+		avatar_url: user.get("avatar_url").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+		email: user
+			.get("email")
+			.and_then(|v| v.as_str())
+			.filter(|s| !s.is_empty())
+			.map(|s| s.to_string()),
+	})
+}
+
+/// 解析负责人, 没有`assignees`字段时取单个的`assignee`
+fn parse_assignees(issue: &Value) -> Vec<UserInfo> {
+	match issue.get("assignees").and_then(|v| v.as_array()) {
+		Some(assignees) => assignees.iter().filter_map(|v| parse_user(Some(v))).collect(),
+		None => parse_user(issue.get("assignee")).into_iter().collect(),
+	}
+}
+
+impl From<JsonValue> for IssueCommentInfo {
+	fn from(comment: JsonValue) -> Self {
+		let comment_info = comment.0;
+		let parse_time = |key: &str| {
+			comment_info
+				.get(key)
+				.and_then(|v| v.as_str())
+				.and_then(|s| s.parse::<chrono::DateTime<Utc>>().ok())
+		};
+		Self {
+			id: match comment_info.get("id") {
+				Some(Value::String(id)) => id.to_string(),
+				Some(id) => id.to_string(),
+				None => String::new(),
+			},
+			user: parse_user(comment_info.get("user")),
+			body: comment_info.get("body").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+			created_at: parse_time("created_at").unwrap_or_default(),
+			updated_at: parse_time("updated_at"),
+		}
+	}
+}
@@ -19,8 +19,8 @@ impl From<JsonValue> for UserInfo {
 				.and_then(|v| v.as_str())
 				.filter(|s| !s.is_empty())
 				.map(|s| s.to_string()),
-			followers: user_info.get("followers").and_then(|v| v.as_u64()).unwrap(),
-			following: user_info.get("following").and_then(|v| v.as_u64()).unwrap(),
+			followers: user_info.get("followers").and_then(|v| v.as_u64()).unwrap_or(0),
+			following: user_info.get("following").and_then(|v| v.as_u64()).unwrap_or(0),
 			public_repo_count: user_info.get("repo_count").and_then(|v| v.as_u64()).unwrap_or(0),
 		}
 	}
@@ -0,0 +1,47 @@
+use async_trait::async_trait;
+use nipaw_core::option::gist::{CreateOptions, UpdateOptions};
+use nipaw_core::option::repo::ListOptions;
+use nipaw_core::types::gist::GistInfo;
+use nipaw_core::{Error, Gist, Result};
+
+/// GitCode没有代码片段功能
+pub struct GitCodeGist;
+
+#[async_trait]
+impl Gist for GitCodeGist {
+	async fn list(
+		&self,
+		_user_name: Option<&str>,
+		_option: Option<ListOptions>,
+	) -> Result<Vec<GistInfo>> {
+		Err(Error::unsupported("GitCode", "gists"))
+	}
+
+	async fn info(&self, _id: &str) -> Result<GistInfo> {
+		Err(Error::unsupported("GitCode", "gists"))
+	}
+
+	async fn create(&self, _option: CreateOptions) -> Result<GistInfo> {
+		Err(Error::unsupported("GitCode", "gists"))
+	}
+
+	async fn update(&self, _id: &str, _option: UpdateOptions) -> Result<GistInfo> {
+		Err(Error::unsupported("GitCode", "gists"))
+	}
+
+	async fn delete(&self, _id: &str) -> Result<()> {
+		Err(Error::unsupported("GitCode", "gists"))
+	}
+
+	async fn star(&self, _id: &str) -> Result<()> {
+		Err(Error::unsupported("GitCode", "gists"))
+	}
+
+	async fn unstar(&self, _id: &str) -> Result<()> {
+		Err(Error::unsupported("GitCode", "gists"))
+	}
+
+	async fn fork(&self, _id: &str) -> Result<GistInfo> {
+		Err(Error::unsupported("GitCode", "gists"))
+	}
+}
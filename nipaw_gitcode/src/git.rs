@@ -0,0 +1,127 @@
+use crate::GitCodeClientInner;
+use crate::common::{JsonValue, branch_ref};
+use async_trait::async_trait;
+use nipaw_core::option::git::{FileChange, TreeEntryOptions};
+use nipaw_core::types::git::{GitCommit, GitRef, GitTree};
+use nipaw_core::types::repo::RepoPath;
+use nipaw_core::{Error, Git, Result};
+use reqwest::Method;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+pub struct GitCodeGit(pub(crate) Arc<GitCodeClientInner>);
+
+impl GitCodeGit {
+	fn repo_url(&self, repo_path: &RepoPath, path: &str) -> String {
+		format!("{}/repos/{}/{}/{}", self.0.config.api_url, repo_path.owner, repo_path.repo, path)
+	}
+
+	/// 发送请求并解析响应, 读取操作在未登录时也可以访问公开仓库
+	async fn send_json(
+		&self,
+		method: Method,
+		url: String,
+		query: HashMap<&str, String>,
+		body: Option<Value>,
+	) -> Result<JsonValue> {
+		let token = &self.0.config.token;
+		if token.is_none() && method != Method::GET {
+			return Err(Error::TokenEmpty);
+		}
+		let client = self.0.client.read().await;
+		let mut request = client.request(method, url).query(&query);
+		if let Some(token) = token {
+			request = request.bearer_auth(token);
+		}
+		if let Some(body) = body {
+			request = request.json(&body);
+		}
+		Ok(request.send().await?.error_for_status()?.json::<JsonValue>().await?)
+	}
+}
+
+/// 取出分支名, GitCode只开放了分支的读取与创建
+fn branch_name(reference: &str) -> Result<&str> {
+	let reference = reference.strip_prefix("refs/").unwrap_or(reference);
+	reference
+		.strip_prefix("heads/")
+		.ok_or(Error::unsupported("GitCode", "refs other than branches"))
+}
+
+#[async_trait]
+impl Git for GitCodeGit {
+	async fn get_ref(&self, repo_path: RepoPath, reference: &str) -> Result<GitRef> {
+		let url = self.repo_url(&repo_path, &format!("branches/{}", branch_name(reference)?));
+		let res = self.send_json(Method::GET, url, HashMap::new(), None).await?;
+		Ok(branch_ref(res))
+	}
+
+	async fn create_ref(&self, repo_path: RepoPath, reference: &str, sha: &str) -> Result<GitRef> {
+		let url = self.repo_url(&repo_path, "branches");
+		let body = serde_json::json!({ "refs": sha, "branch_name": branch_name(reference)? });
+		let res = self.send_json(Method::POST, url, HashMap::new(), Some(body)).await?;
+		Ok(branch_ref(res))
+	}
+
+	async fn update_ref(
+		&self,
+		_repo_path: RepoPath,
+		_reference: &str,
+		_sha: &str,
+		_force: bool,
+	) -> Result<GitRef> {
+		Err(Error::unsupported("GitCode", "updating refs"))
+	}
+
+	async fn delete_ref(&self, _repo_path: RepoPath, _reference: &str) -> Result<()> {
+		Err(Error::unsupported("GitCode", "deleting refs"))
+	}
+
+	async fn tree(&self, repo_path: RepoPath, sha: &str, recursive: bool) -> Result<GitTree> {
+		let url = self.repo_url(&repo_path, &format!("git/trees/{}", sha));
+		let mut query = HashMap::new();
+		if recursive {
+			query.insert("recursive", "1".to_string());
+		}
+		Ok(self.send_json(Method::GET, url, query, None).await?.into())
+	}
+
+	async fn create_blob(&self, _repo_path: RepoPath, _content: &[u8]) -> Result<String> {
+		Err(Error::unsupported("GitCode", "creating blobs"))
+	}
+
+	async fn create_tree(
+		&self,
+		_repo_path: RepoPath,
+		_base_tree: Option<&str>,
+		_entries: Vec<TreeEntryOptions>,
+	) -> Result<GitTree> {
+		Err(Error::unsupported("GitCode", "creating trees"))
+	}
+
+	async fn get_commit(&self, repo_path: RepoPath, sha: &str) -> Result<GitCommit> {
+		let url = self.repo_url(&repo_path, &format!("commits/{}", sha));
+		Ok(self.send_json(Method::GET, url, HashMap::new(), None).await?.into())
+	}
+
+	async fn create_commit(
+		&self,
+		_repo_path: RepoPath,
+		_message: &str,
+		_tree: &str,
+		_parents: Vec<String>,
+	) -> Result<GitCommit> {
+		Err(Error::unsupported("GitCode", "creating commits from trees"))
+	}
+
+	async fn commit_files(
+		&self,
+		_repo_path: RepoPath,
+		_branch: &str,
+		_message: &str,
+		_changes: Vec<FileChange>,
+	) -> Result<GitCommit> {
+		Err(Error::unsupported("GitCode", "multi-file commits"))
+	}
+}
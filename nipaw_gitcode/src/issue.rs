@@ -1,15 +1,40 @@
 use crate::GitCodeClientInner;
 use async_trait::async_trait;
 use crate::common::JsonValue;
-use nipaw_core::option::issue::{CreateOptions, ListOptions, UpdateOptions};
-use nipaw_core::types::issue::{IssueInfo, StateType};
+use nipaw_core::option::issue::{CreateOptions, EventListOptions, ListOptions, UpdateOptions};
+use nipaw_core::types::issue::{IssueEventInfo, IssueInfo, StateType, TimelineItem};
 use nipaw_core::types::repo::RepoPath;
 use nipaw_core::{Error, Issue, Result};
 use std::collections::HashMap;
 use std::sync::Arc;
 
+/// GitCode未开放议题事件与锁定的接口, 时间线只包含评论
 pub struct GitCodeIssue(pub(crate) Arc<GitCodeClientInner>);
 
+impl GitCodeIssue {
+	/// 覆盖议题的负责人, 多个负责人以逗号分隔
+	async fn set_assignees(
+		&self,
+		repo_path: RepoPath,
+		issue_number: &str,
+		assignees: Vec<String>,
+	) -> Result<IssueInfo> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let url = format!("{}/repos/{}/issues/{}", api_url, repo_path.owner, issue_number);
+		let client = self.0.client.read().await;
+		let request = client.patch(url).bearer_auth(token.as_ref().unwrap());
+		let mut req_body: HashMap<&str, String> = HashMap::new();
+		req_body.insert("repo", repo_path.repo.to_string());
+		req_body.insert("assignee", assignees.join(","));
+		let res =
+			request.json(&req_body).send().await?.error_for_status()?.json::<JsonValue>().await?;
+		Ok(res.into())
+	}
+}
+
 #[async_trait]
 impl Issue for GitCodeIssue {
 	async fn create(
@@ -135,4 +160,76 @@ impl Issue for GitCodeIssue {
 		let res = request.json(&req_body).send().await?.json::<JsonValue>().await?;
 		Ok(res.into())
 	}
+
+	async fn events(
+		&self,
+		_repo_path: RepoPath,
+		_issue_number: &str,
+		_options: Option<EventListOptions>,
+	) -> Result<Vec<IssueEventInfo>> {
+		Err(Error::unsupported("GitCode", "issue events"))
+	}
+
+	async fn timeline(
+		&self,
+		repo_path: RepoPath,
+		issue_number: &str,
+		options: Option<EventListOptions>,
+	) -> Result<Vec<TimelineItem>> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		let url = format!(
+			"{}/repos/{}/{}/issues/{}/comments",
+			api_url, repo_path.owner, repo_path.repo, issue_number
+		);
+		let options = options.unwrap_or_default();
+		let per_page = options.per_page.unwrap_or(30).min(100);
+		let page = options.page.unwrap_or(1);
+		let client = self.0.client.read().await;
+		let mut request = client.get(url).query(&[("per_page", per_page), ("page", page)]);
+		if let Some(token) = token {
+			request = request.bearer_auth(token);
+		};
+		let res = request.send().await?.json::<Vec<JsonValue>>().await?;
+		Ok(res.into_iter().map(|v| TimelineItem::Comment(v.into())).collect())
+	}
+
+	async fn add_assignees(
+		&self,
+		repo_path: RepoPath,
+		issue_number: &str,
+		assignees: Vec<String>,
+	) -> Result<IssueInfo> {
+		let issue_info = self.info(repo_path.clone(), issue_number).await?;
+		let mut logins: Vec<String> = issue_info.assignees.into_iter().map(|u| u.login).collect();
+		for assignee in assignees {
+			if !logins.contains(&assignee) {
+				logins.push(assignee);
+			}
+		}
+		self.set_assignees(repo_path, issue_number, logins).await
+	}
+
+	async fn remove_assignees(
+		&self,
+		repo_path: RepoPath,
+		issue_number: &str,
+		assignees: Vec<String>,
+	) -> Result<IssueInfo> {
+		let issue_info = self.info(repo_path.clone(), issue_number).await?;
+		let logins = issue_info
+			.assignees
+			.into_iter()
+			.map(|u| u.login)
+			.filter(|login| !assignees.contains(login))
+			.collect();
+		self.set_assignees(repo_path, issue_number, logins).await
+	}
+
+	async fn lock(&self, _repo_path: RepoPath, _issue_number: &str) -> Result<()> {
+		Err(Error::unsupported("GitCode", "issue lock"))
+	}
+
+	async fn unlock(&self, _repo_path: RepoPath, _issue_number: &str) -> Result<()> {
+		Err(Error::unsupported("GitCode", "issue lock"))
+	}
 }
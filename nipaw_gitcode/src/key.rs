@@ -0,0 +1,108 @@
+use crate::GitCodeClientInner;
+use crate::common::JsonValue;
+use async_trait::async_trait;
+use nipaw_core::option::repo::ListOptions;
+use nipaw_core::types::key::{self, GpgKeyInfo, SshKeyInfo};
+use nipaw_core::{Error, Key, Result};
+use http::header;
+use reqwest::Method;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// GitCode未开放GPG公钥接口
+pub struct GitCodeKey(pub(crate) Arc<GitCodeClientInner>);
+
+impl GitCodeKey {
+	/// 发送不关心响应内容的请求, 需要登录
+	async fn send_empty(&self, method: Method, url: String) -> Result<()> {
+		let token = &self.0.config.token;
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let client = self.0.client.read().await;
+		client
+			.request(method, url)
+			.bearer_auth(token.as_ref().unwrap())
+			.header(header::CONTENT_LENGTH, 0)
+			.send()
+			.await?
+			.error_for_status()?;
+		Ok(())
+	}
+
+	/// 发送JSON请求并返回响应内容, 需要登录
+	async fn send_json(&self, method: Method, url: String, body: Value) -> Result<JsonValue> {
+		let token = &self.0.config.token;
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let client = self.0.client.read().await;
+		let res = client
+			.request(method, url)
+			.bearer_auth(token.as_ref().unwrap())
+			.json(&body)
+			.send()
+			.await?
+			.error_for_status()?
+			.json::<JsonValue>()
+			.await?;
+		Ok(res)
+	}
+
+	/// 获取分页列表
+	async fn get_list<T: From<JsonValue>>(
+		&self,
+		url: String,
+		option: Option<ListOptions>,
+	) -> Result<Vec<T>> {
+		let token = &self.0.config.token;
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let client = self.0.client.read().await;
+		let request = client.get(url).bearer_auth(token.as_ref().unwrap());
+		let mut params: HashMap<&str, String> = HashMap::new();
+		if let Some(option) = option {
+			let per_page = option.per_page.unwrap_or(30).min(100);
+			params.insert("per_page", per_page.to_string());
+			let page = option.page.unwrap_or(1);
+			params.insert("page", page.to_string());
+		}
+		let list = request.query(&params).send().await?.json::<Vec<JsonValue>>().await?;
+		Ok(list.into_iter().map(|v| v.into()).collect())
+	}
+}
+
+#[async_trait]
+impl Key for GitCodeKey {
+	async fn ssh_keys(&self, option: Option<ListOptions>) -> Result<Vec<SshKeyInfo>> {
+		let url = format!("{}/user/keys", self.0.config.api_url);
+		self.get_list(url, option).await
+	}
+
+	async fn add_ssh_key(&self, title: &str, key: &str) -> Result<SshKeyInfo> {
+		key::validate_ssh_key(key)?;
+		let url = format!("{}/user/keys", self.0.config.api_url);
+		let body = serde_json::json!({ "title": title, "key": key.trim() });
+		let key_info = self.send_json(Method::POST, url, body).await?;
+		Ok(key_info.into())
+	}
+
+	async fn delete_ssh_key(&self, id: u64) -> Result<()> {
+		let url = format!("{}/user/keys/{}", self.0.config.api_url, id);
+		self.send_empty(Method::DELETE, url).await
+	}
+
+	async fn gpg_keys(&self, _option: Option<ListOptions>) -> Result<Vec<GpgKeyInfo>> {
+		Err(Error::unsupported("GitCode", "gpg_keys"))
+	}
+
+	async fn add_gpg_key(&self, _title: Option<&str>, _key: &str) -> Result<GpgKeyInfo> {
+		Err(Error::unsupported("GitCode", "add_gpg_key"))
+	}
+
+	async fn delete_gpg_key(&self, _id: u64) -> Result<()> {
+		Err(Error::unsupported("GitCode", "delete_gpg_key"))
+	}
+}
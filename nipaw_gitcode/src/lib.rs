@@ -1,23 +1,47 @@
 mod commit;
 mod common;
+mod gist;
+mod git;
 mod issue;
+mod key;
 mod middleware;
+mod notification;
 mod org;
+mod pipeline;
 mod release;
 mod repo;
+mod search;
+mod secret;
+mod stats;
+mod status;
+mod traffic;
 mod user;
 
 use crate::{
 	commit::GitCodeCommit,
 	common::JsonValue,
+	gist::GitCodeGist,
+	git::GitCodeGit,
 	issue::GitCodeIssue,
+	key::GitCodeKey,
 	middleware::{HeaderMiddleware, ResponseMiddleware},
+	notification::GitCodeNotifications,
 	org::GitCodeOrg,
+	pipeline::GitCodePipeline,
 	release::GitCodeRelease,
 	repo::GitCodeRepo,
+	search::GitCodeSearch,
+	secret::GitCodeSecret,
+	stats::GitCodeStats,
+	status::GitCodeStatus,
+	traffic::GitCodeTraffic,
 	user::GitCodeUser,
 };
-pub use nipaw_core::{Client, Commit, Config, Error, Issue, Org, Provider, Release, Repo, Result, Token, User};
+use nipaw_core::stats::CommitStats;
+pub use nipaw_core::{
+	Client, Commit, Config, Error, Gist, Git, Issue, Key, Notifications, Org, Pipeline, Provider,
+	Release, Repo, Result, Search, Secret, Stats, Status, Token, Traffic, User,
+};
 use reqwest_middleware::{ClientBuilder, ClientWithMiddleware};
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -109,6 +133,16 @@ impl Provider for GitCodeClient {
 	type Commit = GitCodeCommit;
 	type Issue = GitCodeIssue;
 	type Release = GitCodeRelease;
+	type Status = GitCodeStatus;
+	type Search = GitCodeSearch;
+	type Key = GitCodeKey;
+	type Stats = GitCodeStats;
+	type Traffic = GitCodeTraffic;
+	type Notifications = GitCodeNotifications;
+	type Gist = GitCodeGist;
+	type Pipeline = GitCodePipeline;
+	type Secret = GitCodeSecret;
+	type Git = GitCodeGit;
 
 	fn user(&self) -> GitCodeUser {
 		GitCodeUser(self.inner.clone())
@@ -133,6 +167,46 @@ impl Provider for GitCodeClient {
 	fn release(&self) -> GitCodeRelease {
 		GitCodeRelease(self.inner.clone())
 	}
+
+	fn status(&self) -> GitCodeStatus {
+		GitCodeStatus
+	}
+
+	fn search(&self) -> GitCodeSearch {
+		GitCodeSearch(self.inner.clone())
+	}
+
+	fn key(&self) -> GitCodeKey {
+		GitCodeKey(self.inner.clone())
+	}
+
+	fn stats(&self) -> GitCodeStats {
+		CommitStats(GitCodeCommit(self.inner.clone()))
+	}
+
+	fn traffic(&self) -> GitCodeTraffic {
+		GitCodeTraffic
+	}
+
+	fn notifications(&self) -> GitCodeNotifications {
+		GitCodeNotifications
+	}
+
+	fn gist(&self) -> GitCodeGist {
+		GitCodeGist
+	}
+
+	fn pipeline(&self) -> GitCodePipeline {
+		GitCodePipeline
+	}
+
+	fn secret(&self) -> GitCodeSecret {
+		GitCodeSecret
+	}
+
+	fn git(&self) -> GitCodeGit {
+		GitCodeGit(self.inner.clone())
+	}
 }
 
 pub(crate) async fn get_user_repo_count(
@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+use nipaw_core::option::notification::ListOptions;
+use nipaw_core::types::notification::NotificationInfo;
+use nipaw_core::types::repo::RepoPath;
+use nipaw_core::{Error, Notifications, Result};
+
+/// GitCode未开放通知接口
+pub struct GitCodeNotifications;
+
+#[async_trait]
+impl Notifications for GitCodeNotifications {
+	async fn list(&self, _option: Option<ListOptions>) -> Result<Vec<NotificationInfo>> {
+		Err(Error::unsupported("GitCode", "notifications"))
+	}
+
+	async fn mark_read(&self, _thread_id: &str) -> Result<()> {
+		Err(Error::unsupported("GitCode", "notifications"))
+	}
+
+	async fn mark_all_read(&self, _repo_path: Option<RepoPath>) -> Result<()> {
+		Err(Error::unsupported("GitCode", "notifications"))
+	}
+
+	async fn subscribe(&self, _thread_id: &str) -> Result<()> {
+		Err(Error::unsupported("GitCode", "notifications"))
+	}
+
+	async fn unsubscribe(&self, _thread_id: &str) -> Result<()> {
+		Err(Error::unsupported("GitCode", "notifications"))
+	}
+}
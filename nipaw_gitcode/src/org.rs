@@ -2,14 +2,63 @@ use crate::GitCodeClientInner;
 use async_trait::async_trait;
 use crate::common::JsonValue;
 use nipaw_core::option::repo::ListOptions;
-use nipaw_core::types::{org::OrgInfo, repo::RepoInfo};
+use nipaw_core::types::{
+	org::{MemberRole, OrgInfo, OrgMember, TeamInfo, TeamRepoInfo},
+	repo::RepoInfo,
+	user::UserInfo,
+};
 use nipaw_core::{Error, Org, Result};
+use reqwest::Method;
 use serde_json::Value;
 use std::collections::HashMap;
 use std::sync::Arc;
 
+/// GitCode的组织没有团队, 团队相关方法均返回[Error::Unsupported]
 pub struct GitCodeOrg(pub(crate) Arc<GitCodeClientInner>);
 
+impl GitCodeOrg {
+	/// 发送不关心响应内容的请求, 需要登录
+	async fn send_empty(&self, method: Method, url: String, body: Option<Value>) -> Result<()> {
+		let token = &self.0.config.token;
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let client = self.0.client.read().await;
+		let mut request = client.request(method, url).bearer_auth(token.as_ref().unwrap());
+		if let Some(body) = body {
+			request = request.json(&body);
+		}
+		request.send().await?.error_for_status()?;
+		Ok(())
+	}
+
+	/// 获取分页列表
+	async fn get_list<T: From<JsonValue>>(
+		&self,
+		url: String,
+		mut params: HashMap<&'static str, String>,
+		options: Option<ListOptions>,
+	) -> Result<Vec<T>> {
+		let token = &self.0.config.token;
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let client = self.0.client.read().await;
+		let mut request = client.get(url);
+		if let Some(token) = token {
+			request = request.bearer_auth(token);
+		}
+		if let Some(option) = options {
+			let per_page = option.per_page.unwrap_or(30).min(100);
+			params.insert("per_page", per_page.to_string());
+			let page = option.page.unwrap_or(1);
+			params.insert("page", page.to_string());
+		}
+		let list = request.query(&params).send().await?.json::<Vec<JsonValue>>().await?;
+		Ok(list.into_iter().map(|v| v.into()).collect())
+	}
+}
+
 #[async_trait]
 impl Org for GitCodeOrg {
 	async fn info(&self, org_name: &str) -> Result<OrgInfo> {
@@ -65,4 +114,81 @@ impl Org for GitCodeOrg {
 		let avatar_url = res.get("avatar").and_then(|v| v.as_str()).unwrap().to_string();
 		Ok(avatar_url)
 	}
+
+	async fn members(
+		&self,
+		org_name: &str,
+		options: Option<ListOptions>,
+	) -> Result<Vec<OrgMember>> {
+		let url = format!("{}/orgs/{}/members", self.0.config.api_url, org_name);
+		self.get_list(url, HashMap::new(), options).await
+	}
+
+	async fn add_member(
+		&self,
+		org_name: &str,
+		user_name: &str,
+		role: Option<MemberRole>,
+	) -> Result<()> {
+		let url = format!("{}/orgs/{}/memberships/{}", self.0.config.api_url, org_name, user_name);
+		let role = match role {
+			Some(MemberRole::Admin) => "admin",
+			_ => "member",
+		};
+		self.send_empty(Method::PUT, url, Some(serde_json::json!({ "role": role }))).await
+	}
+
+	async fn remove_member(&self, org_name: &str, user_name: &str) -> Result<()> {
+		let url = format!("{}/orgs/{}/memberships/{}", self.0.config.api_url, org_name, user_name);
+		self.send_empty(Method::DELETE, url, None).await
+	}
+
+	async fn is_member(&self, org_name: &str, user_name: &str) -> Result<bool> {
+		let url = format!("{}/orgs/{}/memberships/{}", self.0.config.api_url, org_name, user_name);
+		match self.send_empty(Method::GET, url, None).await {
+			Ok(()) => Ok(true),
+			Err(err) if err.is_not_found() => Ok(false),
+			Err(err) => Err(err),
+		}
+	}
+
+	async fn teams(&self, _org_name: &str, _options: Option<ListOptions>) -> Result<Vec<TeamInfo>> {
+		Err(Error::unsupported("GitCode", "teams"))
+	}
+
+	async fn team_members(
+		&self,
+		_org_name: &str,
+		_team_slug: &str,
+		_options: Option<ListOptions>,
+	) -> Result<Vec<UserInfo>> {
+		Err(Error::unsupported("GitCode", "teams"))
+	}
+
+	async fn add_team_member(
+		&self,
+		_org_name: &str,
+		_team_slug: &str,
+		_user_name: &str,
+	) -> Result<()> {
+		Err(Error::unsupported("GitCode", "teams"))
+	}
+
+	async fn remove_team_member(
+		&self,
+		_org_name: &str,
+		_team_slug: &str,
+		_user_name: &str,
+	) -> Result<()> {
+		Err(Error::unsupported("GitCode", "teams"))
+	}
+
+	async fn team_repos(
+		&self,
+		_org_name: &str,
+		_team_slug: &str,
+		_options: Option<ListOptions>,
+	) -> Result<Vec<TeamRepoInfo>> {
+		Err(Error::unsupported("GitCode", "teams"))
+	}
 }
@@ -1,14 +1,47 @@
 use crate::GitCodeClientInner;
 use async_trait::async_trait;
 use crate::common::JsonValue;
-use nipaw_core::option::release::UpdateOption;
-use nipaw_core::types::release::ReleaseInfo;
+use nipaw_core::download;
+use nipaw_core::option::release::{Checksum, DownloadOptions, UpdateOption};
+use nipaw_core::types::release::{DownloadResult, ReleaseInfo};
 use nipaw_core::types::repo::RepoPath;
 use nipaw_core::{Error, Release, Result};
+use reqwest_middleware::RequestBuilder;
+use std::path::Path;
+use serde_json::Value;
 use std::sync::Arc;
+use tokio::io::AsyncWrite;
 
 pub struct GitCodeRelease(pub(crate) Arc<GitCodeClientInner>);
 
+impl GitCodeRelease {
+	/// 构造附件下载请求, 同时解析出期望的SHA-256摘要
+	pub(crate) async fn asset_request(
+		&self,
+		repo_path: &RepoPath,
+		tag_name: &str,
+		asset_name: &str,
+		checksum: Option<&Checksum>,
+	) -> Result<(RequestBuilder, Option<String>)> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let url = format!(
+			"{}/repos/{}/{}/releases/tags/{}",
+			api_url, repo_path.owner, repo_path.repo, tag_name
+		);
+		let client = self.0.client.read().await;
+		let request = client.get(url).bearer_auth(token.as_ref().unwrap());
+		let release = request.send().await?.json::<JsonValue>().await?;
+		let asset_url = |asset: &Value| {
+			asset.get("browser_download_url").and_then(|v| v.as_str()).map(|s| s.to_string())
+		};
+		let build = |url: String| client.get(url).bearer_auth(token.as_ref().unwrap());
+		download::asset_request(&release.0, asset_name, checksum, asset_url, build).await
+	}
+}
+
 #[async_trait]
 impl Release for GitCodeRelease {
 	async fn create(
@@ -102,4 +135,34 @@ impl Release for GitCodeRelease {
 		let res = request.json(&json_body).send().await?.json::<JsonValue>().await?;
 		Ok(res.into())
 	}
+
+	async fn download_asset(
+		&self,
+		repo_path: RepoPath,
+		tag_name: &str,
+		asset_name: &str,
+		writer: &mut (dyn AsyncWrite + Unpin + Send),
+		option: Option<DownloadOptions>,
+	) -> Result<DownloadResult> {
+		let option = option.unwrap_or_default();
+		let (request, expected) = self
+			.asset_request(&repo_path, tag_name, asset_name, option.checksum.as_ref())
+			.await?;
+		download::to_writer(request, writer, &option, expected).await
+	}
+
+	async fn download_asset_to_file(
+		&self,
+		repo_path: RepoPath,
+		tag_name: &str,
+		asset_name: &str,
+		path: &Path,
+		option: Option<DownloadOptions>,
+	) -> Result<DownloadResult> {
+		let option = option.unwrap_or_default();
+		let (request, expected) = self
+			.asset_request(&repo_path, tag_name, asset_name, option.checksum.as_ref())
+			.await?;
+		download::to_file(request, path, &option, expected).await
+	}
 }
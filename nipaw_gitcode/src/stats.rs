@@ -0,0 +1,5 @@
+use crate::commit::GitCodeCommit;
+use nipaw_core::stats::CommitStats;
+
+/// GitCode没有统计接口, 均从提交列表本地计算
+pub type GitCodeStats = CommitStats<GitCodeCommit>;
@@ -0,0 +1,34 @@
+use async_trait::async_trait;
+use nipaw_core::option::status::{CreateOptions, ListOptions};
+use nipaw_core::types::repo::RepoPath;
+use nipaw_core::types::status::{CombinedStatusInfo, StatusInfo, StatusState};
+use nipaw_core::{Error, Result, Status};
+
+/// GitCode未开放提交状态接口, 所有方法均返回[Error::Unsupported]
+pub struct GitCodeStatus;
+
+#[async_trait]
+impl Status for GitCodeStatus {
+	async fn create(
+		&self,
+		_repo_path: RepoPath,
+		_sha: &str,
+		_state: StatusState,
+		_option: Option<CreateOptions>,
+	) -> Result<StatusInfo> {
+		Err(Error::unsupported("GitCode", "commit status"))
+	}
+
+	async fn list(
+		&self,
+		_repo_path: RepoPath,
+		_reference: &str,
+		_option: Option<ListOptions>,
+	) -> Result<Vec<StatusInfo>> {
+		Err(Error::unsupported("GitCode", "commit status"))
+	}
+
+	async fn combined(&self, _repo_path: RepoPath, _reference: &str) -> Result<CombinedStatusInfo> {
+		Err(Error::unsupported("GitCode", "commit status"))
+	}
+}
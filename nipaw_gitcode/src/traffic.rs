@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+use nipaw_core::types::repo::RepoPath;
+use nipaw_core::types::traffic::{TrafficPath, TrafficPer, TrafficReferrer, TrafficSeries};
+use nipaw_core::{Error, Result, Traffic};
+
+/// GitCode未开放流量统计接口
+pub struct GitCodeTraffic;
+
+#[async_trait]
+impl Traffic for GitCodeTraffic {
+	async fn views(&self, _repo_path: RepoPath, _per: TrafficPer) -> Result<TrafficSeries> {
+		Err(Error::unsupported("GitCode", "traffic views"))
+	}
+
+	async fn clones(&self, _repo_path: RepoPath, _per: TrafficPer) -> Result<TrafficSeries> {
+		Err(Error::unsupported("GitCode", "traffic clones"))
+	}
+
+	async fn referrers(&self, _repo_path: RepoPath) -> Result<Vec<TrafficReferrer>> {
+		Err(Error::unsupported("GitCode", "traffic referrers"))
+	}
+
+	async fn paths(&self, _repo_path: RepoPath) -> Result<Vec<TrafficPath>> {
+		Err(Error::unsupported("GitCode", "traffic paths"))
+	}
+}
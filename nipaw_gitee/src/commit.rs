@@ -2,7 +2,7 @@ use crate::GiteeClientInner;
 use async_trait::async_trait;
 use crate::common::JsonValue;
 use nipaw_core::option::commit::ListOptions;
-use nipaw_core::types::commit::{CommitInfo, CommitListInfo};
+use nipaw_core::types::commit::{CommitInfo, CommitListInfo, CompareInfo};
 use nipaw_core::types::repo::RepoPath;
 use nipaw_core::{Commit, Result};
 use serde_json::Value;
@@ -11,6 +11,28 @@ use std::sync::Arc;
 
 pub struct GiteeCommit(pub(crate) Arc<GiteeClientInner>);
 
+impl GiteeCommit {
+	pub(crate) async fn get_compare_info(
+		&self,
+		repo_path: &RepoPath,
+		base: &str,
+		head: &str,
+	) -> Result<JsonValue> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		let url = format!(
+			"{}/repos/{}/{}/compare/{}...{}",
+			api_url, repo_path.owner, repo_path.repo, base, head
+		);
+		let client = self.0.client.read().await;
+		let mut request = client.get(url);
+		if let Some(token) = token {
+			request = request.query(&[("access_token", token.as_str())]);
+		}
+		let res = request.send().await?.json::<JsonValue>().await?;
+		Ok(res)
+	}
+}
+
 #[async_trait]
 impl Commit for GiteeCommit {
 	async fn info(&self, repo_path: RepoPath, sha: Option<&str>) -> Result<CommitInfo> {
@@ -101,4 +123,49 @@ impl Commit for GiteeCommit {
 		let commit_infos: Vec<JsonValue> = resp.json().await?;
 		Ok(commit_infos.into_iter().map(|v| v.into()).collect())
 	}
+
+	async fn compare(&self, repo_path: RepoPath, base: &str, head: &str) -> Result<CompareInfo> {
+		let mut compare_info = self.get_compare_info(&repo_path, base, head).await?;
+		// Gitee不返回领先/落后数, 反向比较一次得到落后的提交数
+		let behind_by = self
+			.get_compare_info(&repo_path, head, base)
+			.await?
+			.0
+			.get("commits")
+			.and_then(|v| v.as_array())
+			.map(|arr| arr.len())
+			.unwrap_or(0);
+		if let Some(obj) = compare_info.0.as_object_mut() {
+			obj.insert("behind_by".to_string(), Value::Number(behind_by.into()));
+		}
+		if let Some(commits) = compare_info.0.get_mut("commits").and_then(|v| v.as_array_mut()) {
+			commits.iter_mut().for_each(fill_avatar_url);
+		}
+		Ok(compare_info.into())
+	}
+
+	async fn diff(&self, repo_path: RepoPath, sha: &str) -> Result<String> {
+		// Gitee没有返回原始差异的接口, 由各文件的差异内容拼接
+		let commit_info = self.info(repo_path, Some(sha)).await?;
+		Ok(commit_info.files.iter().filter_map(|file| file.to_unified_diff()).collect())
+	}
+}
+
+/// 将平台账号的头像写入提交数据的作者与提交者中, 未关联账号时为空
+fn fill_avatar_url(commit: &mut Value) {
+	for role in ["author", "committer"] {
+		let avatar_url = commit
+			.get(role)
+			.and_then(|v| v.get("avatar_url"))
+			.and_then(|v| v.as_str())
+			.unwrap_or_default()
+			.to_string();
+		if let Some(user) = commit
+			.get_mut("commit")
+			.and_then(|commit| commit.get_mut(role))
+			.and_then(|user| user.as_object_mut())
+		{
+			user.insert("avatar_url".to_string(), Value::String(avatar_url));
+		}
+	}
 }
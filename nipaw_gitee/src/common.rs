@@ -1,10 +1,18 @@
 mod commit;
+mod gist;
+mod git;
 mod issue;
+mod key;
+mod notification;
 mod org;
 mod release;
 mod repo;
+mod status;
 mod user;
 
+pub(crate) use git::branch_ref;
+pub(crate) use issue::parse_event;
+pub(crate) use repo::parse_topics;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
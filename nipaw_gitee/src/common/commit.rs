@@ -1,6 +1,7 @@
 use crate::common::JsonValue;
 use nipaw_core::types::commit::{
-	CommitData, CommitInfo, CommitListInfo, FileInfo, FileStatus, StatsInfo, UserInfo,
+	CommitData, CommitInfo, CommitListInfo, CompareInfo, FileInfo, FileStatus, StatsInfo,
+	UserInfo,
 };
 
 impl From<JsonValue> for CommitInfo {
@@ -32,6 +33,30 @@ impl From<JsonValue> for CommitListInfo {
 	}
 }
 
+impl From<JsonValue> for CompareInfo {
+	fn from(value: JsonValue) -> Self {
+		let compare_info = value.0;
+		let commits: Vec<CommitListInfo> = compare_info
+			.get("commits")
+			.and_then(|v| v.as_array())
+			.map(|arr| arr.iter().map(|v| JsonValue(v.clone()).into()).collect())
+			.unwrap_or_default();
+		Self {
+			ahead_by: compare_info
+				.get("ahead_by")
+				.and_then(|v| v.as_u64())
+				.unwrap_or(commits.len() as u64),
+			behind_by: compare_info.get("behind_by").and_then(|v| v.as_u64()).unwrap_or(0),
+			commits,
+			files: compare_info
+				.get("files")
+				.and_then(|v| v.as_array())
+				.map(|arr| arr.iter().map(|v| JsonValue(v.clone()).into()).collect())
+				.unwrap_or_default(),
+		}
+	}
+}
+
 impl From<JsonValue> for CommitData {
 	fn from(commit: JsonValue) -> Self {
 		let commit_data = commit.0;
@@ -92,6 +117,13 @@ impl From<JsonValue> for FileInfo {
 			additions: file_info.get("additions").and_then(|v| v.as_u64()).unwrap_or(0),
 			deletions: file_info.get("deletions").and_then(|v| v.as_u64()).unwrap_or(0),
 			changes: file_info.get("changes").and_then(|v| v.as_u64()).unwrap_or(0),
+			patch: file_info.get("patch").and_then(|v| v.as_str()).map(|s| s.to_string()),
+			previous_file_name: file_info
+				.get("previous_filename")
+				.and_then(|v| v.as_str())
+				.map(|s| s.to_string()),
+			sha: file_info.get("sha").and_then(|v| v.as_str()).map(|s| s.to_string()),
+			raw_url: file_info.get("raw_url").and_then(|v| v.as_str()).map(|s| s.to_string()),
 		}
 	}
 }
@@ -1,6 +1,9 @@
 use crate::common::JsonValue;
-use chrono::Utc;
-use nipaw_core::types::issue::{IssueInfo, LabelInfo, StateType, UserInfo};
+use chrono::{DateTime, Utc};
+use nipaw_core::types::issue::{
+	IssueCommentInfo, IssueEvent, IssueEventInfo, IssueInfo, LabelInfo, StateType, UserInfo,
+};
+use serde_json::Value;
 
 impl From<JsonValue> for IssueInfo {
 	fn from(issue: JsonValue) -> Self {
@@ -38,6 +41,16 @@ impl From<JsonValue> for IssueInfo {
 				.get("finished_at")
 				.and_then(|v| v.as_str())
 				.and_then(|s| s.parse::<chrono::DateTime<Utc>>().ok()),
+			assignees: parse_assignees(&issue_info),
+			comments: issue_info.get("comments").and_then(|v| v.as_u64()).unwrap_or_default(),
+			milestone: issue_info
+				.get("milestone")
+				.and_then(|v| v.get("title"))
+				.and_then(|v| v.as_str())
+				.map(|s| s.to_string()),
+			html_url: issue_info.get("html_url").and_then(|v| v.as_str()).map(|s| s.to_string()),
+			locked: None,
+			is_pull_request: false,
 		}
 	}
 }
@@ -76,3 +89,79 @@ impl From<JsonValue> for Vec<LabelInfo> {
 			.unwrap_or_default()
 	}
 }
+
+/// 解析可能缺失的用户信息
+fn parse_user(user: Option<&Value>) -> Option<UserInfo> {
+	let user = user?;
+	Some(UserInfo {
+		login: user.get("login")?.as_str()?.to_string(),
+		avatar_url: user.get("avatar_url").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+		email: user
+			.get("email")
+			.and_then(|v| v.as_str())
+			.filter(|s| !s.is_empty())
+			.map(|s| s.to_string()),
+	})
+}
+
+/// 解析负责人, Gitee的议题只有一个负责人, 其余负责人记录为协作者
+fn parse_assignees(issue: &Value) -> Vec<UserInfo> {
+	let collaborators = issue.get("collaborators").and_then(|v| v.as_array());
+	parse_user(issue.get("assignee"))
+		.into_iter()
+		.chain(collaborators.into_iter().flatten().filter_map(|v| parse_user(Some(v))))
+		.collect()
+}
+
+fn parse_time(value: &Value, key: &str) -> Option<DateTime<Utc>> {
+	value.get(key).and_then(|v| v.as_str()).and_then(|s| s.parse().ok())
+}
+
+impl From<JsonValue> for IssueCommentInfo {
+	fn from(comment: JsonValue) -> Self {
+		let comment_info = comment.0;
+		Self {
+			id: comment_info.get("id").and_then(|v| v.as_u64()).unwrap_or_default().to_string(),
+			user: parse_user(comment_info.get("user")),
+			body: comment_info.get("body").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+			created_at: parse_time(&comment_info, "created_at").unwrap_or_default(),
+			updated_at: parse_time(&comment_info, "updated_at"),
+		}
+	}
+}
+
+/// 解析操作日志
+///
+/// 按操作类型映射事件, 关闭与重新打开不需要额外数据; 标签名与负责人只从结构化字段读取,
+/// 缺少对应字段或操作类型未知时返回[IssueEvent::Other], 值为原始操作类型
+pub(crate) fn parse_event(log: &Value) -> Option<IssueEventInfo> {
+	let action =
+		log.get("action_type").or(log.get("icon")).and_then(|v| v.as_str()).unwrap_or_default();
+	let kind = action.strip_prefix("icon-").unwrap_or(action).to_lowercase().replace('-', "_");
+	let label = || log.get("label").and_then(|v| v.get("name")).and_then(|v| v.as_str());
+	let assignee = || parse_user(log.get("assignee")).map(|u| u.login);
+	let issue_event = match kind.as_str() {
+		"close" | "closed" | "close_issue" => Some(IssueEvent::Closed { commit_id: None }),
+		"reopen" | "reopened" | "reopen_issue" => Some(IssueEvent::Reopened),
+		"label" | "labeled" | "add_label" => {
+			label().map(|label| IssueEvent::Labeled { label: label.to_string() })
+		}
+		"unlabeled" | "remove_label" | "delete_label" => {
+			label().map(|label| IssueEvent::Unlabeled { label: label.to_string() })
+		}
+		"assign" | "assigned" | "add_assignee" => {
+			assignee().map(|assignee| IssueEvent::Assigned { assignee })
+		}
+		"unassign" | "unassigned" | "remove_assignee" => {
+			assignee().map(|assignee| IssueEvent::Unassigned { assignee })
+		}
+		_ => None,
+	};
+	let issue_event = issue_event.unwrap_or_else(|| IssueEvent::Other(action.to_string()));
+	Some(IssueEventInfo {
+		id: log.get("id").and_then(|v| v.as_u64()).map(|id| id.to_string()),
+		actor: parse_user(log.get("user")),
+		event: issue_event,
+		created_at: parse_time(log, "created_at")?,
+	})
+}
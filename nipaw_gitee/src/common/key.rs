@@ -0,0 +1,44 @@
+use crate::common::JsonValue;
+use nipaw_core::types::key::{self, DeployKeyInfo, SshKeyInfo};
+
+impl From<JsonValue> for SshKeyInfo {
+	fn from(value: JsonValue) -> Self {
+		let key_info = value.0;
+		let key = key_info.get("key").and_then(|v| v.as_str()).unwrap_or_default().to_string();
+		Self {
+			id: key_info.get("id").and_then(|v| v.as_u64()).unwrap_or(0),
+			title: key_info.get("title").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+			// 平台未返回指纹时在本地计算
+			fingerprint: key_info
+				.get("fingerprint")
+				.and_then(|v| v.as_str())
+				.filter(|s| !s.is_empty())
+				.map(|s| s.to_string())
+				.or_else(|| key::ssh_fingerprint(&key).ok()),
+			key,
+			created_at: key_info
+				.get("created_at")
+				.and_then(|v| v.as_str())
+				.and_then(|s| s.parse().ok()),
+		}
+	}
+}
+
+impl From<JsonValue> for DeployKeyInfo {
+	fn from(value: JsonValue) -> Self {
+		let key_info = value.0;
+		let key = key_info.get("key").and_then(|v| v.as_str()).unwrap_or_default().to_string();
+		Self {
+			id: key_info.get("id").and_then(|v| v.as_u64()).unwrap_or(0),
+			title: key_info.get("title").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+			// 未返回时视为只读
+			read_only: key_info.get("read_only").and_then(|v| v.as_bool()).unwrap_or(true),
+			fingerprint: key::ssh_fingerprint(&key).ok(),
+			key,
+			created_at: key_info
+				.get("created_at")
+				.and_then(|v| v.as_str())
+				.and_then(|s| s.parse().ok()),
+		}
+	}
+}
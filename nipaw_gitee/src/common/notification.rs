@@ -0,0 +1,49 @@
+use crate::common::JsonValue;
+use nipaw_core::types::notification::{NotificationInfo, SubjectType};
+use nipaw_core::types::repo::RepoPath;
+
+impl From<JsonValue> for NotificationInfo {
+	fn from(value: JsonValue) -> Self {
+		let notification = value.0;
+		let subject = notification.get("subject");
+		let repository = notification.get("repository");
+		// Gitee的仓库字段中`full_name`为`所有者/仓库名`
+		let repo_path = repository
+			.and_then(|v| v.get("full_name"))
+			.and_then(|v| v.as_str())
+			.and_then(|s| s.split_once('/'))
+			.map(|(owner, repo)| RepoPath::new(owner, repo))
+			.unwrap_or_else(|| RepoPath::new("", ""));
+		Self {
+			id: match notification.get("id") {
+				Some(id) if id.is_number() => id.to_string(),
+				Some(id) => id.as_str().unwrap_or_default().to_string(),
+				None => String::new(),
+			},
+			subject_type: subject
+				.and_then(|v| v.get("type"))
+				.and_then(|v| v.as_str())
+				.map(SubjectType::parse)
+				.unwrap_or(SubjectType::Other),
+			title: subject
+				.and_then(|v| v.get("title"))
+				.and_then(|v| v.as_str())
+				.unwrap_or_default()
+				.to_string(),
+			repo_path,
+			// Gitee只区分`event`与`referer`(被提及)
+			reason: notification.get("type").and_then(|v| v.as_str()).map(|s| s.to_string()),
+			unread: notification.get("unread").and_then(|v| v.as_bool()).unwrap_or(false),
+			updated_at: notification
+				.get("updated_at")
+				.and_then(|v| v.as_str())
+				.and_then(|s| s.parse().ok())
+				.unwrap_or_default(),
+			html_url: notification
+				.get("html_url")
+				.and_then(|v| v.as_str())
+				.filter(|s| !s.is_empty())
+				.map(|s| s.to_string()),
+		}
+	}
+}
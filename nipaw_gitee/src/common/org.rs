@@ -1,5 +1,6 @@
 use crate::common::JsonValue;
-use nipaw_core::types::org::OrgInfo;
+use nipaw_core::types::org::{MemberRole, OrgInfo, OrgMember};
+use serde_json::Value;
 
 impl From<JsonValue> for OrgInfo {
 	fn from(org: JsonValue) -> Self {
@@ -26,3 +27,35 @@ impl From<JsonValue> for OrgInfo {
 		}
 	}
 }
+
+impl From<JsonValue> for OrgMember {
+	fn from(value: JsonValue) -> Self {
+		let member = value.0;
+		Self {
+			login: member.get("login").and_then(|v| v.as_str()).unwrap().to_string(),
+			avatar_url: member
+				.get("avatar_url")
+				.and_then(|v| v.as_str())
+				.unwrap_or_default()
+				.to_string(),
+			role: JsonValue(member).into(),
+		}
+	}
+}
+
+/// 支持`role`字符串与`access_level`两种格式
+impl From<JsonValue> for MemberRole {
+	fn from(value: JsonValue) -> Self {
+		let member = value.0;
+		let role = member.get("role").or(member.get("member_role")).and_then(|v| v.as_str());
+		let is_admin = match role {
+			Some(role) => matches!(role, "admin" | "owner"),
+			None => match member.get("access_level") {
+				Some(Value::Number(level)) => level.as_u64().unwrap_or(0) >= 40,
+				Some(Value::String(level)) => matches!(level.as_str(), "Owner" | "Master"),
+				_ => false,
+			},
+		};
+		if is_admin { MemberRole::Admin } else { MemberRole::Member }
+	}
+}
@@ -1,10 +1,15 @@
 use crate::common::JsonValue;
-use nipaw_core::types::repo::{CollaboratorResult, RepoInfo, Visibility};
+use nipaw_core::types::repo::{
+	CollaboratorInfo, CollaboratorPermission, CollaboratorResult, ContributorInfo, LanguageUnit,
+	RepoInfo, RepoLanguages, RepoPath, Visibility,
+};
+use serde_json::Value;
 
 impl From<JsonValue> for RepoInfo {
 	fn from(json_value: JsonValue) -> Self {
 		let repo_info = json_value.0;
 		let is_public = repo_info.get("public").and_then(|v| v.as_bool()).unwrap_or(false);
+		let is_internal = repo_info.get("internal").and_then(|v| v.as_bool()).unwrap_or(false);
 		let owner = repo_info
 			.get("owner")
 			.and_then(|v| v.get("login"))
@@ -21,14 +26,26 @@ impl From<JsonValue> for RepoInfo {
 				.and_then(|v| v.as_str())
 				.filter(|s| !s.is_empty())
 				.map(|s| s.to_string()),
-			visibility: if is_public { Visibility::Public } else { Visibility::Private },
+			visibility: if is_public {
+				Visibility::Public
+			} else if is_internal {
+				Visibility::Internal
+			} else {
+				Visibility::Private
+			},
 			fork: repo_info.get("fork").and_then(|v| v.as_bool()).unwrap_or(false),
 			fork_count: repo_info.get("forks_count").and_then(|v| v.as_u64()).unwrap_or(0),
+			parent: repo_info.get("parent").and_then(|parent| {
+				let owner = parent.get("owner")?.get("login")?.as_str()?;
+				let name = parent.get("path")?.as_str()?;
+				Some(RepoPath::new(owner, name))
+			}),
 			language: repo_info
 				.get("language")
 				.and_then(|v| v.as_str())
 				.filter(|s| !s.is_empty())
 				.map(|s| s.to_string()),
+			topics: parse_topics(repo_info.get("project_labels")),
 			star_count: repo_info.get("stargazers_count").and_then(|v| v.as_u64()).unwrap_or(0),
 			default_branch: repo_info
 				.get("default_branch")
@@ -73,3 +90,94 @@ impl From<JsonValue> for CollaboratorResult {
 		}
 	}
 }
+
+impl From<JsonValue> for CollaboratorInfo {
+	fn from(json_value: JsonValue) -> Self {
+		let collaborator = json_value.0;
+		Self {
+			login: collaborator.get("login").and_then(|v| v.as_str()).unwrap().to_string(),
+			avatar_url: collaborator
+				.get("avatar_url")
+				.and_then(|v| v.as_str())
+				.unwrap_or_default()
+				.to_string(),
+			permission: JsonValue(collaborator).into(),
+		}
+	}
+}
+
+/// 支持`permission`字符串与`permissions`对象两种格式
+impl From<JsonValue> for CollaboratorPermission {
+	fn from(json_value: JsonValue) -> Self {
+		let value = json_value.0;
+		if let Some(permission) = value.get("permission").and_then(|v| v.as_str()) {
+			return match permission {
+				"admin" => CollaboratorPermission::Admin,
+				"maintain" | "write" | "push" => CollaboratorPermission::Push,
+				"triage" | "read" | "pull" => CollaboratorPermission::Pull,
+				_ => CollaboratorPermission::None,
+			};
+		}
+		let permissions = value.get("permissions");
+		let has = |key: &str| {
+			permissions.and_then(|v| v.get(key)).and_then(|v| v.as_bool()).unwrap_or(false)
+		};
+		if has("admin") {
+			CollaboratorPermission::Admin
+		} else if has("push") {
+			CollaboratorPermission::Push
+		} else if has("pull") {
+			CollaboratorPermission::Pull
+		} else {
+			CollaboratorPermission::None
+		}
+	}
+}
+
+/// 统一主题格式, 兼容字符串数组、带`name`字段的对象数组以及逗号分隔的字符串
+pub(crate) fn parse_topics(value: Option<&Value>) -> Vec<String> {
+	let topics: Vec<String> = match value {
+		Some(Value::Array(items)) => items
+			.iter()
+			.filter_map(|v| v.as_str().or_else(|| v.get("name").and_then(|v| v.as_str())))
+			.map(|s| s.trim().to_string())
+			.collect(),
+		Some(Value::String(s)) => s.split(',').map(|s| s.trim().to_string()).collect(),
+		_ => Vec::new(),
+	};
+	topics.into_iter().filter(|s| !s.is_empty()).collect()
+}
+
+impl From<JsonValue> for RepoLanguages {
+	fn from(json_value: JsonValue) -> Self {
+		let languages = json_value
+			.0
+			.as_object()
+			.map(|map| map.iter().filter_map(|(k, v)| Some((k.clone(), v.as_f64()?))).collect())
+			.unwrap_or_default();
+		Self { unit: LanguageUnit::Bytes, languages }
+	}
+}
+
+impl From<JsonValue> for ContributorInfo {
+	fn from(json_value: JsonValue) -> Self {
+		let contributor = json_value.0;
+		let get_str = |key: &str| {
+			contributor
+				.get(key)
+				.and_then(|v| v.as_str())
+				.filter(|s| !s.is_empty())
+				.map(|s| s.to_string())
+		};
+		Self {
+			login: get_str("login").or_else(|| get_str("name")).unwrap_or_default(),
+			email: get_str("email"),
+			avatar_url: get_str("avatar_url"),
+			contributions: contributor
+				.get("contributions")
+				.or_else(|| contributor.get("commits"))
+				.and_then(|v| v.as_u64())
+				.unwrap_or(0),
+		}
+	}
+}
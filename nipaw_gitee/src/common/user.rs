@@ -20,9 +20,9 @@ impl From<JsonValue> for UserInfo {
 				.and_then(|v| v.as_str())
 				.filter(|s| !s.is_empty())
 				.map(|s| s.to_string()),
-			followers: user_info.get("followers").and_then(|v| v.as_u64()).unwrap(),
-			following: user_info.get("following").and_then(|v| v.as_u64()).unwrap(),
-			public_repo_count: user_info.get("public_repos").and_then(|v| v.as_u64()).unwrap(),
+			followers: user_info.get("followers").and_then(|v| v.as_u64()).unwrap_or(0),
+			following: user_info.get("following").and_then(|v| v.as_u64()).unwrap_or(0),
+			public_repo_count: user_info.get("public_repos").and_then(|v| v.as_u64()).unwrap_or(0),
 		}
 	}
 }
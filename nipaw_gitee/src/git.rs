@@ -0,0 +1,152 @@
+use crate::GiteeClientInner;
+use crate::common::{JsonValue, branch_ref};
+use async_trait::async_trait;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use nipaw_core::option::git::{FileChange, TreeEntryOptions};
+use nipaw_core::types::git::{GitCommit, GitRef, GitTree};
+use nipaw_core::types::repo::RepoPath;
+use nipaw_core::{Error, Git, Result};
+use reqwest::Method;
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+pub struct GiteeGit(pub(crate) Arc<GiteeClientInner>);
+
+impl GiteeGit {
+	fn repo_url(&self, repo_path: &RepoPath, path: &str) -> String {
+		format!("{}/repos/{}/{}/{}", self.0.config.api_url, repo_path.owner, repo_path.repo, path)
+	}
+
+	/// 发送请求并解析响应, 读取操作在未登录时也可以访问公开仓库
+	async fn send_json(
+		&self,
+		method: Method,
+		url: String,
+		query: HashMap<&str, String>,
+		body: Option<Value>,
+	) -> Result<JsonValue> {
+		let token = &self.0.config.token;
+		if token.is_none() && method != Method::GET {
+			return Err(Error::TokenEmpty);
+		}
+		let mut query = query;
+		if let Some(token) = token {
+			query.insert("access_token", token.to_string());
+		}
+		let client = self.0.client.read().await;
+		let mut request = client.request(method, url).query(&query);
+		if let Some(body) = body {
+			request = request.json(&body);
+		}
+		Ok(request.send().await?.error_for_status()?.json::<JsonValue>().await?)
+	}
+}
+
+/// 取出分支名, Gitee只开放了分支的读取与创建
+fn branch_name(reference: &str) -> Result<&str> {
+	let reference = reference.strip_prefix("refs/").unwrap_or(reference);
+	reference.strip_prefix("heads/").ok_or(Error::unsupported("Gitee", "refs other than branches"))
+}
+
+#[async_trait]
+impl Git for GiteeGit {
+	async fn get_ref(&self, repo_path: RepoPath, reference: &str) -> Result<GitRef> {
+		let url = self.repo_url(&repo_path, &format!("branches/{}", branch_name(reference)?));
+		let res = self.send_json(Method::GET, url, HashMap::new(), None).await?;
+		Ok(branch_ref(res))
+	}
+
+	async fn create_ref(&self, repo_path: RepoPath, reference: &str, sha: &str) -> Result<GitRef> {
+		let url = self.repo_url(&repo_path, "branches");
+		let body = serde_json::json!({ "refs": sha, "branch_name": branch_name(reference)? });
+		let res = self.send_json(Method::POST, url, HashMap::new(), Some(body)).await?;
+		Ok(branch_ref(res))
+	}
+
+	async fn update_ref(
+		&self,
+		_repo_path: RepoPath,
+		_reference: &str,
+		_sha: &str,
+		_force: bool,
+	) -> Result<GitRef> {
+		Err(Error::unsupported("Gitee", "updating refs"))
+	}
+
+	async fn delete_ref(&self, _repo_path: RepoPath, _reference: &str) -> Result<()> {
+		Err(Error::unsupported("Gitee", "deleting refs"))
+	}
+
+	async fn tree(&self, repo_path: RepoPath, sha: &str, recursive: bool) -> Result<GitTree> {
+		let url = self.repo_url(&repo_path, &format!("git/trees/{}", sha));
+		let mut query = HashMap::new();
+		if recursive {
+			query.insert("recursive", "1".to_string());
+		}
+		Ok(self.send_json(Method::GET, url, query, None).await?.into())
+	}
+
+	async fn create_blob(&self, _repo_path: RepoPath, _content: &[u8]) -> Result<String> {
+		Err(Error::unsupported("Gitee", "creating blobs"))
+	}
+
+	async fn create_tree(
+		&self,
+		_repo_path: RepoPath,
+		_base_tree: Option<&str>,
+		_entries: Vec<TreeEntryOptions>,
+	) -> Result<GitTree> {
+		Err(Error::unsupported("Gitee", "creating trees"))
+	}
+
+	async fn get_commit(&self, repo_path: RepoPath, sha: &str) -> Result<GitCommit> {
+		let url = self.repo_url(&repo_path, &format!("commits/{}", sha));
+		Ok(self.send_json(Method::GET, url, HashMap::new(), None).await?.into())
+	}
+
+	async fn create_commit(
+		&self,
+		_repo_path: RepoPath,
+		_message: &str,
+		_tree: &str,
+		_parents: Vec<String>,
+	) -> Result<GitCommit> {
+		Err(Error::unsupported("Gitee", "creating commits from trees"))
+	}
+
+	async fn commit_files(
+		&self,
+		repo_path: RepoPath,
+		branch: &str,
+		message: &str,
+		changes: Vec<FileChange>,
+	) -> Result<GitCommit> {
+		// 提交多个文件接口需要区分新建与更新, 先读取分支上已有的文件
+		let tree = self.tree(repo_path.clone(), branch, true).await?;
+		if tree.truncated {
+			// 截断的文件列表无法判断文件是否存在, 直接失败以免提交错误的操作
+			return Err(Error::InvalidParam {
+				param: "branch".to_string(),
+				reason: "file tree is truncated".to_string(),
+			});
+		}
+		let existing: HashSet<String> = tree.entries.into_iter().map(|e| e.path).collect();
+		let actions = changes
+			.into_iter()
+			.map(|change| match change.content {
+				Some(content) => serde_json::json!({
+					"action": if existing.contains(&change.path) { "update" } else { "create" },
+					"path": change.path,
+					"content": STANDARD.encode(content.as_bytes()),
+					"encoding": "base64",
+				}),
+				None => serde_json::json!({ "action": "delete", "path": change.path }),
+			})
+			.collect::<Vec<_>>();
+		let url = self.repo_url(&repo_path, "commits");
+		let body = serde_json::json!({ "branch": branch, "message": message, "actions": actions });
+		Ok(self.send_json(Method::POST, url, HashMap::new(), Some(body)).await?.into())
+	}
+}
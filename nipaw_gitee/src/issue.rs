@@ -1,15 +1,80 @@
 use crate::GiteeClientInner;
-use crate::common::JsonValue;
+use crate::common::{JsonValue, parse_event};
 use async_trait::async_trait;
-use nipaw_core::option::issue::{CreateOptions, ListOptions, UpdateOptions};
-use nipaw_core::types::issue::{IssueInfo, StateType};
+use nipaw_core::option::issue::{CreateOptions, EventListOptions, ListOptions, UpdateOptions};
+use nipaw_core::types::issue::{IssueEventInfo, IssueInfo, StateType, TimelineItem};
 use nipaw_core::types::repo::RepoPath;
 use nipaw_core::{Error, Issue, Result};
 use std::collections::HashMap;
 use std::sync::Arc;
 
+/// Gitee未开放议题锁定的接口
 pub struct GiteeIssue(pub(crate) Arc<GiteeClientInner>);
 
+impl GiteeIssue {
+	/// 获取分页列表, 公开仓库可以不登录
+	async fn get_list(
+		&self,
+		url: String,
+		options: Option<EventListOptions>,
+	) -> Result<Vec<JsonValue>> {
+		let token = &self.0.config.token;
+		let options = options.unwrap_or_default();
+		let mut params: HashMap<&str, String> = HashMap::new();
+		let per_page = options.per_page.unwrap_or(30).min(100);
+		params.insert("per_page", per_page.to_string());
+		let page = options.page.unwrap_or(1);
+		params.insert("page", page.to_string());
+		if let Some(token) = token {
+			params.insert("access_token", token.to_string());
+		}
+		let client = self.0.client.read().await;
+		let request = client.get(url).query(&params);
+		Ok(request.send().await?.json::<Vec<JsonValue>>().await?)
+	}
+
+	/// 逐页获取完整列表
+	async fn get_all(&self, url: String) -> Result<Vec<JsonValue>> {
+		let mut items = Vec::new();
+		for page in 1.. {
+			let options = EventListOptions { per_page: Some(100), page: Some(page) };
+			let res = self.get_list(url.clone(), Some(options)).await?;
+			let len = res.len();
+			items.extend(res);
+			if len < 100 {
+				break;
+			}
+		}
+		Ok(items)
+	}
+
+	/// 覆盖议题的负责人, 第一个为负责人, 其余为协作者
+	async fn set_assignees(
+		&self,
+		repo_path: RepoPath,
+		issue_number: &str,
+		assignees: Vec<String>,
+	) -> Result<IssueInfo> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let url = format!("{}/repos/{}/issues/{}", api_url, repo_path.owner, issue_number);
+		let client = self.0.client.read().await;
+		let request = client.patch(url).query(&[("access_token", token.as_ref().unwrap())]);
+		let mut req_body: HashMap<&str, String> = HashMap::new();
+		req_body.insert("repo", repo_path.repo.to_string());
+		req_body.insert("assignee", assignees.first().cloned().unwrap_or_default());
+		req_body.insert(
+			"collaborators",
+			assignees.iter().skip(1).cloned().collect::<Vec<_>>().join(","),
+		);
+		let res =
+			request.form(&req_body).send().await?.error_for_status()?.json::<JsonValue>().await?;
+		Ok(res.into())
+	}
+}
+
 #[async_trait]
 impl Issue for GiteeIssue {
 	async fn create(
@@ -128,4 +193,88 @@ impl Issue for GiteeIssue {
 		let res = request.form(&req_body).send().await?.json::<JsonValue>().await?;
 		Ok(res.into())
 	}
+
+	async fn events(
+		&self,
+		repo_path: RepoPath,
+		issue_number: &str,
+		options: Option<EventListOptions>,
+	) -> Result<Vec<IssueEventInfo>> {
+		// 操作日志接口的仓库名通过`repo`参数传入
+		let url = format!(
+			"{}/repos/{}/issues/{}/operate_logs?repo={}&sort=asc",
+			self.0.config.api_url, repo_path.owner, issue_number, repo_path.repo
+		);
+		let res = self.get_list(url, options).await?;
+		Ok(res.iter().filter_map(|v| parse_event(&v.0)).collect())
+	}
+
+	async fn timeline(
+		&self,
+		repo_path: RepoPath,
+		issue_number: &str,
+		options: Option<EventListOptions>,
+	) -> Result<Vec<TimelineItem>> {
+		// 两个接口各自分页, 需要完整获取后按时间合并再分页, 否则跨页的条目顺序会错乱
+		let comments_url = format!(
+			"{}/repos/{}/{}/issues/{}/comments",
+			self.0.config.api_url, repo_path.owner, repo_path.repo, issue_number
+		);
+		let events_url = format!(
+			"{}/repos/{}/issues/{}/operate_logs?repo={}&sort=asc",
+			self.0.config.api_url, repo_path.owner, issue_number, repo_path.repo
+		);
+		let comments = self.get_all(comments_url).await?;
+		let events = self.get_all(events_url).await?;
+		let mut timeline: Vec<TimelineItem> = comments
+			.into_iter()
+			.map(|v| TimelineItem::Comment(v.into()))
+			.chain(events.iter().filter_map(|v| parse_event(&v.0)).map(TimelineItem::Event))
+			.collect();
+		timeline.sort_by_key(|item| item.created_at());
+		let options = options.unwrap_or_default();
+		let per_page = options.per_page.unwrap_or(30).min(100) as usize;
+		let page = options.page.unwrap_or(1).max(1) as usize;
+		Ok(timeline.into_iter().skip((page - 1) * per_page).take(per_page).collect())
+	}
+
+	async fn add_assignees(
+		&self,
+		repo_path: RepoPath,
+		issue_number: &str,
+		assignees: Vec<String>,
+	) -> Result<IssueInfo> {
+		let issue_info = self.info(repo_path.clone(), issue_number).await?;
+		let mut logins: Vec<String> = issue_info.assignees.into_iter().map(|u| u.login).collect();
+		for assignee in assignees {
+			if !logins.contains(&assignee) {
+				logins.push(assignee);
+			}
+		}
+		self.set_assignees(repo_path, issue_number, logins).await
+	}
+
+	async fn remove_assignees(
+		&self,
+		repo_path: RepoPath,
+		issue_number: &str,
+		assignees: Vec<String>,
+	) -> Result<IssueInfo> {
+		let issue_info = self.info(repo_path.clone(), issue_number).await?;
+		let logins = issue_info
+			.assignees
+			.into_iter()
+			.map(|u| u.login)
+			.filter(|login| !assignees.contains(login))
+			.collect();
+		self.set_assignees(repo_path, issue_number, logins).await
+	}
+
+	async fn lock(&self, _repo_path: RepoPath, _issue_number: &str) -> Result<()> {
+		Err(Error::unsupported("Gitee", "issue lock"))
+	}
+
+	async fn unlock(&self, _repo_path: RepoPath, _issue_number: &str) -> Result<()> {
+		Err(Error::unsupported("Gitee", "issue lock"))
+	}
 }
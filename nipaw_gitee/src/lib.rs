@@ -1,21 +1,45 @@
 mod commit;
 mod common;
+mod gist;
+mod git;
 mod issue;
+mod key;
 mod middleware;
+mod notification;
 mod org;
+mod pipeline;
 mod release;
 mod repo;
+mod search;
+mod secret;
+mod stats;
+mod status;
+mod traffic;
 mod user;
 
-pub use nipaw_core::{Client, Commit, Config, Error, Issue, Org, Provider, Release, Repo, Result, Token, User};
+use nipaw_core::stats::CommitStats;
+pub use nipaw_core::{
+	Client, Commit, Config, Error, Gist, Git, Issue, Key, Notifications, Org, Pipeline, Provider,
+	Release, Repo, Result, Search, Secret, Stats, Status, Token, Traffic, User,
+};
 
 use crate::{
 	commit::GiteeCommit,
+	gist::GiteeGist,
+	git::GiteeGit,
 	issue::GiteeIssue,
+	key::GiteeKey,
 	middleware::{HeaderMiddleware, ResponseMiddleware},
+	notification::GiteeNotifications,
 	org::GiteeOrg,
+	pipeline::GiteePipeline,
 	release::GiteeRelease,
 	repo::GiteeRepo,
+	search::GiteeSearch,
+	secret::GiteeSecret,
+	stats::GiteeStats,
+	status::GiteeStatus,
+	traffic::GiteeTraffic,
 	user::GiteeUser,
 };
 use reqwest_middleware::{ClientBuilder, ClientWithMiddleware};
@@ -107,6 +131,16 @@ impl Provider for GiteeClient {
 	type Commit = GiteeCommit;
 	type Issue = GiteeIssue;
 	type Release = GiteeRelease;
+	type Status = GiteeStatus;
+	type Search = GiteeSearch;
+	type Key = GiteeKey;
+	type Stats = GiteeStats;
+	type Traffic = GiteeTraffic;
+	type Notifications = GiteeNotifications;
+	type Gist = GiteeGist;
+	type Pipeline = GiteePipeline;
+	type Secret = GiteeSecret;
+	type Git = GiteeGit;
 
 	fn user(&self) -> GiteeUser {
 		GiteeUser(self.inner.clone())
@@ -131,4 +165,44 @@ impl Provider for GiteeClient {
 	fn release(&self) -> GiteeRelease {
 		GiteeRelease(self.inner.clone())
 	}
+
+	fn status(&self) -> GiteeStatus {
+		GiteeStatus(self.inner.clone())
+	}
+
+	fn search(&self) -> GiteeSearch {
+		GiteeSearch(self.inner.clone())
+	}
+
+	fn key(&self) -> GiteeKey {
+		GiteeKey(self.inner.clone())
+	}
+
+	fn stats(&self) -> GiteeStats {
+		CommitStats(GiteeCommit(self.inner.clone()))
+	}
+
+	fn traffic(&self) -> GiteeTraffic {
+		GiteeTraffic
+	}
+
+	fn notifications(&self) -> GiteeNotifications {
+		GiteeNotifications(self.inner.clone())
+	}
+
+	fn gist(&self) -> GiteeGist {
+		GiteeGist(self.inner.clone())
+	}
+
+	fn pipeline(&self) -> GiteePipeline {
+		GiteePipeline
+	}
+
+	fn secret(&self) -> GiteeSecret {
+		GiteeSecret
+	}
+
+	fn git(&self) -> GiteeGit {
+		GiteeGit(self.inner.clone())
+	}
 }
@@ -0,0 +1,87 @@
+use crate::GiteeClientInner;
+use crate::common::JsonValue;
+use async_trait::async_trait;
+use nipaw_core::option::notification::ListOptions;
+use nipaw_core::types::notification::NotificationInfo;
+use nipaw_core::types::repo::RepoPath;
+use nipaw_core::{Error, Notifications, Result};
+use reqwest::Method;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Gitee没有通知订阅接口, `subscribe`与`unsubscribe`返回[Error::Unsupported]
+pub struct GiteeNotifications(pub(crate) Arc<GiteeClientInner>);
+
+impl GiteeNotifications {
+	/// 发送不关心响应内容的请求, 需要登录
+	async fn send_empty(&self, method: Method, url: String) -> Result<()> {
+		let token = &self.0.config.token;
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let client = self.0.client.read().await;
+		let request =
+			client.request(method, url).query(&[("access_token", token.as_ref().unwrap())]);
+		request.send().await?.error_for_status()?;
+		Ok(())
+	}
+}
+
+#[async_trait]
+impl Notifications for GiteeNotifications {
+	async fn list(&self, option: Option<ListOptions>) -> Result<Vec<NotificationInfo>> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let option = option.unwrap_or_default();
+		let url = match &option.repo_path {
+			Some(repo_path) => {
+				format!("{}/repos/{}/{}/notifications", api_url, repo_path.owner, repo_path.repo)
+			}
+			None => format!("{}/notifications/threads", api_url),
+		};
+		let mut params: HashMap<&str, String> = HashMap::new();
+		let per_page = option.per_page.unwrap_or(30).min(100);
+		params.insert("per_page", per_page.to_string());
+		let page = option.page.unwrap_or(1);
+		params.insert("page", page.to_string());
+		params.insert("unread", option.unread.unwrap_or(true).to_string());
+		if let Some(participating) = option.participating {
+			params.insert("participating", participating.to_string());
+		}
+		if let Some(since) = option.since {
+			params.insert("since", since.to_rfc3339());
+		}
+		let client = self.0.client.read().await;
+		let request = client.get(url).query(&[("access_token", token.as_ref().unwrap())]);
+		// Gitee将通知列表放在`list`字段中
+		let res = request.query(&params).send().await?.json::<JsonValue>().await?;
+		let list = res.0.get("list").cloned().map(JsonValue).unwrap_or(JsonValue(res.0));
+		Ok(list.into_iter().map(|v| v.into()).collect())
+	}
+
+	async fn mark_read(&self, thread_id: &str) -> Result<()> {
+		let url = format!("{}/notifications/threads/{}", self.0.config.api_url, thread_id);
+		self.send_empty(Method::PATCH, url).await
+	}
+
+	async fn mark_all_read(&self, repo_path: Option<RepoPath>) -> Result<()> {
+		let api_url = &self.0.config.api_url;
+		let url = match repo_path {
+			Some(repo_path) => {
+				format!("{}/repos/{}/{}/notifications", api_url, repo_path.owner, repo_path.repo)
+			}
+			None => format!("{}/notifications/threads", api_url),
+		};
+		self.send_empty(Method::PUT, url).await
+	}
+
+	async fn subscribe(&self, _thread_id: &str) -> Result<()> {
+		Err(Error::unsupported("Gitee", "subscribing notification threads"))
+	}
+
+	async fn unsubscribe(&self, _thread_id: &str) -> Result<()> {
+		Err(Error::unsupported("Gitee", "unsubscribing notification threads"))
+	}
+}
@@ -0,0 +1,51 @@
+use async_trait::async_trait;
+use nipaw_core::option::pipeline::{ListOptions, TriggerOptions};
+use nipaw_core::types::pipeline::PipelineRun;
+use nipaw_core::types::release::DownloadResult;
+use nipaw_core::types::repo::RepoPath;
+use nipaw_core::{Error, Pipeline, Result};
+use tokio::io::AsyncWrite;
+
+/// Gitee未开放流水线运行的接口
+pub struct GiteePipeline;
+
+#[async_trait]
+impl Pipeline for GiteePipeline {
+	async fn list(
+		&self,
+		_repo_path: RepoPath,
+		_option: Option<ListOptions>,
+	) -> Result<Vec<PipelineRun>> {
+		Err(Error::unsupported("Gitee", "listing pipeline runs"))
+	}
+
+	async fn info(&self, _repo_path: RepoPath, _run_id: &str) -> Result<PipelineRun> {
+		Err(Error::unsupported("Gitee", "pipeline run info"))
+	}
+
+	async fn rerun(&self, _repo_path: RepoPath, _run_id: &str) -> Result<()> {
+		Err(Error::unsupported("Gitee", "rerunning pipeline"))
+	}
+
+	async fn cancel(&self, _repo_path: RepoPath, _run_id: &str) -> Result<()> {
+		Err(Error::unsupported("Gitee", "cancelling pipeline"))
+	}
+
+	async fn trigger(
+		&self,
+		_repo_path: RepoPath,
+		_workflow: &str,
+		_option: TriggerOptions,
+	) -> Result<Option<String>> {
+		Err(Error::unsupported("Gitee", "triggering pipeline"))
+	}
+
+	async fn download_logs(
+		&self,
+		_repo_path: RepoPath,
+		_run_id: &str,
+		_writer: &mut (dyn AsyncWrite + Unpin + Send),
+	) -> Result<DownloadResult> {
+		Err(Error::unsupported("Gitee", "pipeline logs"))
+	}
+}
@@ -0,0 +1,110 @@
+use crate::GiteeClientInner;
+use crate::common::JsonValue;
+use async_trait::async_trait;
+use nipaw_core::option::search::{SearchQuery, SearchSort};
+use nipaw_core::types::issue::{IssueInfo, StateType};
+use nipaw_core::types::repo::RepoInfo;
+use nipaw_core::types::search::{CodeInfo, SearchResult};
+use nipaw_core::types::user::UserInfo;
+use nipaw_core::{Error, Result, Search};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+pub struct GiteeSearch(pub(crate) Arc<GiteeClientInner>);
+
+impl GiteeSearch {
+	async fn search<T: From<JsonValue>>(
+		&self,
+		kind: &str,
+		mut params: HashMap<&str, String>,
+		query: &SearchQuery,
+	) -> Result<SearchResult<T>> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		let url = format!("{}/search/{}", api_url, kind);
+		let client = self.0.client.read().await;
+		let mut request = client.get(url);
+		if let Some(token) = token {
+			request = request.query(&[("access_token", token)]);
+		}
+		params.insert("q", query.keywords_text());
+		if let Some(order) = query.order {
+			params.insert("order", order.to_string());
+		}
+		let per_page = query.per_page.unwrap_or(30).min(100);
+		params.insert("per_page", per_page.to_string());
+		let page = query.page.unwrap_or(1);
+		params.insert("page", page.to_string());
+		let resp = request.query(&params).send().await?;
+		// 总数在响应头中返回
+		let total_count = resp
+			.headers()
+			.get("total_count")
+			.and_then(|v| v.to_str().ok())
+			.and_then(|v| v.parse::<u64>().ok());
+		let items: Vec<JsonValue> = resp.json().await?;
+		Ok(SearchResult {
+			total_count: total_count.unwrap_or(items.len() as u64),
+			incomplete: false,
+			items: items.into_iter().map(|v| v.into()).collect(),
+		})
+	}
+}
+
+#[async_trait]
+impl Search for GiteeSearch {
+	async fn repos(&self, query: SearchQuery) -> Result<SearchResult<RepoInfo>> {
+		let mut params: HashMap<&str, String> = HashMap::new();
+		if let Some(language) = query.language.as_ref() {
+			params.insert("language", language.to_string());
+		}
+		if let Some(owner) = query.owner.as_ref() {
+			params.insert("owner", owner.to_string());
+		}
+		let sort = match query.sort {
+			Some(SearchSort::Stars) => Some("stars_count"),
+			Some(SearchSort::Forks) => Some("forks_count"),
+			Some(SearchSort::Updated) => Some("last_push_at"),
+			_ => None,
+		};
+		if let Some(sort) = sort {
+			params.insert("sort", sort.to_string());
+		}
+		self.search("repositories", params, &query).await
+	}
+
+	async fn issues(&self, query: SearchQuery) -> Result<SearchResult<IssueInfo>> {
+		let mut params: HashMap<&str, String> = HashMap::new();
+		if let Some(language) = query.language.as_ref() {
+			params.insert("language", language.to_string());
+		}
+		if let Some(state) = query.state.as_ref() {
+			let state = match state {
+				StateType::Opened => "open",
+				StateType::Closed => "closed",
+			};
+			params.insert("state", state.to_string());
+		}
+		let sort = match query.sort {
+			Some(SearchSort::Comments) => Some("notes_count"),
+			Some(SearchSort::Created) => Some("created_at"),
+			Some(SearchSort::Updated) => Some("updated_at"),
+			_ => None,
+		};
+		if let Some(sort) = sort {
+			params.insert("sort", sort.to_string());
+		}
+		self.search("issues", params, &query).await
+	}
+
+	async fn users(&self, query: SearchQuery) -> Result<SearchResult<UserInfo>> {
+		let mut params: HashMap<&str, String> = HashMap::new();
+		if let Some(SearchSort::Created) = query.sort {
+			params.insert("sort", "joined_at".to_string());
+		}
+		self.search("users", params, &query).await
+	}
+
+	async fn code(&self, _query: SearchQuery) -> Result<SearchResult<CodeInfo>> {
+		Err(Error::unsupported("Gitee", "code search"))
+	}
+}
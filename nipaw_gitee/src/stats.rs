@@ -0,0 +1,5 @@
+use crate::commit::GiteeCommit;
+use nipaw_core::stats::CommitStats;
+
+/// Gitee没有统计接口, 均从提交列表本地计算
+pub type GiteeStats = CommitStats<GiteeCommit>;
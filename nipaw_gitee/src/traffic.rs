@@ -0,0 +1,28 @@
+use async_trait::async_trait;
+use nipaw_core::types::repo::RepoPath;
+use nipaw_core::types::traffic::{TrafficPath, TrafficPer, TrafficReferrer, TrafficSeries};
+use nipaw_core::{Error, Result, Traffic};
+
+/// Gitee的流量统计只在网页上展示, 没有开放接口
+///
+/// 统计页面需要登录态的浏览器会话, 令牌无法访问, 因此访问量、克隆量、来源与热门页面均返回[Error::Unsupported]
+pub struct GiteeTraffic;
+
+#[async_trait]
+impl Traffic for GiteeTraffic {
+	async fn views(&self, _repo_path: RepoPath, _per: TrafficPer) -> Result<TrafficSeries> {
+		Err(Error::unsupported("Gitee", "traffic views"))
+	}
+
+	async fn clones(&self, _repo_path: RepoPath, _per: TrafficPer) -> Result<TrafficSeries> {
+		Err(Error::unsupported("Gitee", "traffic clones"))
+	}
+
+	async fn referrers(&self, _repo_path: RepoPath) -> Result<Vec<TrafficReferrer>> {
+		Err(Error::unsupported("Gitee", "traffic referrers"))
+	}
+
+	async fn paths(&self, _repo_path: RepoPath) -> Result<Vec<TrafficPath>> {
+		Err(Error::unsupported("Gitee", "traffic paths"))
+	}
+}
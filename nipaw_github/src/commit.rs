@@ -2,7 +2,7 @@ use crate::GitHubClientInner;
 use async_trait::async_trait;
 use crate::common::JsonValue;
 use nipaw_core::option::commit::ListOptions;
-use nipaw_core::types::commit::{CommitInfo, CommitListInfo};
+use nipaw_core::types::commit::{CommitInfo, CommitListInfo, CompareInfo};
 use nipaw_core::types::repo::RepoPath;
 use nipaw_core::{Commit, Result};
 use serde_json::Value;
@@ -100,4 +100,53 @@ impl Commit for GitHubCommit {
 		let commit_infos: Vec<JsonValue> = resp.json().await?;
 		Ok(commit_infos.into_iter().map(|v| v.into()).collect())
 	}
+
+	async fn compare(&self, repo_path: RepoPath, base: &str, head: &str) -> Result<CompareInfo> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		let url = format!(
+			"{}/repos/{}/{}/compare/{}...{}",
+			api_url, repo_path.owner, repo_path.repo, base, head
+		);
+		let client = self.0.client.read().await;
+		let mut request = client.get(url);
+		if let Some(token) = token {
+			request = request.bearer_auth(token);
+		}
+		let mut res = request.send().await?.json::<JsonValue>().await?;
+		if let Some(commits) = res.0.get_mut("commits").and_then(|v| v.as_array_mut()) {
+			commits.iter_mut().for_each(fill_avatar_url);
+		}
+		Ok(res.into())
+	}
+
+	async fn diff(&self, repo_path: RepoPath, sha: &str) -> Result<String> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		let url =
+			format!("{}/repos/{}/{}/commits/{}", api_url, repo_path.owner, repo_path.repo, sha);
+		let client = self.0.client.read().await;
+		let mut request = client.get(url).header("Accept", "application/vnd.github.diff");
+		if let Some(token) = token {
+			request = request.bearer_auth(token);
+		}
+		Ok(request.send().await?.text().await?)
+	}
+}
+
+/// 将平台账号的头像写入提交数据的作者与提交者中, 未关联账号时为空
+fn fill_avatar_url(commit: &mut Value) {
+	for role in ["author", "committer"] {
+		let avatar_url = commit
+			.get(role)
+			.and_then(|v| v.get("avatar_url"))
+			.and_then(|v| v.as_str())
+			.unwrap_or_default()
+			.to_string();
+		if let Some(user) = commit
+			.get_mut("commit")
+			.and_then(|commit| commit.get_mut(role))
+			.and_then(|user| user.as_object_mut())
+		{
+			user.insert("avatar_url".to_string(), Value::String(avatar_url));
+		}
+	}
 }
@@ -1,10 +1,23 @@
 mod commit;
+mod gist;
+mod git;
 mod issue;
+mod key;
+mod notification;
 mod org;
+mod pipeline;
 mod release;
 mod repo;
+mod search;
+mod secret;
+mod stats;
+mod status;
+mod traffic;
 mod user;
 
+pub(crate) use issue::parse_event;
+pub(crate) use traffic::parse_series;
+
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -0,0 +1,68 @@
+use crate::common::JsonValue;
+use nipaw_core::types::gist::{GistFile, GistInfo};
+
+impl From<JsonValue> for GistInfo {
+	fn from(value: JsonValue) -> Self {
+		let gist_info = value.0;
+		let mut files: Vec<GistFile> = gist_info
+			.get("files")
+			.and_then(|v| v.as_object())
+			.map(|files| {
+				files
+					.iter()
+					.map(|(name, file)| GistFile {
+						filename: file
+							.get("filename")
+							.and_then(|v| v.as_str())
+							.unwrap_or(name)
+							.to_string(),
+						language: file
+							.get("language")
+							.and_then(|v| v.as_str())
+							.map(|s| s.to_string()),
+						size: file.get("size").and_then(|v| v.as_u64()).unwrap_or(0),
+						raw_url: file
+							.get("raw_url")
+							.and_then(|v| v.as_str())
+							.map(|s| s.to_string()),
+						content: file
+							.get("content")
+							.and_then(|v| v.as_str())
+							.map(|s| s.to_string()),
+					})
+					.collect()
+			})
+			.unwrap_or_default();
+		files.sort_by(|a, b| a.filename.cmp(&b.filename));
+		Self {
+			id: gist_info.get("id").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+			description: gist_info
+				.get("description")
+				.and_then(|v| v.as_str())
+				.filter(|s| !s.is_empty())
+				.map(|s| s.to_string()),
+			public: gist_info.get("public").and_then(|v| v.as_bool()).unwrap_or(false),
+			owner: gist_info
+				.get("owner")
+				.and_then(|v| v.get("login"))
+				.and_then(|v| v.as_str())
+				.map(|s| s.to_string()),
+			html_url: gist_info
+				.get("html_url")
+				.and_then(|v| v.as_str())
+				.unwrap_or_default()
+				.to_string(),
+			files,
+			created_at: gist_info
+				.get("created_at")
+				.and_then(|v| v.as_str())
+				.and_then(|s| s.parse().ok())
+				.unwrap_or_default(),
+			updated_at: gist_info
+				.get("updated_at")
+				.and_then(|v| v.as_str())
+				.and_then(|s| s.parse().ok())
+				.unwrap_or_default(),
+		}
+	}
+}
@@ -0,0 +1,94 @@
+use crate::common::JsonValue;
+use nipaw_core::Error;
+use nipaw_core::types::git::{GitCommit, GitRef, GitTree, GitTreeEntry};
+use serde_json::Value;
+
+/// 读取对象SHA, 缺失时说明响应不是预期的对象
+fn required_sha(value: Option<&Value>) -> Result<String, Error> {
+	value
+		.and_then(|v| v.get("sha"))
+		.and_then(|v| v.as_str())
+		.filter(|sha| !sha.is_empty())
+		.map(|sha| sha.to_string())
+		.ok_or(Error::NotFound { resource: "sha".to_string() })
+}
+
+impl TryFrom<JsonValue> for GitRef {
+	type Error = Error;
+
+	fn try_from(value: JsonValue) -> Result<Self, Self::Error> {
+		let git_ref = value.0;
+		let object = git_ref.get("object");
+		Ok(Self {
+			name: git_ref.get("ref").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+			sha: required_sha(object)?,
+			object_type: object
+				.and_then(|o| o.get("type"))
+				.and_then(|v| v.as_str())
+				.unwrap_or_default()
+				.to_string(),
+		})
+	}
+}
+
+impl TryFrom<JsonValue> for GitTree {
+	type Error = Error;
+
+	fn try_from(value: JsonValue) -> Result<Self, Self::Error> {
+		let tree = value.0;
+		Ok(Self {
+			sha: required_sha(Some(&tree))?,
+			truncated: tree.get("truncated").and_then(|v| v.as_bool()).unwrap_or(false),
+			entries: tree
+				.get("tree")
+				.and_then(|v| v.as_array())
+				.map(|entries| {
+					entries
+						.iter()
+						.map(|entry| {
+							let get_str = |key: &str| {
+								entry
+									.get(key)
+									.and_then(|v| v.as_str())
+									.unwrap_or_default()
+									.to_string()
+							};
+							GitTreeEntry {
+								path: get_str("path"),
+								mode: get_str("mode"),
+								entry_type: get_str("type"),
+								sha: get_str("sha"),
+								size: entry.get("size").and_then(|v| v.as_u64()),
+							}
+						})
+						.collect()
+				})
+				.unwrap_or_default(),
+		})
+	}
+}
+
+impl TryFrom<JsonValue> for GitCommit {
+	type Error = Error;
+
+	fn try_from(value: JsonValue) -> Result<Self, Self::Error> {
+		let commit = value.0;
+		Ok(Self {
+			sha: required_sha(Some(&commit))?,
+			message: commit.get("message").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+			tree: required_sha(commit.get("tree"))?,
+			parents: commit
+				.get("parents")
+				.and_then(|v| v.as_array())
+				.map(|parents| {
+					parents
+						.iter()
+						.filter_map(|p| p.get("sha").and_then(|v| v.as_str()))
+						.map(|sha| sha.to_string())
+						.collect()
+				})
+				.unwrap_or_default(),
+			html_url: commit.get("html_url").and_then(|v| v.as_str()).map(|s| s.to_string()),
+		})
+	}
+}
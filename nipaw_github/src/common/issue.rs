@@ -1,6 +1,9 @@
 use crate::common::JsonValue;
-use chrono::Utc;
-use nipaw_core::types::issue::{IssueInfo, LabelInfo, StateType, UserInfo};
+use chrono::{DateTime, Utc};
+use nipaw_core::types::issue::{
+	IssueCommentInfo, IssueEvent, IssueEventInfo, IssueInfo, LabelInfo, StateType, UserInfo,
+};
+use serde_json::Value;
 
 impl From<JsonValue> for IssueInfo {
 	fn from(issue: JsonValue) -> Self {
@@ -38,6 +41,20 @@ impl From<JsonValue> for IssueInfo {
 				.get("closed_at")
 				.and_then(|v| v.as_str())
 				.and_then(|s| s.parse::<chrono::DateTime<Utc>>().ok()),
+			assignees: issue_info
+				.get("assignees")
+				.and_then(|v| v.as_array())
+				.map(|arr| arr.iter().filter_map(|v| parse_user(Some(v))).collect())
+				.unwrap_or_default(),
+			comments: issue_info.get("comments").and_then(|v| v.as_u64()).unwrap_or_default(),
+			milestone: issue_info
+				.get("milestone")
+				.and_then(|v| v.get("title"))
+				.and_then(|v| v.as_str())
+				.map(|s| s.to_string()),
+			html_url: issue_info.get("html_url").and_then(|v| v.as_str()).map(|s| s.to_string()),
+			locked: issue_info.get("locked").and_then(|v| v.as_bool()),
+			is_pull_request: issue_info.get("pull_request").is_some(),
 		}
 	}
 }
@@ -76,3 +93,69 @@ impl From<JsonValue> for Vec<LabelInfo> {
 			.unwrap_or_default()
 	}
 }
+
+/// 解析可能缺失的用户信息, 已删除的用户会返回[None]
+fn parse_user(user: Option<&Value>) -> Option<UserInfo> {
+	let user = user?;
+	Some(UserInfo {
+		login: user.get("login")?.as_str()?.to_string(),
+		avatar_url: user.get("avatar_url").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+		email: user
+			.get("email")
+			.and_then(|v| v.as_str())
+			.filter(|s| !s.is_empty())
+			.map(|s| s.to_string()),
+	})
+}
+
+fn parse_time(value: &Value, key: &str) -> Option<DateTime<Utc>> {
+	value.get(key).and_then(|v| v.as_str()).and_then(|s| s.parse().ok())
+}
+
+impl From<JsonValue> for IssueCommentInfo {
+	fn from(comment: JsonValue) -> Self {
+		let comment_info = comment.0;
+		Self {
+			id: comment_info.get("id").and_then(|v| v.as_u64()).unwrap_or_default().to_string(),
+			user: parse_user(comment_info.get("user")),
+			body: comment_info.get("body").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+			created_at: parse_time(&comment_info, "created_at").unwrap_or_default(),
+			updated_at: parse_time(&comment_info, "updated_at"),
+		}
+	}
+}
+
+/// 解析事件, 时间线中没有发生时间的条目如`committed`会返回[None]
+pub(crate) fn parse_event(event: &Value) -> Option<IssueEventInfo> {
+	let name = event.get("event")?.as_str()?;
+	let get_str = |object: &str, key: &str| {
+		event
+			.get(object)
+			.and_then(|v| v.get(key))
+			.and_then(|v| v.as_str())
+			.unwrap_or_default()
+			.to_string()
+	};
+	let commit_id = event.get("commit_id").and_then(|v| v.as_str()).map(|s| s.to_string());
+	let issue_event = match name {
+		"labeled" => IssueEvent::Labeled { label: get_str("label", "name") },
+		"unlabeled" => IssueEvent::Unlabeled { label: get_str("label", "name") },
+		"assigned" => IssueEvent::Assigned { assignee: get_str("assignee", "login") },
+		"unassigned" => IssueEvent::Unassigned { assignee: get_str("assignee", "login") },
+		"closed" => IssueEvent::Closed { commit_id },
+		"reopened" => IssueEvent::Reopened,
+		"renamed" => {
+			IssueEvent::Renamed { from: get_str("rename", "from"), to: get_str("rename", "to") }
+		}
+		"referenced" | "cross-referenced" => IssueEvent::Referenced { commit_id },
+		"milestoned" => IssueEvent::Milestoned { milestone: get_str("milestone", "title") },
+		"demilestoned" => IssueEvent::Demilestoned { milestone: get_str("milestone", "title") },
+		other => IssueEvent::Other(other.to_string()),
+	};
+	Some(IssueEventInfo {
+		id: event.get("id").and_then(|v| v.as_u64()).map(|id| id.to_string()),
+		actor: parse_user(event.get("actor")),
+		event: issue_event,
+		created_at: parse_time(event, "created_at")?,
+	})
+}
@@ -0,0 +1,40 @@
+use crate::common::JsonValue;
+use nipaw_core::types::notification::{NotificationInfo, SubjectType};
+use nipaw_core::types::repo::RepoPath;
+
+impl From<JsonValue> for NotificationInfo {
+	fn from(value: JsonValue) -> Self {
+		let notification = value.0;
+		let subject = notification.get("subject");
+		let repository = notification.get("repository");
+		Self {
+			id: notification.get("id").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+			subject_type: subject
+				.and_then(|v| v.get("type"))
+				.and_then(|v| v.as_str())
+				.map(SubjectType::parse)
+				.unwrap_or(SubjectType::Other),
+			title: subject
+				.and_then(|v| v.get("title"))
+				.and_then(|v| v.as_str())
+				.unwrap_or_default()
+				.to_string(),
+			repo_path: RepoPath::new(
+				repository
+					.and_then(|v| v.get("owner"))
+					.and_then(|v| v.get("login"))
+					.and_then(|v| v.as_str())
+					.unwrap_or_default(),
+				repository.and_then(|v| v.get("name")).and_then(|v| v.as_str()).unwrap_or_default(),
+			),
+			reason: notification.get("reason").and_then(|v| v.as_str()).map(|s| s.to_string()),
+			unread: notification.get("unread").and_then(|v| v.as_bool()).unwrap_or(false),
+			updated_at: notification
+				.get("updated_at")
+				.and_then(|v| v.as_str())
+				.and_then(|s| s.parse().ok())
+				.unwrap_or_default(),
+			html_url: notification.get("html_url").and_then(|v| v.as_str()).map(|s| s.to_string()),
+		}
+	}
+}
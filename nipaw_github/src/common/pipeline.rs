@@ -0,0 +1,64 @@
+use crate::common::JsonValue;
+use chrono::{DateTime, Utc};
+use nipaw_core::types::pipeline::{PipelineJob, PipelineRun, PipelineStatus, PipelineStep};
+use serde_json::Value;
+
+fn parse_time(value: &Value, key: &str) -> Option<DateTime<Utc>> {
+	value.get(key).and_then(|v| v.as_str()).and_then(|s| s.parse().ok())
+}
+
+fn parse_status(value: &Value) -> PipelineStatus {
+	let status = value.get("status").and_then(|v| v.as_str()).unwrap_or_default();
+	PipelineStatus::parse(status, value.get("conclusion").and_then(|v| v.as_str()))
+}
+
+impl From<JsonValue> for PipelineRun {
+	fn from(value: JsonValue) -> Self {
+		let run = value.0;
+		let get_str = |key: &str| run.get(key).and_then(|v| v.as_str()).map(|s| s.to_string());
+		Self {
+			id: run.get("id").and_then(|v| v.as_u64()).unwrap_or_default().to_string(),
+			name: get_str("name"),
+			number: run.get("run_number").and_then(|v| v.as_u64()),
+			branch: get_str("head_branch"),
+			sha: get_str("head_sha"),
+			event: get_str("event"),
+			status: parse_status(&run),
+			html_url: get_str("html_url"),
+			created_at: parse_time(&run, "created_at"),
+			updated_at: parse_time(&run, "updated_at"),
+			jobs: Vec::new(),
+		}
+	}
+}
+
+impl From<JsonValue> for PipelineJob {
+	fn from(value: JsonValue) -> Self {
+		let job = value.0;
+		Self {
+			id: job.get("id").and_then(|v| v.as_u64()).unwrap_or_default().to_string(),
+			name: job.get("name").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+			status: parse_status(&job),
+			started_at: parse_time(&job, "started_at"),
+			completed_at: parse_time(&job, "completed_at"),
+			steps: job
+				.get("steps")
+				.and_then(|v| v.as_array())
+				.map(|steps| steps.iter().cloned().map(|v| JsonValue(v).into()).collect())
+				.unwrap_or_default(),
+		}
+	}
+}
+
+impl From<JsonValue> for PipelineStep {
+	fn from(value: JsonValue) -> Self {
+		let step = value.0;
+		Self {
+			name: step.get("name").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+			number: step.get("number").and_then(|v| v.as_u64()).unwrap_or_default(),
+			status: parse_status(&step),
+			started_at: parse_time(&step, "started_at"),
+			completed_at: parse_time(&step, "completed_at"),
+		}
+	}
+}
@@ -0,0 +1,48 @@
+use crate::common::JsonValue;
+use nipaw_core::types::search::{CodeInfo, SearchResult};
+
+impl<T: From<JsonValue>> From<JsonValue> for SearchResult<T> {
+	fn from(value: JsonValue) -> Self {
+		let search_result = value.0;
+		let items: Vec<T> = search_result
+			.get("items")
+			.and_then(|v| v.as_array())
+			.map(|arr| arr.iter().map(|v| JsonValue(v.clone()).into()).collect())
+			.unwrap_or_default();
+		Self {
+			total_count: search_result
+				.get("total_count")
+				.and_then(|v| v.as_u64())
+				.unwrap_or(items.len() as u64),
+			incomplete: search_result
+				.get("incomplete_results")
+				.and_then(|v| v.as_bool())
+				.unwrap_or(false),
+			items,
+		}
+	}
+}
+
+impl From<JsonValue> for CodeInfo {
+	fn from(value: JsonValue) -> Self {
+		let code_info = value.0;
+		let repository = code_info.get("repository");
+		Self {
+			name: code_info.get("name").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+			path: code_info.get("path").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+			sha: code_info.get("sha").and_then(|v| v.as_str()).map(|s| s.to_string()),
+			owner: repository
+				.and_then(|v| v.get("owner"))
+				.and_then(|v| v.get("login"))
+				.and_then(|v| v.as_str())
+				.unwrap_or_default()
+				.to_string(),
+			repo: repository
+				.and_then(|v| v.get("name"))
+				.and_then(|v| v.as_str())
+				.unwrap_or_default()
+				.to_string(),
+			html_url: code_info.get("html_url").and_then(|v| v.as_str()).map(|s| s.to_string()),
+		}
+	}
+}
@@ -0,0 +1,39 @@
+use crate::common::JsonValue;
+use nipaw_core::types::secret::{SecretInfo, VariableInfo};
+
+impl From<JsonValue> for SecretInfo {
+	fn from(value: JsonValue) -> Self {
+		let secret = value.0;
+		Self {
+			name: secret.get("name").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+			visibility: secret.get("visibility").and_then(|v| v.as_str()).map(|s| s.to_string()),
+			created_at: secret
+				.get("created_at")
+				.and_then(|v| v.as_str())
+				.and_then(|s| s.parse().ok()),
+			updated_at: secret
+				.get("updated_at")
+				.and_then(|v| v.as_str())
+				.and_then(|s| s.parse().ok()),
+		}
+	}
+}
+
+impl From<JsonValue> for VariableInfo {
+	fn from(value: JsonValue) -> Self {
+		let variable = value.0;
+		Self {
+			name: variable.get("name").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+			value: variable.get("value").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+			visibility: variable.get("visibility").and_then(|v| v.as_str()).map(|s| s.to_string()),
+			created_at: variable
+				.get("created_at")
+				.and_then(|v| v.as_str())
+				.and_then(|s| s.parse().ok()),
+			updated_at: variable
+				.get("updated_at")
+				.and_then(|v| v.as_str())
+				.and_then(|s| s.parse().ok()),
+		}
+	}
+}
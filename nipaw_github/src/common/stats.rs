@@ -0,0 +1,48 @@
+use crate::common::JsonValue;
+use chrono::{DateTime, Utc};
+use nipaw_core::types::stats::{CodeFrequency, PunchCard, WeeklyCommitActivity};
+use serde_json::Value;
+
+/// 将Unix时间戳转换为UTC时间
+fn timestamp(value: Option<&Value>) -> DateTime<Utc> {
+	value.and_then(|v| v.as_i64()).and_then(|t| DateTime::from_timestamp(t, 0)).unwrap_or_default()
+}
+
+impl From<JsonValue> for WeeklyCommitActivity {
+	fn from(value: JsonValue) -> Self {
+		let activity = value.0;
+		Self {
+			week: timestamp(activity.get("week")),
+			total: activity.get("total").and_then(|v| v.as_u64()).unwrap_or(0),
+			days: activity
+				.get("days")
+				.and_then(|v| v.as_array())
+				.map(|days| days.iter().map(|v| v.as_u64().unwrap_or(0)).collect())
+				.unwrap_or_default(),
+		}
+	}
+}
+
+impl From<JsonValue> for CodeFrequency {
+	fn from(value: JsonValue) -> Self {
+		// 格式为`[周起始时间戳, 新增行数, 删除行数]`, 删除行数为负数
+		let item = value.0;
+		Self {
+			week: timestamp(item.get(0)),
+			additions: item.get(1).and_then(|v| v.as_i64()).unwrap_or(0).unsigned_abs(),
+			deletions: item.get(2).and_then(|v| v.as_i64()).unwrap_or(0).unsigned_abs(),
+		}
+	}
+}
+
+impl From<JsonValue> for PunchCard {
+	fn from(value: JsonValue) -> Self {
+		// 格式为`[星期, 小时, 提交数]`
+		let item = value.0;
+		Self {
+			weekday: item.get(0).and_then(|v| v.as_u64()).unwrap_or(0) as u8,
+			hour: item.get(1).and_then(|v| v.as_u64()).unwrap_or(0) as u8,
+			commits: item.get(2).and_then(|v| v.as_u64()).unwrap_or(0),
+		}
+	}
+}
@@ -0,0 +1,73 @@
+use crate::common::JsonValue;
+use nipaw_core::types::status::{CombinedStatusInfo, StatusInfo, StatusState};
+
+impl From<JsonValue> for StatusInfo {
+	fn from(value: JsonValue) -> Self {
+		let status_info = value.0;
+		let state_value = status_info.get("state").cloned().unwrap_or_default();
+		Self {
+			id: status_info.get("id").and_then(|v| v.as_u64()),
+			state: JsonValue(state_value).into(),
+			context: status_info
+				.get("context")
+				.and_then(|v| v.as_str())
+				.unwrap_or("default")
+				.to_string(),
+			description: status_info
+				.get("description")
+				.and_then(|v| v.as_str())
+				.filter(|s| !s.is_empty())
+				.map(|s| s.to_string()),
+			target_url: status_info
+				.get("target_url")
+				.and_then(|v| v.as_str())
+				.filter(|s| !s.is_empty())
+				.map(|s| s.to_string()),
+			creator: status_info
+				.get("creator")
+				.and_then(|v| v.get("login"))
+				.and_then(|v| v.as_str())
+				.map(|s| s.to_string()),
+			created_at: status_info
+				.get("created_at")
+				.and_then(|v| v.as_str())
+				.and_then(|s| s.parse().ok()),
+			updated_at: status_info
+				.get("updated_at")
+				.and_then(|v| v.as_str())
+				.and_then(|s| s.parse().ok()),
+		}
+	}
+}
+
+impl From<JsonValue> for CombinedStatusInfo {
+	fn from(value: JsonValue) -> Self {
+		let combined_info = value.0;
+		let state_value = combined_info.get("state").cloned().unwrap_or_default();
+		let statuses: Vec<StatusInfo> = combined_info
+			.get("statuses")
+			.and_then(|v| v.as_array())
+			.map(|arr| arr.iter().map(|v| JsonValue(v.clone()).into()).collect())
+			.unwrap_or_default();
+		Self {
+			state: JsonValue(state_value).into(),
+			sha: combined_info.get("sha").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+			total_count: combined_info
+				.get("total_count")
+				.and_then(|v| v.as_u64())
+				.unwrap_or(statuses.len() as u64),
+			statuses,
+		}
+	}
+}
+
+impl From<JsonValue> for StatusState {
+	fn from(value: JsonValue) -> Self {
+		match value.0.as_str().map(|s| s.to_lowercase()).as_deref() {
+			Some("success") => Self::Success,
+			Some("failure") => Self::Failure,
+			Some("error") => Self::Error,
+			_ => Self::Pending,
+		}
+	}
+}
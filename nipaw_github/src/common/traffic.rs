@@ -0,0 +1,54 @@
+use crate::common::JsonValue;
+use nipaw_core::types::traffic::{TrafficPath, TrafficPoint, TrafficReferrer, TrafficSeries};
+
+/// 解析访问量或克隆量, `key`为明细所在的字段名
+pub(crate) fn parse_series(value: JsonValue, key: &str) -> TrafficSeries {
+	let series = value.0;
+	TrafficSeries {
+		count: series.get("count").and_then(|v| v.as_u64()).unwrap_or(0),
+		uniques: series.get("uniques").and_then(|v| v.as_u64()).unwrap_or(0),
+		items: series
+			.get(key)
+			.and_then(|v| v.as_array())
+			.map(|items| {
+				items
+					.iter()
+					.filter_map(|item| {
+						Some(TrafficPoint {
+							timestamp: item.get("timestamp")?.as_str()?.parse().ok()?,
+							count: item.get("count").and_then(|v| v.as_u64()).unwrap_or(0),
+							uniques: item.get("uniques").and_then(|v| v.as_u64()).unwrap_or(0),
+						})
+					})
+					.collect()
+			})
+			.unwrap_or_default(),
+	}
+}
+
+impl From<JsonValue> for TrafficReferrer {
+	fn from(value: JsonValue) -> Self {
+		let referrer = value.0;
+		Self {
+			referrer: referrer
+				.get("referrer")
+				.and_then(|v| v.as_str())
+				.unwrap_or_default()
+				.to_string(),
+			count: referrer.get("count").and_then(|v| v.as_u64()).unwrap_or(0),
+			uniques: referrer.get("uniques").and_then(|v| v.as_u64()).unwrap_or(0),
+		}
+	}
+}
+
+impl From<JsonValue> for TrafficPath {
+	fn from(value: JsonValue) -> Self {
+		let path = value.0;
+		Self {
+			path: path.get("path").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+			title: path.get("title").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+			count: path.get("count").and_then(|v| v.as_u64()).unwrap_or(0),
+			uniques: path.get("uniques").and_then(|v| v.as_u64()).unwrap_or(0),
+		}
+	}
+}
@@ -0,0 +1,158 @@
+use crate::GitHubClientInner;
+use crate::common::JsonValue;
+use async_trait::async_trait;
+use http::header;
+use nipaw_core::option::gist::{CreateOptions, UpdateOptions};
+use nipaw_core::option::repo::ListOptions;
+use nipaw_core::types::gist::GistInfo;
+use nipaw_core::{Error, Gist, Result};
+use reqwest::Method;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+pub struct GitHubGist(pub(crate) Arc<GitHubClientInner>);
+
+impl GitHubGist {
+	/// 发送不关心响应内容的请求, 需要登录
+	async fn send_empty(&self, method: Method, url: String) -> Result<()> {
+		let token = &self.0.config.token;
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let client = self.0.client.read().await;
+		client
+			.request(method, url)
+			.bearer_auth(token.as_ref().unwrap())
+			.header(header::CONTENT_LENGTH, 0)
+			.send()
+			.await?
+			.error_for_status()?;
+		Ok(())
+	}
+
+	/// 发送JSON请求并返回响应内容, 需要登录
+	async fn send_json(&self, method: Method, url: String, body: Value) -> Result<JsonValue> {
+		let token = &self.0.config.token;
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let client = self.0.client.read().await;
+		let res = client
+			.request(method, url)
+			.bearer_auth(token.as_ref().unwrap())
+			.json(&body)
+			.send()
+			.await?
+			.error_for_status()?
+			.json::<JsonValue>()
+			.await?;
+		Ok(res)
+	}
+}
+
+#[async_trait]
+impl Gist for GitHubGist {
+	async fn list(
+		&self,
+		user_name: Option<&str>,
+		option: Option<ListOptions>,
+	) -> Result<Vec<GistInfo>> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		let url = match user_name {
+			Some(user_name) => format!("{}/users/{}/gists", api_url, user_name),
+			None if token.is_none() => return Err(Error::TokenEmpty),
+			None => format!("{}/gists", api_url),
+		};
+		let client = self.0.client.read().await;
+		let mut request = client.get(url);
+		if let Some(token) = token {
+			request = request.bearer_auth(token);
+		}
+		let mut params: HashMap<&str, String> = HashMap::new();
+		if let Some(option) = option {
+			let per_page = option.per_page.unwrap_or(30).min(100);
+			params.insert("per_page", per_page.to_string());
+			let page = option.page.unwrap_or(1);
+			params.insert("page", page.to_string());
+		}
+		let gists = request.query(&params).send().await?.json::<Vec<JsonValue>>().await?;
+		Ok(gists.into_iter().map(|v| v.into()).collect())
+	}
+
+	async fn info(&self, id: &str) -> Result<GistInfo> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		let url = format!("{}/gists/{}", api_url, id);
+		let client = self.0.client.read().await;
+		let mut request = client.get(url);
+		if let Some(token) = token {
+			request = request.bearer_auth(token);
+		}
+		let gist_info = request.send().await?.error_for_status()?.json::<JsonValue>().await?;
+		Ok(gist_info.into())
+	}
+
+	async fn create(&self, option: CreateOptions) -> Result<GistInfo> {
+		if option.files.is_empty() {
+			return Err(Error::InvalidParam {
+				param: "files".to_string(),
+				reason: "at least one file is required".to_string(),
+			});
+		}
+		let url = format!("{}/gists", self.0.config.api_url);
+		let files: Map<String, Value> = option
+			.files
+			.into_iter()
+			.map(|(name, content)| (name, serde_json::json!({ "content": content })))
+			.collect();
+		let mut body = serde_json::json!({
+			"public": option.public.unwrap_or(false),
+			"files": files,
+		});
+		if let Some(description) = option.description {
+			body["description"] = description.into();
+		}
+		let gist_info = self.send_json(Method::POST, url, body).await?;
+		Ok(gist_info.into())
+	}
+
+	async fn update(&self, id: &str, option: UpdateOptions) -> Result<GistInfo> {
+		let url = format!("{}/gists/{}", self.0.config.api_url, id);
+		// 文件内容为null时删除该文件
+		let files: Map<String, Value> = option
+			.files
+			.into_iter()
+			.map(|(name, content)| {
+				let file = content.map(|content| serde_json::json!({ "content": content }));
+				(name, file.unwrap_or(Value::Null))
+			})
+			.collect();
+		let mut body = serde_json::json!({ "files": files });
+		if let Some(description) = option.description {
+			body["description"] = description.into();
+		}
+		let gist_info = self.send_json(Method::PATCH, url, body).await?;
+		Ok(gist_info.into())
+	}
+
+	async fn delete(&self, id: &str) -> Result<()> {
+		let url = format!("{}/gists/{}", self.0.config.api_url, id);
+		self.send_empty(Method::DELETE, url).await
+	}
+
+	async fn star(&self, id: &str) -> Result<()> {
+		let url = format!("{}/gists/{}/star", self.0.config.api_url, id);
+		self.send_empty(Method::PUT, url).await
+	}
+
+	async fn unstar(&self, id: &str) -> Result<()> {
+		let url = format!("{}/gists/{}/star", self.0.config.api_url, id);
+		self.send_empty(Method::DELETE, url).await
+	}
+
+	async fn fork(&self, id: &str) -> Result<GistInfo> {
+		let url = format!("{}/gists/{}/forks", self.0.config.api_url, id);
+		let gist_info = self.send_json(Method::POST, url, serde_json::json!({})).await?;
+		Ok(gist_info.into())
+	}
+}
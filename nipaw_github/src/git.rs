@@ -0,0 +1,241 @@
+use crate::GitHubClientInner;
+use crate::common::JsonValue;
+use async_trait::async_trait;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use nipaw_core::option::git::{FileChange, FileContent, TreeEntryOptions};
+use nipaw_core::types::git::{GitCommit, GitRef, GitTree, GitTreeEntry};
+use nipaw_core::types::repo::RepoPath;
+use nipaw_core::{Error, Git, Result};
+use reqwest::Method;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+pub struct GitHubGit(pub(crate) Arc<GitHubClientInner>);
+
+impl GitHubGit {
+	fn git_url(&self, repo_path: &RepoPath, path: &str) -> String {
+		format!(
+			"{}/repos/{}/{}/git/{}",
+			self.0.config.api_url, repo_path.owner, repo_path.repo, path
+		)
+	}
+
+	/// 发送请求并解析响应, 读取操作在未登录时也可以访问公开仓库
+	async fn send_json(
+		&self,
+		method: Method,
+		url: String,
+		body: Option<Value>,
+	) -> Result<JsonValue> {
+		let token = &self.0.config.token;
+		if token.is_none() && method != Method::GET {
+			return Err(Error::TokenEmpty);
+		}
+		let client = self.0.client.read().await;
+		let mut request = client.request(method, url);
+		if let Some(token) = token {
+			request = request.bearer_auth(token);
+		}
+		if let Some(body) = body {
+			request = request.json(&body);
+		}
+		Ok(request.send().await?.error_for_status()?.json::<JsonValue>().await?)
+	}
+
+	/// 读取树中已有路径的文件模式, 递归结果被截断时逐级读取修改路径所在的目录
+	async fn existing_modes(
+		&self,
+		repo_path: &RepoPath,
+		tree: &str,
+		paths: &[&str],
+	) -> Result<HashMap<String, String>> {
+		let root = self.tree(repo_path.clone(), tree, true).await?;
+		if !root.truncated {
+			return Ok(root.entries.into_iter().map(|e| (e.path, e.mode)).collect());
+		}
+		let mut trees: HashMap<String, GitTree> = HashMap::new();
+		let mut modes = HashMap::new();
+		'paths: for path in paths {
+			let (dirs, name) = path.rsplit_once('/').unwrap_or(("", path));
+			let mut sha = tree.to_string();
+			for dir in dirs.split('/').filter(|p| !p.is_empty()) {
+				let Some(entry) = self.find_entry(repo_path, &mut trees, &sha, dir).await? else {
+					continue 'paths;
+				};
+				if entry.entry_type != "tree" {
+					continue 'paths;
+				}
+				sha = entry.sha;
+			}
+			if let Some(entry) = self.find_entry(repo_path, &mut trees, &sha, name).await? {
+				modes.insert(path.to_string(), entry.mode);
+			}
+		}
+		Ok(modes)
+	}
+
+	/// 在非递归读取的树中查找条目, 读取过的树会被缓存
+	async fn find_entry(
+		&self,
+		repo_path: &RepoPath,
+		trees: &mut HashMap<String, GitTree>,
+		sha: &str,
+		name: &str,
+	) -> Result<Option<GitTreeEntry>> {
+		if !trees.contains_key(sha) {
+			let tree = self.tree(repo_path.clone(), sha, false).await?;
+			trees.insert(sha.to_string(), tree);
+		}
+		Ok(trees[sha].entries.iter().find(|e| e.path == name).cloned())
+	}
+}
+
+/// 去掉引用名的`refs/`前缀
+fn short_ref(reference: &str) -> &str {
+	reference.strip_prefix("refs/").unwrap_or(reference)
+}
+
+fn tree_entry(entry: TreeEntryOptions) -> Value {
+	let mut value = serde_json::json!({
+		"path": entry.path,
+		"mode": entry.mode.unwrap_or("100644".to_string()),
+		"type": "blob",
+	});
+	match (entry.content, entry.sha) {
+		(Some(content), _) => value["content"] = content.into(),
+		(None, sha) => value["sha"] = sha.into(),
+	}
+	value
+}
+
+#[async_trait]
+impl Git for GitHubGit {
+	async fn get_ref(&self, repo_path: RepoPath, reference: &str) -> Result<GitRef> {
+		let url = self.git_url(&repo_path, &format!("ref/{}", short_ref(reference)));
+		self.send_json(Method::GET, url, None).await?.try_into()
+	}
+
+	async fn create_ref(&self, repo_path: RepoPath, reference: &str, sha: &str) -> Result<GitRef> {
+		let url = self.git_url(&repo_path, "refs");
+		let body = serde_json::json!({
+			"ref": format!("refs/{}", short_ref(reference)),
+			"sha": sha,
+		});
+		self.send_json(Method::POST, url, Some(body)).await?.try_into()
+	}
+
+	async fn update_ref(
+		&self,
+		repo_path: RepoPath,
+		reference: &str,
+		sha: &str,
+		force: bool,
+	) -> Result<GitRef> {
+		let url = self.git_url(&repo_path, &format!("refs/{}", short_ref(reference)));
+		let body = serde_json::json!({ "sha": sha, "force": force });
+		self.send_json(Method::PATCH, url, Some(body)).await?.try_into()
+	}
+
+	async fn delete_ref(&self, repo_path: RepoPath, reference: &str) -> Result<()> {
+		let token = &self.0.config.token;
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let url = self.git_url(&repo_path, &format!("refs/{}", short_ref(reference)));
+		let client = self.0.client.read().await;
+		let request = client.delete(url).bearer_auth(token.as_ref().unwrap());
+		request.send().await?.error_for_status()?;
+		Ok(())
+	}
+
+	async fn tree(&self, repo_path: RepoPath, sha: &str, recursive: bool) -> Result<GitTree> {
+		let mut url = self.git_url(&repo_path, &format!("trees/{}", sha));
+		if recursive {
+			url.push_str("?recursive=1");
+		}
+		self.send_json(Method::GET, url, None).await?.try_into()
+	}
+
+	async fn create_blob(&self, repo_path: RepoPath, content: &[u8]) -> Result<String> {
+		let url = self.git_url(&repo_path, "blobs");
+		let body = serde_json::json!({ "content": STANDARD.encode(content), "encoding": "base64" });
+		let res = self.send_json(Method::POST, url, Some(body)).await?;
+		res.0
+			.get("sha")
+			.and_then(|v| v.as_str())
+			.map(|sha| sha.to_string())
+			.ok_or(Error::NotFound { resource: "blob sha".to_string() })
+	}
+
+	async fn create_tree(
+		&self,
+		repo_path: RepoPath,
+		base_tree: Option<&str>,
+		entries: Vec<TreeEntryOptions>,
+	) -> Result<GitTree> {
+		let url = self.git_url(&repo_path, "trees");
+		let mut body = serde_json::json!({
+			"tree": entries.into_iter().map(tree_entry).collect::<Vec<_>>(),
+		});
+		if let Some(base_tree) = base_tree {
+			body["base_tree"] = base_tree.into();
+		}
+		self.send_json(Method::POST, url, Some(body)).await?.try_into()
+	}
+
+	async fn get_commit(&self, repo_path: RepoPath, sha: &str) -> Result<GitCommit> {
+		let url = self.git_url(&repo_path, &format!("commits/{}", sha));
+		self.send_json(Method::GET, url, None).await?.try_into()
+	}
+
+	async fn create_commit(
+		&self,
+		repo_path: RepoPath,
+		message: &str,
+		tree: &str,
+		parents: Vec<String>,
+	) -> Result<GitCommit> {
+		let url = self.git_url(&repo_path, "commits");
+		let body = serde_json::json!({ "message": message, "tree": tree, "parents": parents });
+		self.send_json(Method::POST, url, Some(body)).await?.try_into()
+	}
+
+	async fn commit_files(
+		&self,
+		repo_path: RepoPath,
+		branch: &str,
+		message: &str,
+		changes: Vec<FileChange>,
+	) -> Result<GitCommit> {
+		let reference = format!("heads/{}", branch);
+		let head = self.get_ref(repo_path.clone(), &reference).await?;
+		let parent = self.get_commit(repo_path.clone(), &head.sha).await?;
+		// 沿用已有文件的模式, 避免更新可执行文件或符号链接时被重置为普通文件
+		let paths = changes.iter().map(|c| c.path.as_str()).collect::<Vec<_>>();
+		let modes = self.existing_modes(&repo_path, &parent.tree, &paths).await?;
+		let mut entries = Vec::with_capacity(changes.len());
+		for change in changes {
+			let mut entry = TreeEntryOptions {
+				mode: modes.get(&change.path).cloned(),
+				path: change.path,
+				..Default::default()
+			};
+			match change.content {
+				Some(FileContent::Text(text)) => entry.content = Some(text),
+				Some(FileContent::Binary(bytes)) => {
+					entry.sha = Some(self.create_blob(repo_path.clone(), &bytes).await?);
+				}
+				None => {}
+			}
+			entries.push(entry);
+		}
+		let tree = self.create_tree(repo_path.clone(), Some(&parent.tree), entries).await?;
+		let commit =
+			self.create_commit(repo_path.clone(), message, &tree.sha, vec![parent.sha]).await?;
+		// 不强制更新, 分支在此期间被其他提交移动时返回错误而不是覆盖
+		self.update_ref(repo_path, &reference, &commit.sha, false).await?;
+		Ok(commit)
+	}
+}
@@ -1,8 +1,8 @@
 use crate::GitHubClientInner;
 use async_trait::async_trait;
-use crate::common::JsonValue;
-use nipaw_core::option::issue::{CreateOptions, ListOptions, UpdateOptions};
-use nipaw_core::types::issue::{IssueInfo, StateType};
+use crate::common::{JsonValue, parse_event};
+use nipaw_core::option::issue::{CreateOptions, EventListOptions, ListOptions, UpdateOptions};
+use nipaw_core::types::issue::{IssueEventInfo, IssueInfo, StateType, TimelineItem};
 use nipaw_core::types::repo::RepoPath;
 use nipaw_core::{Error, Issue, Result};
 use std::collections::HashMap;
@@ -10,6 +10,32 @@ use std::sync::Arc;
 
 pub struct GitHubIssue(pub(crate) Arc<GitHubClientInner>);
 
+impl GitHubIssue {
+	/// 获取议题下的分页列表, `kind`为`events`或`timeline`
+	async fn get_list(
+		&self,
+		repo_path: &RepoPath,
+		issue_number: &str,
+		kind: &str,
+		options: Option<EventListOptions>,
+	) -> Result<Vec<JsonValue>> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		let url = format!(
+			"{}/repos/{}/{}/issues/{}/{}",
+			api_url, repo_path.owner, repo_path.repo, issue_number, kind
+		);
+		let options = options.unwrap_or_default();
+		let per_page = options.per_page.unwrap_or(30).min(100);
+		let page = options.page.unwrap_or(1);
+		let client = self.0.client.read().await;
+		let mut request = client.get(url).query(&[("per_page", per_page), ("page", page)]);
+		if let Some(token) = token {
+			request = request.bearer_auth(token);
+		};
+		Ok(request.send().await?.json::<Vec<JsonValue>>().await?)
+	}
+}
+
 #[async_trait]
 impl Issue for GitHubIssue {
 	async fn create(
@@ -128,4 +154,114 @@ impl Issue for GitHubIssue {
 		let res = request.json(&req_body).send().await?.json::<JsonValue>().await?;
 		Ok(res.into())
 	}
+
+	async fn events(
+		&self,
+		repo_path: RepoPath,
+		issue_number: &str,
+		options: Option<EventListOptions>,
+	) -> Result<Vec<IssueEventInfo>> {
+		let res = self.get_list(&repo_path, issue_number, "events", options).await?;
+		Ok(res.iter().filter_map(|v| parse_event(&v.0)).collect())
+	}
+
+	async fn timeline(
+		&self,
+		repo_path: RepoPath,
+		issue_number: &str,
+		options: Option<EventListOptions>,
+	) -> Result<Vec<TimelineItem>> {
+		let res = self.get_list(&repo_path, issue_number, "timeline", options).await?;
+		Ok(res
+			.into_iter()
+			.filter_map(|v| match v.0.get("event").and_then(|e| e.as_str()) {
+				Some("commented") => Some(TimelineItem::Comment(v.into())),
+				_ => parse_event(&v.0).map(TimelineItem::Event),
+			})
+			.collect())
+	}
+
+	async fn add_assignees(
+		&self,
+		repo_path: RepoPath,
+		issue_number: &str,
+		assignees: Vec<String>,
+	) -> Result<IssueInfo> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let url = format!(
+			"{}/repos/{}/{}/issues/{}/assignees",
+			api_url, repo_path.owner, repo_path.repo, issue_number
+		);
+		let client = self.0.client.read().await;
+		let request = client.post(url).bearer_auth(token.as_ref().unwrap());
+		let res = request
+			.json(&serde_json::json!({ "assignees": assignees }))
+			.send()
+			.await?
+			.error_for_status()?
+			.json::<JsonValue>()
+			.await?;
+		Ok(res.into())
+	}
+
+	async fn remove_assignees(
+		&self,
+		repo_path: RepoPath,
+		issue_number: &str,
+		assignees: Vec<String>,
+	) -> Result<IssueInfo> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let url = format!(
+			"{}/repos/{}/{}/issues/{}/assignees",
+			api_url, repo_path.owner, repo_path.repo, issue_number
+		);
+		let client = self.0.client.read().await;
+		let request = client.delete(url).bearer_auth(token.as_ref().unwrap());
+		let res = request
+			.json(&serde_json::json!({ "assignees": assignees }))
+			.send()
+			.await?
+			.error_for_status()?
+			.json::<JsonValue>()
+			.await?;
+		Ok(res.into())
+	}
+
+	async fn lock(&self, repo_path: RepoPath, issue_number: &str) -> Result<()> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let url = format!(
+			"{}/repos/{}/{}/issues/{}/lock",
+			api_url, repo_path.owner, repo_path.repo, issue_number
+		);
+		let client = self.0.client.read().await;
+		// 不带请求体时接口会因缺少长度返回411, 这里发送空对象
+		let request =
+			client.put(url).bearer_auth(token.as_ref().unwrap()).json(&serde_json::json!({}));
+		request.send().await?.error_for_status()?;
+		Ok(())
+	}
+
+	async fn unlock(&self, repo_path: RepoPath, issue_number: &str) -> Result<()> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let url = format!(
+			"{}/repos/{}/{}/issues/{}/lock",
+			api_url, repo_path.owner, repo_path.repo, issue_number
+		);
+		let client = self.0.client.read().await;
+		let request = client.delete(url).bearer_auth(token.as_ref().unwrap());
+		request.send().await?.error_for_status()?;
+		Ok(())
+	}
 }
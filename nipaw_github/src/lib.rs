@@ -1,22 +1,45 @@
 mod commit;
 mod common;
+mod gist;
+mod git;
 mod issue;
+mod key;
 mod middleware;
+mod notification;
 mod org;
+mod pipeline;
 mod release;
 mod repo;
+mod search;
+mod secret;
+mod stats;
+mod status;
+mod traffic;
 mod user;
 
 use crate::{
 	commit::GitHubCommit,
+	gist::GitHubGist,
+	git::GitHubGit,
 	issue::GitHubIssue,
+	key::GitHubKey,
 	middleware::{HeaderMiddleware, ResponseMiddleware},
+	notification::GitHubNotifications,
 	org::GitHubOrg,
+	pipeline::GitHubPipeline,
 	release::GitHubRelease,
 	repo::GitHubRepo,
+	search::GitHubSearch,
+	secret::GitHubSecret,
+	stats::GitHubStats,
+	status::GitHubStatus,
+	traffic::GitHubTraffic,
 	user::GitHubUser,
 };
-pub use nipaw_core::{Client, Commit, Config, Error, Issue, Org, Provider, Release, Repo, Result, Token, User};
+pub use nipaw_core::{
+	Client, Commit, Config, Error, Gist, Git, Issue, Key, Notifications, Org, Pipeline, Provider,
+	Release, Repo, Result, Search, Secret, Stats, Status, Token, Traffic, User,
+};
 use reqwest_middleware::{ClientBuilder, ClientWithMiddleware};
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -124,6 +147,16 @@ impl Provider for GitHubClient {
 	type Commit = GitHubCommit;
 	type Issue = GitHubIssue;
 	type Release = GitHubRelease;
+	type Status = GitHubStatus;
+	type Search = GitHubSearch;
+	type Key = GitHubKey;
+	type Stats = GitHubStats;
+	type Traffic = GitHubTraffic;
+	type Notifications = GitHubNotifications;
+	type Gist = GitHubGist;
+	type Pipeline = GitHubPipeline;
+	type Secret = GitHubSecret;
+	type Git = GitHubGit;
 
 	fn user(&self) -> GitHubUser {
 		GitHubUser(self.inner.clone())
@@ -148,4 +181,44 @@ impl Provider for GitHubClient {
 	fn release(&self) -> GitHubRelease {
 		GitHubRelease(self.inner.clone())
 	}
+
+	fn status(&self) -> GitHubStatus {
+		GitHubStatus(self.inner.clone())
+	}
+
+	fn search(&self) -> GitHubSearch {
+		GitHubSearch(self.inner.clone())
+	}
+
+	fn key(&self) -> GitHubKey {
+		GitHubKey(self.inner.clone())
+	}
+
+	fn stats(&self) -> GitHubStats {
+		GitHubStats(self.inner.clone())
+	}
+
+	fn traffic(&self) -> GitHubTraffic {
+		GitHubTraffic(self.inner.clone())
+	}
+
+	fn notifications(&self) -> GitHubNotifications {
+		GitHubNotifications(self.inner.clone())
+	}
+
+	fn gist(&self) -> GitHubGist {
+		GitHubGist(self.inner.clone())
+	}
+
+	fn pipeline(&self) -> GitHubPipeline {
+		GitHubPipeline(self.inner.clone())
+	}
+
+	fn secret(&self) -> GitHubSecret {
+		GitHubSecret(self.inner.clone())
+	}
+
+	fn git(&self) -> GitHubGit {
+		GitHubGit(self.inner.clone())
+	}
 }
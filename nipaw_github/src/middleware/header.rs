@@ -13,10 +13,9 @@ impl Middleware for HeaderMiddleware {
 		extensions: &mut Extensions,
 		next: Next<'_>,
 	) -> reqwest_middleware::Result<Response> {
-		req.headers_mut().insert(
-			HeaderName::from_static("accept"),
-			HeaderValue::from_static("application/vnd.github+json"),
-		);
+		req.headers_mut()
+			.entry(HeaderName::from_static("accept"))
+			.or_insert(HeaderValue::from_static("application/vnd.github+json"));
 		req.headers_mut().insert(
 			HeaderName::from_static("x-github-api-version"),
 			HeaderValue::from_static("2022-11-28"),
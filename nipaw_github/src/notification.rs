@@ -0,0 +1,117 @@
+use crate::GitHubClientInner;
+use crate::common::JsonValue;
+use async_trait::async_trait;
+use http::header;
+use nipaw_core::option::notification::ListOptions;
+use nipaw_core::types::notification::NotificationInfo;
+use nipaw_core::types::repo::RepoPath;
+use nipaw_core::{Error, Notifications, Result};
+use reqwest::Method;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+pub struct GitHubNotifications(pub(crate) Arc<GitHubClientInner>);
+
+impl GitHubNotifications {
+	/// 发送不关心响应内容的请求, 需要登录
+	async fn send_empty(&self, method: Method, url: String, body: Option<Value>) -> Result<()> {
+		let token = &self.0.config.token;
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let client = self.0.client.read().await;
+		let mut request = client.request(method.clone(), url).bearer_auth(token.as_ref().unwrap());
+		if body.is_none() && method != Method::GET {
+			request = request.header(header::CONTENT_LENGTH, 0);
+		}
+		if let Some(body) = body {
+			request = request.json(&body);
+		}
+		request.send().await?.error_for_status()?;
+		Ok(())
+	}
+
+	/// 将通知对象的接口地址转换为网页地址
+	fn html_url(&self, notification: &Value) -> Option<String> {
+		let (api_url, base_url) = (&self.0.config.api_url, &self.0.config.base_url);
+		let subject_url = notification.get("subject")?.get("url")?.as_str();
+		if let Some(path) = subject_url.and_then(|url| url.strip_prefix(api_url.as_str()))
+			&& let Some(path) = path.strip_prefix("/repos")
+			&& !path.contains("/releases/")
+		{
+			let path = path.replacen("/pulls/", "/pull/", 1).replacen("/commits/", "/commit/", 1);
+			return Some(format!("{}{}", base_url, path));
+		}
+		notification.get("repository")?.get("html_url")?.as_str().map(|url| url.to_string())
+	}
+}
+
+#[async_trait]
+impl Notifications for GitHubNotifications {
+	async fn list(&self, option: Option<ListOptions>) -> Result<Vec<NotificationInfo>> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let option = option.unwrap_or_default();
+		let url = match &option.repo_path {
+			Some(repo_path) => {
+				format!("{}/repos/{}/{}/notifications", api_url, repo_path.owner, repo_path.repo)
+			}
+			None => format!("{}/notifications", api_url),
+		};
+		let mut params: HashMap<&str, String> = HashMap::new();
+		let per_page = option.per_page.unwrap_or(30).min(100);
+		params.insert("per_page", per_page.to_string());
+		let page = option.page.unwrap_or(1);
+		params.insert("page", page.to_string());
+		params.insert("all", (!option.unread.unwrap_or(true)).to_string());
+		if let Some(participating) = option.participating {
+			params.insert("participating", participating.to_string());
+		}
+		if let Some(since) = option.since {
+			params.insert("since", since.to_rfc3339());
+		}
+		let client = self.0.client.read().await;
+		let request = client.get(url).bearer_auth(token.as_ref().unwrap());
+		let list = request.query(&params).send().await?.json::<Vec<JsonValue>>().await?;
+		Ok(list
+			.into_iter()
+			.map(|mut v| {
+				if let Some(html_url) = self.html_url(&v.0) {
+					v.0["html_url"] = html_url.into();
+				}
+				v.into()
+			})
+			.collect())
+	}
+
+	async fn mark_read(&self, thread_id: &str) -> Result<()> {
+		let url = format!("{}/notifications/threads/{}", self.0.config.api_url, thread_id);
+		self.send_empty(Method::PATCH, url, None).await
+	}
+
+	async fn mark_all_read(&self, repo_path: Option<RepoPath>) -> Result<()> {
+		let api_url = &self.0.config.api_url;
+		let url = match repo_path {
+			Some(repo_path) => {
+				format!("{}/repos/{}/{}/notifications", api_url, repo_path.owner, repo_path.repo)
+			}
+			None => format!("{}/notifications", api_url),
+		};
+		self.send_empty(Method::PUT, url, Some(serde_json::json!({ "read": true }))).await
+	}
+
+	async fn subscribe(&self, thread_id: &str) -> Result<()> {
+		let url =
+			format!("{}/notifications/threads/{}/subscription", self.0.config.api_url, thread_id);
+		self.send_empty(Method::PUT, url, Some(serde_json::json!({ "ignored": false }))).await
+	}
+
+	async fn unsubscribe(&self, thread_id: &str) -> Result<()> {
+		let url =
+			format!("{}/notifications/threads/{}/subscription", self.0.config.api_url, thread_id);
+		self.send_empty(Method::DELETE, url, None).await
+	}
+}
@@ -2,13 +2,80 @@ use crate::GitHubClientInner;
 use async_trait::async_trait;
 use crate::common::JsonValue;
 use nipaw_core::option::repo::ListOptions;
-use nipaw_core::types::{org::OrgInfo, repo::RepoInfo};
-use nipaw_core::{Org, Result};
-use std::collections::HashMap;
+use nipaw_core::types::{
+	org::{MemberRole, OrgInfo, OrgMember, TeamInfo, TeamRepoInfo},
+	repo::RepoInfo,
+	user::UserInfo,
+};
+use nipaw_core::{Error, Org, Result};
+use http::header;
+use reqwest::Method;
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 pub struct GitHubOrg(pub(crate) Arc<GitHubClientInner>);
 
+impl GitHubOrg {
+	/// 发送不关心响应内容的请求, 需要登录
+	async fn send_empty(&self, method: Method, url: String, body: Option<Value>) -> Result<()> {
+		let token = &self.0.config.token;
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let client = self.0.client.read().await;
+		let mut request = client.request(method.clone(), url).bearer_auth(token.as_ref().unwrap());
+		if body.is_none() && method != Method::GET {
+			request = request.header(header::CONTENT_LENGTH, 0);
+		}
+		if let Some(body) = body {
+			request = request.json(&body);
+		}
+		request.send().await?.error_for_status()?;
+		Ok(())
+	}
+
+	/// 获取分页列表
+	async fn get_list<T: From<JsonValue>>(
+		&self,
+		url: String,
+		mut params: HashMap<&'static str, String>,
+		options: Option<ListOptions>,
+	) -> Result<Vec<T>> {
+		let token = &self.0.config.token;
+		let client = self.0.client.read().await;
+		let mut request = client.get(url);
+		if let Some(token) = token {
+			request = request.bearer_auth(token);
+		}
+		if let Some(option) = options {
+			let per_page = option.per_page.unwrap_or(30).min(100);
+			params.insert("per_page", per_page.to_string());
+			let page = option.page.unwrap_or(1);
+			params.insert("page", page.to_string());
+		}
+		let list = request.query(&params).send().await?.json::<Vec<JsonValue>>().await?;
+		Ok(list.into_iter().map(|v| v.into()).collect())
+	}
+
+	/// 获取组织管理员的用户名
+	async fn admin_logins(&self, org_name: &str) -> Result<HashSet<String>> {
+		let url = format!("{}/orgs/{}/members", self.0.config.api_url, org_name);
+		let mut admins = HashSet::new();
+		for page in 1.. {
+			let params = HashMap::from([("role", "admin".to_string())]);
+			let options = ListOptions { per_page: Some(100), page: Some(page) };
+			let members: Vec<UserInfo> = self.get_list(url.clone(), params, Some(options)).await?;
+			let count = members.len();
+			admins.extend(members.into_iter().map(|m| m.login));
+			if count < 100 {
+				break;
+			}
+		}
+		Ok(admins)
+	}
+}
+
 #[async_trait]
 impl Org for GitHubOrg {
 	async fn info(&self, org_name: &str) -> Result<OrgInfo> {
@@ -61,4 +128,101 @@ impl Org for GitHubOrg {
 		let avatar_url = format!("https://avatars.githubusercontent.com/u/{}?v=4", org_id);
 		Ok(avatar_url)
 	}
+
+	async fn members(
+		&self,
+		org_name: &str,
+		options: Option<ListOptions>,
+	) -> Result<Vec<OrgMember>> {
+		let url = format!("{}/orgs/{}/members", self.0.config.api_url, org_name);
+		let mut members: Vec<OrgMember> = self.get_list(url, HashMap::new(), options).await?;
+		// 成员列表不包含角色, 通过管理员列表补全
+		let admins = self.admin_logins(org_name).await?;
+		for member in members.iter_mut() {
+			if admins.contains(&member.login) {
+				member.role = MemberRole::Admin;
+			}
+		}
+		Ok(members)
+	}
+
+	async fn add_member(
+		&self,
+		org_name: &str,
+		user_name: &str,
+		role: Option<MemberRole>,
+	) -> Result<()> {
+		let url = format!("{}/orgs/{}/memberships/{}", self.0.config.api_url, org_name, user_name);
+		let role = match role {
+			Some(MemberRole::Admin) => "admin",
+			_ => "member",
+		};
+		self.send_empty(Method::PUT, url, Some(serde_json::json!({ "role": role }))).await
+	}
+
+	async fn remove_member(&self, org_name: &str, user_name: &str) -> Result<()> {
+		let url = format!("{}/orgs/{}/memberships/{}", self.0.config.api_url, org_name, user_name);
+		self.send_empty(Method::DELETE, url, None).await
+	}
+
+	async fn is_member(&self, org_name: &str, user_name: &str) -> Result<bool> {
+		let url = format!("{}/orgs/{}/members/{}", self.0.config.api_url, org_name, user_name);
+		match self.send_empty(Method::GET, url, None).await {
+			Ok(()) => Ok(true),
+			Err(err) if err.is_not_found() => Ok(false),
+			Err(err) => Err(err),
+		}
+	}
+
+	async fn teams(&self, org_name: &str, options: Option<ListOptions>) -> Result<Vec<TeamInfo>> {
+		let url = format!("{}/orgs/{}/teams", self.0.config.api_url, org_name);
+		self.get_list(url, HashMap::new(), options).await
+	}
+
+	async fn team_members(
+		&self,
+		org_name: &str,
+		team_slug: &str,
+		options: Option<ListOptions>,
+	) -> Result<Vec<UserInfo>> {
+		let url =
+			format!("{}/orgs/{}/teams/{}/members", self.0.config.api_url, org_name, team_slug);
+		self.get_list(url, HashMap::new(), options).await
+	}
+
+	async fn add_team_member(
+		&self,
+		org_name: &str,
+		team_slug: &str,
+		user_name: &str,
+	) -> Result<()> {
+		let url = format!(
+			"{}/orgs/{}/teams/{}/memberships/{}",
+			self.0.config.api_url, org_name, team_slug, user_name
+		);
+		self.send_empty(Method::PUT, url, Some(serde_json::json!({ "role": "member" }))).await
+	}
+
+	async fn remove_team_member(
+		&self,
+		org_name: &str,
+		team_slug: &str,
+		user_name: &str,
+	) -> Result<()> {
+		let url = format!(
+			"{}/orgs/{}/teams/{}/memberships/{}",
+			self.0.config.api_url, org_name, team_slug, user_name
+		);
+		self.send_empty(Method::DELETE, url, None).await
+	}
+
+	async fn team_repos(
+		&self,
+		org_name: &str,
+		team_slug: &str,
+		options: Option<ListOptions>,
+	) -> Result<Vec<TeamRepoInfo>> {
+		let url = format!("{}/orgs/{}/teams/{}/repos", self.0.config.api_url, org_name, team_slug);
+		self.get_list(url, HashMap::new(), options).await
+	}
 }
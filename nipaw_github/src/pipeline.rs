@@ -0,0 +1,168 @@
+use crate::GitHubClientInner;
+use crate::common::JsonValue;
+use async_trait::async_trait;
+use http::header;
+use nipaw_core::option::pipeline::{ListOptions, TriggerOptions};
+use nipaw_core::types::pipeline::{PipelineJob, PipelineRun, PipelineStatus};
+use nipaw_core::types::release::DownloadResult;
+use nipaw_core::types::repo::RepoPath;
+use nipaw_core::{Error, Pipeline, Result, download};
+use reqwest::Method;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::io::AsyncWrite;
+
+pub struct GitHubPipeline(pub(crate) Arc<GitHubClientInner>);
+
+impl GitHubPipeline {
+	/// 发送不关心响应内容的请求, 需要登录
+	async fn send_empty(&self, method: Method, url: String, body: Option<Value>) -> Result<()> {
+		let token = &self.0.config.token;
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let client = self.0.client.read().await;
+		let mut request = client.request(method, url).bearer_auth(token.as_ref().unwrap());
+		request = match body {
+			Some(body) => request.json(&body),
+			None => request.header(header::CONTENT_LENGTH, 0),
+		};
+		request.send().await?.error_for_status()?;
+		Ok(())
+	}
+
+	fn run_url(&self, repo_path: &RepoPath, run_id: &str) -> String {
+		format!(
+			"{}/repos/{}/{}/actions/runs/{}",
+			self.0.config.api_url, repo_path.owner, repo_path.repo, run_id
+		)
+	}
+}
+
+/// 将状态转换为运行列表接口的`status`筛选值
+fn status_filter(status: PipelineStatus) -> Result<&'static str> {
+	Ok(match status {
+		PipelineStatus::Queued => "queued",
+		PipelineStatus::Waiting => "waiting",
+		PipelineStatus::Running => "in_progress",
+		PipelineStatus::Success => "success",
+		PipelineStatus::Failure => "failure",
+		PipelineStatus::Cancelled => "cancelled",
+		PipelineStatus::Skipped => "skipped",
+		PipelineStatus::Unknown => {
+			return Err(Error::InvalidParam {
+				param: "status".to_string(),
+				reason: "unknown status cannot be used as a filter".to_string(),
+			});
+		}
+	})
+}
+
+#[async_trait]
+impl Pipeline for GitHubPipeline {
+	async fn list(
+		&self,
+		repo_path: RepoPath,
+		option: Option<ListOptions>,
+	) -> Result<Vec<PipelineRun>> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		let url = format!("{}/repos/{}/{}/actions/runs", api_url, repo_path.owner, repo_path.repo);
+		let option = option.unwrap_or_default();
+		let mut params: HashMap<&str, String> = HashMap::new();
+		let per_page = option.per_page.unwrap_or(30).min(100);
+		params.insert("per_page", per_page.to_string());
+		let page = option.page.unwrap_or(1);
+		params.insert("page", page.to_string());
+		if let Some(branch) = option.branch {
+			params.insert("branch", branch);
+		}
+		if let Some(status) = option.status {
+			params.insert("status", status_filter(status)?.to_string());
+		}
+		if let Some(event) = option.event {
+			params.insert("event", event);
+		}
+		let client = self.0.client.read().await;
+		let mut request = client.get(url);
+		if let Some(token) = token {
+			request = request.bearer_auth(token);
+		}
+		let res =
+			request.query(&params).send().await?.error_for_status()?.json::<JsonValue>().await?;
+		let runs = res.0.get("workflow_runs").cloned().unwrap_or_default();
+		Ok(JsonValue(runs).into_iter().map(|v| v.into()).collect())
+	}
+
+	async fn info(&self, repo_path: RepoPath, run_id: &str) -> Result<PipelineRun> {
+		let token = &self.0.config.token;
+		let url = self.run_url(&repo_path, run_id);
+		let client = self.0.client.read().await;
+		let mut request = client.get(&url);
+		if let Some(token) = token {
+			request = request.bearer_auth(token);
+		}
+		let res = request.send().await?.error_for_status()?.json::<JsonValue>().await?;
+		let mut run: PipelineRun = res.into();
+		// 任务列表分页返回, 逐页获取直到取满`total_count`
+		for page in 1.. {
+			let mut request =
+				client.get(format!("{}/jobs", url)).query(&[("per_page", 100), ("page", page)]);
+			if let Some(token) = token {
+				request = request.bearer_auth(token);
+			}
+			let res = request.send().await?.error_for_status()?.json::<JsonValue>().await?;
+			let total = res.0.get("total_count").and_then(|v| v.as_u64()).unwrap_or_default();
+			let jobs = res.0.get("jobs").cloned().unwrap_or_default();
+			let jobs: Vec<PipelineJob> =
+				JsonValue(jobs).into_iter().map(PipelineJob::from).collect();
+			let done = jobs.is_empty() || run.jobs.len() + jobs.len() >= total as usize;
+			run.jobs.extend(jobs);
+			if done {
+				break;
+			}
+		}
+		Ok(run)
+	}
+
+	async fn rerun(&self, repo_path: RepoPath, run_id: &str) -> Result<()> {
+		let url = format!("{}/rerun", self.run_url(&repo_path, run_id));
+		self.send_empty(Method::POST, url, None).await
+	}
+
+	async fn cancel(&self, repo_path: RepoPath, run_id: &str) -> Result<()> {
+		let url = format!("{}/cancel", self.run_url(&repo_path, run_id));
+		self.send_empty(Method::POST, url, None).await
+	}
+
+	async fn trigger(
+		&self,
+		repo_path: RepoPath,
+		workflow: &str,
+		option: TriggerOptions,
+	) -> Result<Option<String>> {
+		let url = format!(
+			"{}/repos/{}/{}/actions/workflows/{}/dispatches",
+			self.0.config.api_url, repo_path.owner, repo_path.repo, workflow
+		);
+		let body = serde_json::json!({ "ref": option.reference, "inputs": option.inputs });
+		self.send_empty(Method::POST, url, Some(body)).await?;
+		Ok(None)
+	}
+
+	async fn download_logs(
+		&self,
+		repo_path: RepoPath,
+		run_id: &str,
+		writer: &mut (dyn AsyncWrite + Unpin + Send),
+	) -> Result<DownloadResult> {
+		let token = &self.0.config.token;
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let url = format!("{}/logs", self.run_url(&repo_path, run_id));
+		let client = self.0.client.read().await;
+		let request = client.get(url).bearer_auth(token.as_ref().unwrap());
+		download::to_writer(request, writer, &Default::default(), None).await
+	}
+}
@@ -1,11 +1,17 @@
 use crate::GitHubClientInner;
 use async_trait::async_trait;
 use crate::common::JsonValue;
-use nipaw_core::option::release::UpdateOption;
-use nipaw_core::types::release::ReleaseInfo;
+use http::header;
+use nipaw_core::download;
+use nipaw_core::option::release::{Checksum, DownloadOptions, UpdateOption};
+use nipaw_core::types::release::{DownloadResult, ReleaseInfo};
 use nipaw_core::types::repo::RepoPath;
 use nipaw_core::{Error, Release, Result};
+use reqwest_middleware::RequestBuilder;
+use std::path::Path;
+use serde_json::Value;
 use std::sync::Arc;
+use tokio::io::AsyncWrite;
 
 pub struct GitHubRelease(pub(crate) Arc<GitHubClientInner>);
 
@@ -27,6 +33,40 @@ impl GitHubRelease {
 		let id = res.0.get("id").and_then(|x| x.as_u64()).ok_or(Error::NotFound{ resource: "id".to_string()})?;
 		Ok(id)
 	}
+
+	/// 构造附件下载请求, 同时解析出期望的SHA-256摘要
+	///
+	/// 使用附件的API地址并指定`application/octet-stream`, 以便下载私有仓库的附件
+	pub(crate) async fn asset_request(
+		&self,
+		repo_path: &RepoPath,
+		tag_name: &str,
+		asset_name: &str,
+		checksum: Option<&Checksum>,
+	) -> Result<(RequestBuilder, Option<String>)> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		let url = format!(
+			"{}/repos/{}/{}/releases/tags/{}",
+			api_url, repo_path.owner, repo_path.repo, tag_name
+		);
+		let client = self.0.client.read().await;
+		let mut request = client.get(url);
+		if let Some(token) = token {
+			request = request.bearer_auth(token);
+		}
+		let release = request.send().await?.json::<JsonValue>().await?;
+		let asset_url =
+			|asset: &Value| asset.get("url").and_then(|v| v.as_str()).map(|s| s.to_string());
+		let build = |url: String| {
+			let mut request =
+				client.get(url).header(header::ACCEPT, "application/octet-stream");
+			if let Some(token) = token {
+				request = request.bearer_auth(token);
+			}
+			request
+		};
+		download::asset_request(&release.0, asset_name, checksum, asset_url, build).await
+	}
 }
 
 #[async_trait]
@@ -113,4 +153,34 @@ impl Release for GitHubRelease {
 		let res = request.json(&json_body).send().await?.json::<JsonValue>().await?;
 		Ok(res.into())
 	}
+
+	async fn download_asset(
+		&self,
+		repo_path: RepoPath,
+		tag_name: &str,
+		asset_name: &str,
+		writer: &mut (dyn AsyncWrite + Unpin + Send),
+		option: Option<DownloadOptions>,
+	) -> Result<DownloadResult> {
+		let option = option.unwrap_or_default();
+		let (request, expected) = self
+			.asset_request(&repo_path, tag_name, asset_name, option.checksum.as_ref())
+			.await?;
+		download::to_writer(request, writer, &option, expected).await
+	}
+
+	async fn download_asset_to_file(
+		&self,
+		repo_path: RepoPath,
+		tag_name: &str,
+		asset_name: &str,
+		path: &Path,
+		option: Option<DownloadOptions>,
+	) -> Result<DownloadResult> {
+		let option = option.unwrap_or_default();
+		let (request, expected) = self
+			.asset_request(&repo_path, tag_name, asset_name, option.checksum.as_ref())
+			.await?;
+		download::to_file(request, path, &option, expected).await
+	}
 }
@@ -2,12 +2,107 @@ use crate::GitHubClientInner;
 use async_trait::async_trait;
 use crate::common::JsonValue;
 use http::header;
-use nipaw_core::types::repo::{CollaboratorPermission, CollaboratorResult, RepoInfo, RepoPath};
-use nipaw_core::{Repo, Result};
+use nipaw_core::option::repo::{CreateOptions, ListOptions, UpdateOptions};
+use nipaw_core::types::key::{self, DeployKeyInfo};
+use nipaw_core::types::release::DownloadResult;
+use nipaw_core::types::repo::{
+	ArchiveFormat, CollaboratorInfo, CollaboratorPermission, CollaboratorResult, ContributorInfo,
+	RepoInfo, RepoLanguages, RepoPath, SyncForkResult, Visibility,
+};
+use nipaw_core::types::user::UserInfo;
+use nipaw_core::{Error, Repo, Result, download};
+use reqwest::Method;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
 use std::sync::Arc;
+use tokio::io::AsyncWrite;
 
 pub struct GitHubRepo(pub(crate) Arc<GitHubClientInner>);
 
+impl GitHubRepo {
+	/// 发送不关心响应内容的请求, 需要登录
+	async fn send_empty(&self, method: Method, url: String, body: Option<String>) -> Result<()> {
+		let token = &self.0.config.token;
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let client = self.0.client.read().await;
+		let mut request = client.request(method.clone(), url).bearer_auth(token.as_ref().unwrap());
+		if let Some(body) = body {
+			request = request.header(header::CONTENT_TYPE, "application/json").body(body);
+		} else if method != Method::GET {
+			request = request.header(header::CONTENT_LENGTH, 0);
+		}
+		request.send().await?.error_for_status()?;
+		Ok(())
+	}
+
+	/// 获取分页的用户列表
+	async fn get_user_list(
+		&self,
+		url: String,
+		option: Option<ListOptions>,
+	) -> Result<Vec<UserInfo>> {
+		let token = &self.0.config.token;
+		let client = self.0.client.read().await;
+		let mut request = client.get(url);
+		if let Some(token) = token {
+			request = request.bearer_auth(token);
+		}
+		let mut params: HashMap<&str, String> = HashMap::new();
+		if let Some(option) = option {
+			let per_page = option.per_page.unwrap_or(30).min(100);
+			params.insert("per_page", per_page.to_string());
+			let page = option.page.unwrap_or(1);
+			params.insert("page", page.to_string());
+		}
+		let user_infos = request.query(&params).send().await?.json::<Vec<JsonValue>>().await?;
+		Ok(user_infos.into_iter().map(|v| v.into()).collect())
+	}
+
+	/// 获取分页的仓库列表
+	async fn get_repo_list(
+		&self,
+		url: String,
+		option: Option<ListOptions>,
+	) -> Result<Vec<RepoInfo>> {
+		let token = &self.0.config.token;
+		let client = self.0.client.read().await;
+		let mut request = client.get(url);
+		if let Some(token) = token {
+			request = request.bearer_auth(token);
+		}
+		let mut params: HashMap<&str, String> = HashMap::new();
+		if let Some(option) = option {
+			let per_page = option.per_page.unwrap_or(30).min(100);
+			params.insert("per_page", per_page.to_string());
+			let page = option.page.unwrap_or(1);
+			params.insert("page", page.to_string());
+		}
+		let repo_infos = request.query(&params).send().await?.json::<Vec<JsonValue>>().await?;
+		Ok(repo_infos.into_iter().map(|v| v.into()).collect())
+	}
+
+	/// 发送JSON请求并返回响应内容, 需要登录
+	async fn send_json(&self, method: Method, url: String, body: Value) -> Result<JsonValue> {
+		let token = &self.0.config.token;
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let client = self.0.client.read().await;
+		let res = client
+			.request(method, url)
+			.bearer_auth(token.as_ref().unwrap())
+			.json(&body)
+			.send()
+			.await?
+			.error_for_status()?
+			.json::<JsonValue>()
+			.await?;
+		Ok(res)
+	}
+}
+
 #[async_trait]
 impl Repo for GitHubRepo {
 	async fn info(&self, repo_path: RepoPath) -> Result<RepoInfo> {
@@ -44,6 +139,12 @@ impl Repo for GitHubRepo {
 				CollaboratorPermission::Admin => "admin".to_string(),
 				CollaboratorPermission::Push => "push".to_string(),
 				CollaboratorPermission::Pull => "pull".to_string(),
+				CollaboratorPermission::None => {
+					return Err(Error::InvalidParam {
+						param: "permission".to_string(),
+						reason: "cannot add a collaborator without access".to_string(),
+					});
+				}
 			},
 			None => "pull".to_string(),
 		};
@@ -59,4 +160,434 @@ impl Repo for GitHubRepo {
 		let collaborator_result: JsonValue = resp.json().await?;
 		Ok(collaborator_result.into())
 	}
+
+	async fn star(&self, repo_path: RepoPath) -> Result<()> {
+		let api_url = &self.0.config.api_url;
+		let url = format!("{}/user/starred/{}/{}", api_url, repo_path.owner, repo_path.repo);
+		self.send_empty(Method::PUT, url, None).await
+	}
+
+	async fn unstar(&self, repo_path: RepoPath) -> Result<()> {
+		let api_url = &self.0.config.api_url;
+		let url = format!("{}/user/starred/{}/{}", api_url, repo_path.owner, repo_path.repo);
+		self.send_empty(Method::DELETE, url, None).await
+	}
+
+	async fn is_starred(&self, repo_path: RepoPath) -> Result<bool> {
+		let api_url = &self.0.config.api_url;
+		let url = format!("{}/user/starred/{}/{}", api_url, repo_path.owner, repo_path.repo);
+		match self.send_empty(Method::GET, url, None).await {
+			Ok(()) => Ok(true),
+			Err(err) if err.is_not_found() => Ok(false),
+			Err(err) => Err(err),
+		}
+	}
+
+	async fn stargazers(
+		&self,
+		repo_path: RepoPath,
+		option: Option<ListOptions>,
+	) -> Result<Vec<UserInfo>> {
+		let api_url = &self.0.config.api_url;
+		let url = format!("{}/repos/{}/{}/stargazers", api_url, repo_path.owner, repo_path.repo);
+		self.get_user_list(url, option).await
+	}
+
+	async fn watch(&self, repo_path: RepoPath) -> Result<()> {
+		let api_url = &self.0.config.api_url;
+		let url = format!("{}/repos/{}/{}/subscription", api_url, repo_path.owner, repo_path.repo);
+		let body = serde_json::json!({ "subscribed": true });
+		self.send_empty(Method::PUT, url, Some(body.to_string())).await
+	}
+
+	async fn unwatch(&self, repo_path: RepoPath) -> Result<()> {
+		let api_url = &self.0.config.api_url;
+		let url = format!("{}/repos/{}/{}/subscription", api_url, repo_path.owner, repo_path.repo);
+		self.send_empty(Method::DELETE, url, None).await
+	}
+
+	async fn watchers(
+		&self,
+		repo_path: RepoPath,
+		option: Option<ListOptions>,
+	) -> Result<Vec<UserInfo>> {
+		let api_url = &self.0.config.api_url;
+		let url = format!("{}/repos/{}/{}/subscribers", api_url, repo_path.owner, repo_path.repo);
+		self.get_user_list(url, option).await
+	}
+
+	async fn fork(
+		&self,
+		repo_path: RepoPath,
+		target_org: Option<&str>,
+		name: Option<&str>,
+	) -> Result<RepoInfo> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let url = format!("{}/repos/{}/{}/forks", api_url, repo_path.owner, repo_path.repo);
+		let client = self.0.client.read().await;
+		let mut body = serde_json::Map::new();
+		if let Some(org) = target_org {
+			body.insert("organization".to_string(), org.into());
+		}
+		if let Some(name) = name {
+			body.insert("name".to_string(), name.into());
+		}
+		let repo_info = client
+			.post(url)
+			.bearer_auth(token.as_ref().unwrap())
+			.json(&body)
+			.send()
+			.await?
+			.error_for_status()?
+			.json::<JsonValue>()
+			.await?;
+		Ok(repo_info.into())
+	}
+
+	async fn forks(
+		&self,
+		repo_path: RepoPath,
+		option: Option<ListOptions>,
+	) -> Result<Vec<RepoInfo>> {
+		let api_url = &self.0.config.api_url;
+		let url = format!("{}/repos/{}/{}/forks", api_url, repo_path.owner, repo_path.repo);
+		self.get_repo_list(url, option).await
+	}
+
+	async fn sync_fork(&self, repo_path: RepoPath, branch: Option<&str>) -> Result<SyncForkResult> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let branch = match branch {
+			Some(branch) => branch.to_string(),
+			None => self.info(repo_path.clone()).await?.default_branch,
+		};
+		let url =
+			format!("{}/repos/{}/{}/merge-upstream", api_url, repo_path.owner, repo_path.repo);
+		let client = self.0.client.read().await;
+		let result = client
+			.post(url)
+			.bearer_auth(token.as_ref().unwrap())
+			.json(&serde_json::json!({ "branch": branch }))
+			.send()
+			.await?
+			.error_for_status()?
+			.json::<JsonValue>()
+			.await?;
+		Ok(result.into())
+	}
+
+	async fn create(
+		&self,
+		org_name: Option<&str>,
+		name: &str,
+		option: Option<CreateOptions>,
+	) -> Result<RepoInfo> {
+		let api_url = &self.0.config.api_url;
+		let url = match org_name {
+			Some(org) => format!("{}/orgs/{}/repos", api_url, org),
+			None => format!("{}/user/repos", api_url),
+		};
+		let option = option.unwrap_or_default();
+		let mut body = Map::new();
+		body.insert("name".to_string(), name.into());
+		if let Some(description) = option.description {
+			body.insert("description".to_string(), description.into());
+		}
+		if let Some(homepage) = option.homepage {
+			body.insert("homepage".to_string(), homepage.into());
+		}
+		if let Some(visibility) = option.visibility {
+			body.insert("private".to_string(), (!matches!(visibility, Visibility::Public)).into());
+			body.insert("visibility".to_string(), visibility_str(&visibility).into());
+		}
+		if let Some(auto_init) = option.auto_init {
+			body.insert("auto_init".to_string(), auto_init.into());
+		}
+		if let Some(template) = option.gitignore_template {
+			body.insert("gitignore_template".to_string(), template.into());
+		}
+		if let Some(template) = option.license_template {
+			body.insert("license_template".to_string(), template.into());
+		}
+		let repo_info = self.send_json(Method::POST, url, Value::Object(body)).await?;
+		Ok(repo_info.into())
+	}
+
+	async fn update(&self, repo_path: RepoPath, option: UpdateOptions) -> Result<RepoInfo> {
+		let api_url = &self.0.config.api_url;
+		let url = format!("{}/repos/{}/{}", api_url, repo_path.owner, repo_path.repo);
+		let mut body = Map::new();
+		if let Some(name) = option.name {
+			body.insert("name".to_string(), name.into());
+		}
+		if let Some(description) = option.description {
+			body.insert("description".to_string(), description.into());
+		}
+		if let Some(homepage) = option.homepage {
+			body.insert("homepage".to_string(), homepage.into());
+		}
+		if let Some(default_branch) = option.default_branch {
+			body.insert("default_branch".to_string(), default_branch.into());
+		}
+		if let Some(visibility) = option.visibility {
+			body.insert("visibility".to_string(), visibility_str(&visibility).into());
+		}
+		if let Some(has_issues) = option.has_issues {
+			body.insert("has_issues".to_string(), has_issues.into());
+		}
+		if let Some(has_wiki) = option.has_wiki {
+			body.insert("has_wiki".to_string(), has_wiki.into());
+		}
+		if let Some(has_projects) = option.has_projects {
+			body.insert("has_projects".to_string(), has_projects.into());
+		}
+		let repo_info = self.send_json(Method::PATCH, url, Value::Object(body)).await?;
+		Ok(repo_info.into())
+	}
+
+	async fn delete(&self, repo_path: RepoPath) -> Result<()> {
+		let api_url = &self.0.config.api_url;
+		let url = format!("{}/repos/{}/{}", api_url, repo_path.owner, repo_path.repo);
+		self.send_empty(Method::DELETE, url, None).await
+	}
+
+	async fn set_archived(&self, repo_path: RepoPath, archived: bool) -> Result<()> {
+		let api_url = &self.0.config.api_url;
+		let url = format!("{}/repos/{}/{}", api_url, repo_path.owner, repo_path.repo);
+		let body = serde_json::json!({ "archived": archived });
+		self.send_empty(Method::PATCH, url, Some(body.to_string())).await
+	}
+
+	async fn transfer(
+		&self,
+		repo_path: RepoPath,
+		new_owner: &str,
+		new_name: Option<&str>,
+	) -> Result<RepoInfo> {
+		let api_url = &self.0.config.api_url;
+		let url = format!("{}/repos/{}/{}/transfer", api_url, repo_path.owner, repo_path.repo);
+		let mut body = Map::new();
+		body.insert("new_owner".to_string(), new_owner.into());
+		if let Some(new_name) = new_name {
+			body.insert("new_name".to_string(), new_name.into());
+		}
+		let repo_info = self.send_json(Method::POST, url, Value::Object(body)).await?;
+		Ok(repo_info.into())
+	}
+
+	async fn collaborators(
+		&self,
+		repo_path: RepoPath,
+		option: Option<ListOptions>,
+	) -> Result<Vec<CollaboratorInfo>> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		let url = format!("{}/repos/{}/{}/collaborators", api_url, repo_path.owner, repo_path.repo);
+		let client = self.0.client.read().await;
+		let mut request = client.get(url);
+		if let Some(token) = token {
+			request = request.bearer_auth(token);
+		}
+		let mut params: HashMap<&str, String> = HashMap::new();
+		if let Some(option) = option {
+			let per_page = option.per_page.unwrap_or(30).min(100);
+			params.insert("per_page", per_page.to_string());
+			let page = option.page.unwrap_or(1);
+			params.insert("page", page.to_string());
+		}
+		let collaborators = request.query(&params).send().await?.json::<Vec<JsonValue>>().await?;
+		Ok(collaborators.into_iter().map(|v| v.into()).collect())
+	}
+
+	async fn remove_collaborator(&self, repo_path: RepoPath, user_name: &str) -> Result<()> {
+		let api_url = &self.0.config.api_url;
+		let url = format!(
+			"{}/repos/{}/{}/collaborators/{}",
+			api_url, repo_path.owner, repo_path.repo, user_name
+		);
+		self.send_empty(Method::DELETE, url, None).await
+	}
+
+	async fn collaborator_permission(
+		&self,
+		repo_path: RepoPath,
+		user_name: &str,
+	) -> Result<CollaboratorPermission> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let url = format!(
+			"{}/repos/{}/{}/collaborators/{}/permission",
+			api_url, repo_path.owner, repo_path.repo, user_name
+		);
+		let client = self.0.client.read().await;
+		let permission = client
+			.get(url)
+			.bearer_auth(token.as_ref().unwrap())
+			.send()
+			.await?
+			.error_for_status()?
+			.json::<JsonValue>()
+			.await?;
+		Ok(permission.into())
+	}
+
+	async fn is_collaborator(&self, repo_path: RepoPath, user_name: &str) -> Result<bool> {
+		let api_url = &self.0.config.api_url;
+		let url = format!(
+			"{}/repos/{}/{}/collaborators/{}",
+			api_url, repo_path.owner, repo_path.repo, user_name
+		);
+		match self.send_empty(Method::GET, url, None).await {
+			Ok(()) => Ok(true),
+			Err(err) if err.is_not_found() => Ok(false),
+			Err(err) => Err(err),
+		}
+	}
+
+	async fn deploy_keys(
+		&self,
+		repo_path: RepoPath,
+		option: Option<ListOptions>,
+	) -> Result<Vec<DeployKeyInfo>> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let url = format!("{}/repos/{}/{}/keys", api_url, repo_path.owner, repo_path.repo);
+		let client = self.0.client.read().await;
+		let request = client.get(url).bearer_auth(token.as_ref().unwrap());
+		let mut params: HashMap<&str, String> = HashMap::new();
+		if let Some(option) = option {
+			let per_page = option.per_page.unwrap_or(30).min(100);
+			params.insert("per_page", per_page.to_string());
+			let page = option.page.unwrap_or(1);
+			params.insert("page", page.to_string());
+		}
+		let deploy_keys = request.query(&params).send().await?.json::<Vec<JsonValue>>().await?;
+		Ok(deploy_keys.into_iter().map(|v| v.into()).collect())
+	}
+
+	async fn add_deploy_key(
+		&self,
+		repo_path: RepoPath,
+		title: &str,
+		key: &str,
+		read_only: bool,
+	) -> Result<DeployKeyInfo> {
+		key::validate_ssh_key(key)?;
+		let api_url = &self.0.config.api_url;
+		let url = format!("{}/repos/{}/{}/keys", api_url, repo_path.owner, repo_path.repo);
+		let body = serde_json::json!({ "title": title, "key": key.trim(), "read_only": read_only });
+		let deploy_key = self.send_json(Method::POST, url, body).await?;
+		Ok(deploy_key.into())
+	}
+
+	async fn remove_deploy_key(&self, repo_path: RepoPath, id: u64) -> Result<()> {
+		let api_url = &self.0.config.api_url;
+		let url = format!("{}/repos/{}/{}/keys/{}", api_url, repo_path.owner, repo_path.repo, id);
+		self.send_empty(Method::DELETE, url, None).await
+	}
+
+	async fn topics(&self, repo_path: RepoPath) -> Result<Vec<String>> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		let url = format!("{}/repos/{}/{}/topics", api_url, repo_path.owner, repo_path.repo);
+		let client = self.0.client.read().await;
+		let mut request = client.get(url);
+		if let Some(token) = token {
+			request = request.bearer_auth(token);
+		}
+		let res = request.send().await?.json::<JsonValue>().await?;
+		Ok(parse_topic_names(res))
+	}
+
+	async fn set_topics(&self, repo_path: RepoPath, topics: Vec<String>) -> Result<Vec<String>> {
+		let api_url = &self.0.config.api_url;
+		let url = format!("{}/repos/{}/{}/topics", api_url, repo_path.owner, repo_path.repo);
+		let body = serde_json::json!({ "names": topics });
+		let res = self.send_json(Method::PUT, url, body).await?;
+		Ok(parse_topic_names(res))
+	}
+
+	async fn languages(&self, repo_path: RepoPath) -> Result<RepoLanguages> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		let url = format!("{}/repos/{}/{}/languages", api_url, repo_path.owner, repo_path.repo);
+		let client = self.0.client.read().await;
+		let mut request = client.get(url);
+		if let Some(token) = token {
+			request = request.bearer_auth(token);
+		}
+		let res = request.send().await?.json::<JsonValue>().await?;
+		Ok(res.into())
+	}
+
+	async fn contributors(
+		&self,
+		repo_path: RepoPath,
+		option: Option<ListOptions>,
+	) -> Result<Vec<ContributorInfo>> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		let url = format!("{}/repos/{}/{}/contributors", api_url, repo_path.owner, repo_path.repo);
+		let client = self.0.client.read().await;
+		let mut request = client.get(url);
+		if let Some(token) = token {
+			request = request.bearer_auth(token);
+		}
+		let mut params: HashMap<&str, String> = HashMap::new();
+		if let Some(option) = option {
+			let per_page = option.per_page.unwrap_or(30).min(100);
+			params.insert("per_page", per_page.to_string());
+			let page = option.page.unwrap_or(1);
+			params.insert("page", page.to_string());
+		}
+		let contributors = request.query(&params).send().await?.json::<Vec<JsonValue>>().await?;
+		Ok(contributors.into_iter().map(|v| v.into()).collect())
+	}
+
+	async fn archive(
+		&self,
+		repo_path: RepoPath,
+		reference: &str,
+		format: ArchiveFormat,
+		writer: &mut (dyn AsyncWrite + Unpin + Send),
+	) -> Result<DownloadResult> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		let kind = match format {
+			ArchiveFormat::TarGz => "tarball",
+			ArchiveFormat::Zip => "zipball",
+		};
+		let url = format!(
+			"{}/repos/{}/{}/{}/{}",
+			api_url, repo_path.owner, repo_path.repo, kind, reference
+		);
+		let client = self.0.client.read().await;
+		let mut request = client.get(url);
+		if let Some(token) = token {
+			request = request.bearer_auth(token);
+		}
+		download::to_writer(request, writer, &Default::default(), None).await
+	}
+}
+
+fn visibility_str(visibility: &Visibility) -> &'static str {
+	match visibility {
+		Visibility::Public => "public",
+		Visibility::Private => "private",
+		Visibility::Internal => "internal",
+	}
+}
+
+/// 读取主题接口返回的`names`字段
+fn parse_topic_names(value: JsonValue) -> Vec<String> {
+	value
+		.0
+		.get("names")
+		.and_then(|v| v.as_array())
+		.map(|names| names.iter().filter_map(|v| v.as_str()).map(|s| s.to_string()).collect())
+		.unwrap_or_default()
 }
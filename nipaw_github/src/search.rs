@@ -0,0 +1,121 @@
+use crate::GitHubClientInner;
+use crate::common::JsonValue;
+use async_trait::async_trait;
+use nipaw_core::option::search::{SearchQuery, SearchSort};
+use nipaw_core::types::issue::{IssueInfo, StateType};
+use nipaw_core::types::repo::RepoInfo;
+use nipaw_core::types::search::{CodeInfo, SearchResult};
+use nipaw_core::types::user::UserInfo;
+use nipaw_core::{Error, Result, Search};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+pub struct GitHubSearch(pub(crate) Arc<GitHubClientInner>);
+
+impl GitHubSearch {
+	/// 以`q=`限定符语法发起搜索
+	async fn search(
+		&self,
+		kind: &str,
+		q: String,
+		sort: Option<&str>,
+		query: &SearchQuery,
+	) -> Result<JsonValue> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		let url = format!("{}/search/{}", api_url, kind);
+		let client = self.0.client.read().await;
+		let mut request = client.get(url);
+		if let Some(token) = token {
+			request = request.bearer_auth(token);
+		}
+		let mut params: HashMap<&str, String> = HashMap::new();
+		params.insert("q", q);
+		if let Some(sort) = sort {
+			params.insert("sort", sort.to_string());
+			if let Some(order) = query.order {
+				params.insert("order", order.to_string());
+			}
+		}
+		let per_page = query.per_page.unwrap_or(30).min(100);
+		params.insert("per_page", per_page.to_string());
+		let page = query.page.unwrap_or(1);
+		params.insert("page", page.to_string());
+		let res = request.query(&params).send().await?.json::<JsonValue>().await?;
+		Ok(res)
+	}
+}
+
+/// 将关键字与限定条件拼接为`q`参数
+fn render_query(query: &SearchQuery, qualifiers: &[(&str, Option<&str>)]) -> String {
+	let mut q = vec![query.keywords_text()];
+	if let Some(language) = query.language.as_deref() {
+		q.push(format!("language:{}", language));
+	}
+	if let Some(owner) = query.owner.as_deref() {
+		q.push(format!("user:{}", owner));
+	}
+	for (key, value) in qualifiers {
+		if let Some(value) = value {
+			q.push(format!("{}:{}", key, value));
+		}
+	}
+	q.retain(|s| !s.is_empty());
+	q.join(" ")
+}
+
+#[async_trait]
+impl Search for GitHubSearch {
+	async fn repos(&self, query: SearchQuery) -> Result<SearchResult<RepoInfo>> {
+		let sort = match query.sort {
+			Some(SearchSort::Stars) => Some("stars"),
+			Some(SearchSort::Forks) => Some("forks"),
+			Some(SearchSort::Updated) => Some("updated"),
+			_ => None,
+		};
+		let q = render_query(&query, &[]);
+		let res = self.search("repositories", q, sort, &query).await?;
+		Ok(res.into())
+	}
+
+	async fn issues(&self, query: SearchQuery) -> Result<SearchResult<IssueInfo>> {
+		let sort = match query.sort {
+			Some(SearchSort::Comments) => Some("comments"),
+			Some(SearchSort::Created) => Some("created"),
+			Some(SearchSort::Updated) => Some("updated"),
+			_ => None,
+		};
+		let state = query.state.as_ref().map(|state| match state {
+			StateType::Opened => "open",
+			StateType::Closed => "closed",
+		});
+		let q = render_query(&query, &[("is", Some("issue")), ("state", state)]);
+		let res = self.search("issues", q, sort, &query).await?;
+		Ok(res.into())
+	}
+
+	async fn users(&self, query: SearchQuery) -> Result<SearchResult<UserInfo>> {
+		let sort = match query.sort {
+			Some(SearchSort::Followers) => Some("followers"),
+			Some(SearchSort::Created) => Some("joined"),
+			_ => None,
+		};
+		let q = render_query(&query, &[]);
+		let res = self.search("users", q, sort, &query).await?;
+		Ok(res.into())
+	}
+
+	async fn code(&self, query: SearchQuery) -> Result<SearchResult<CodeInfo>> {
+		if self.0.config.token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		if query.keywords.is_empty() {
+			return Err(Error::InvalidParam {
+				param: "keywords".to_string(),
+				reason: "code search requires at least one keyword".to_string(),
+			});
+		}
+		let q = render_query(&query, &[]);
+		let res = self.search("code", q, None, &query).await?;
+		Ok(res.into())
+	}
+}
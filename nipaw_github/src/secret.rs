@@ -0,0 +1,168 @@
+use crate::GitHubClientInner;
+use crate::common::JsonValue;
+use async_trait::async_trait;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use crypto_box::PublicKey;
+use crypto_box::aead::OsRng;
+use http::header;
+use nipaw_core::option::repo::ListOptions;
+use nipaw_core::types::secret::{SecretInfo, SecretScope, SecretValue, VariableInfo};
+use nipaw_core::{Error, Result, Secret};
+use reqwest::Method;
+use serde_json::Value;
+use std::sync::Arc;
+
+/// 组织级别的密钥与变量在新建时默认只对私有仓库可见
+const DEFAULT_ORG_VISIBILITY: &str = "private";
+
+pub struct GitHubSecret(pub(crate) Arc<GitHubClientInner>);
+
+impl GitHubSecret {
+	fn actions_url(&self, scope: &SecretScope) -> String {
+		let api_url = &self.0.config.api_url;
+		match scope {
+			SecretScope::Repo(repo_path) => {
+				format!("{}/repos/{}/{}/actions", api_url, repo_path.owner, repo_path.repo)
+			}
+			SecretScope::Org(org_name) => format!("{}/orgs/{}/actions", api_url, org_name),
+		}
+	}
+
+	/// 发送请求并返回响应, 需要登录
+	async fn send(
+		&self,
+		method: Method,
+		url: String,
+		query: &[(&str, String)],
+		body: Option<Value>,
+	) -> Result<reqwest::Response> {
+		let token = &self.0.config.token;
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let client = self.0.client.read().await;
+		let mut request =
+			client.request(method, url).bearer_auth(token.as_ref().unwrap()).query(query);
+		request = match body {
+			Some(body) => request.json(&body),
+			None => request.header(header::CONTENT_LENGTH, 0),
+		};
+		Ok(request.send().await?.error_for_status()?)
+	}
+
+	/// 获取组织下已有密钥或变量的可见范围, 不存在时返回[None]
+	async fn org_visibility(&self, url: String) -> Result<Option<String>> {
+		match self.send(Method::GET, url, &[], None).await {
+			Ok(res) => {
+				let res = res.json::<JsonValue>().await?;
+				Ok(res.0.get("visibility").and_then(|v| v.as_str()).map(|s| s.to_string()))
+			}
+			Err(err) if err.is_not_found() => Ok(None),
+			Err(err) => Err(err),
+		}
+	}
+}
+
+/// 使用仓库或组织的公钥对密钥进行libsodium sealed box加密, 返回base64编码的密文
+fn seal(public_key: &str, value: &SecretValue) -> Result<String> {
+	let invalid = |reason: &str| Error::InvalidParam {
+		param: "public_key".to_string(),
+		reason: reason.to_string(),
+	};
+	let key_bytes: [u8; 32] = STANDARD
+		.decode(public_key)
+		.map_err(|_| invalid("not valid base64"))?
+		.try_into()
+		.map_err(|_| invalid("expected a 32-byte key"))?;
+	let sealed = PublicKey::from(key_bytes)
+		.seal(&mut OsRng, value.expose().as_bytes())
+		.map_err(|_| invalid("encryption failed"))?;
+	Ok(STANDARD.encode(sealed))
+}
+
+fn page_query(option: Option<ListOptions>, max: u32) -> Vec<(&'static str, String)> {
+	let option = option.unwrap_or_default();
+	let per_page = option.per_page.unwrap_or(30).min(max);
+	let page = option.page.unwrap_or(1);
+	vec![("per_page", per_page.to_string()), ("page", page.to_string())]
+}
+
+#[async_trait]
+impl Secret for GitHubSecret {
+	async fn list_secrets(
+		&self,
+		scope: SecretScope,
+		option: Option<ListOptions>,
+	) -> Result<Vec<SecretInfo>> {
+		let url = format!("{}/secrets", self.actions_url(&scope));
+		let query = page_query(option, 100);
+		let res = self.send(Method::GET, url, &query, None).await?.json::<JsonValue>().await?;
+		let secrets = res.0.get("secrets").cloned().unwrap_or_default();
+		Ok(JsonValue(secrets).into_iter().map(|v| v.into()).collect())
+	}
+
+	async fn set_secret(&self, scope: SecretScope, name: &str, value: &SecretValue) -> Result<()> {
+		let base_url = self.actions_url(&scope);
+		let url = format!("{}/secrets/public-key", base_url);
+		let public_key = self.send(Method::GET, url, &[], None).await?.json::<JsonValue>().await?;
+		let get_str =
+			|key: &str| public_key.0.get(key).and_then(|v| v.as_str()).unwrap_or_default();
+		let mut body = serde_json::json!({
+			"encrypted_value": seal(get_str("key"), value)?,
+			"key_id": get_str("key_id"),
+		});
+		let url = format!("{}/secrets/{}", base_url, name);
+		// 组织密钥必须指定可见范围, 更新时沿用原有设置
+		if let SecretScope::Org(_) = scope {
+			let visibility = self.org_visibility(url.clone()).await?;
+			body["visibility"] = visibility.unwrap_or(DEFAULT_ORG_VISIBILITY.to_string()).into();
+		}
+		self.send(Method::PUT, url, &[], Some(body)).await?;
+		Ok(())
+	}
+
+	async fn delete_secret(&self, scope: SecretScope, name: &str) -> Result<()> {
+		let url = format!("{}/secrets/{}", self.actions_url(&scope), name);
+		self.send(Method::DELETE, url, &[], None).await?;
+		Ok(())
+	}
+
+	async fn list_variables(
+		&self,
+		scope: SecretScope,
+		option: Option<ListOptions>,
+	) -> Result<Vec<VariableInfo>> {
+		let url = format!("{}/variables", self.actions_url(&scope));
+		// 变量列表每页最多30条
+		let query = page_query(option, 30);
+		let res = self.send(Method::GET, url, &query, None).await?.json::<JsonValue>().await?;
+		let variables = res.0.get("variables").cloned().unwrap_or_default();
+		Ok(JsonValue(variables).into_iter().map(|v| v.into()).collect())
+	}
+
+	async fn set_variable(&self, scope: SecretScope, name: &str, value: &str) -> Result<()> {
+		let base_url = self.actions_url(&scope);
+		let body = serde_json::json!({ "name": name, "value": value });
+		let url = format!("{}/variables/{}", base_url, name);
+		match self.send(Method::PATCH, url, &[], Some(body.clone())).await {
+			Ok(_) => Ok(()),
+			Err(err) if err.is_not_found() => {
+				let mut body = body;
+				if let SecretScope::Org(_) = scope {
+					body["visibility"] = DEFAULT_ORG_VISIBILITY.into();
+				}
+				let url = format!("{}/variables", base_url);
+				self.send(Method::POST, url, &[], Some(body)).await?;
+				Ok(())
+			}
+			Err(err) => Err(err),
+		}
+	}
+
+	async fn delete_variable(&self, scope: SecretScope, name: &str) -> Result<()> {
+		let url = format!("{}/variables/{}", self.actions_url(&scope), name);
+		self.send(Method::DELETE, url, &[], None).await?;
+		Ok(())
+	}
+}
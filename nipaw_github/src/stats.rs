@@ -0,0 +1,108 @@
+use crate::GitHubClientInner;
+use crate::commit::GitHubCommit;
+use crate::common::JsonValue;
+use async_trait::async_trait;
+use nipaw_core::option::stats::StatsOptions;
+use nipaw_core::stats;
+use nipaw_core::types::repo::RepoPath;
+use nipaw_core::types::stats::{CodeFrequency, PunchCard, WeeklyCommitActivity};
+use nipaw_core::{Error, Result, Stats};
+use reqwest::StatusCode;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// 统计尚未计算完成时的重试间隔, 单位为秒
+const RETRY_DELAYS: [u64; 4] = [1, 2, 4, 8];
+
+pub struct GitHubStats(pub(crate) Arc<GitHubClientInner>);
+
+impl GitHubStats {
+	/// 获取统计接口数据
+	///
+	/// GitHub尚未完成计算时返回202, 按退避间隔重试, 仍未完成时返回[Error::NotReady]
+	async fn get_stats(&self, repo_path: &RepoPath, kind: &str) -> Result<Vec<JsonValue>> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		let url =
+			format!("{}/repos/{}/{}/stats/{}", api_url, repo_path.owner, repo_path.repo, kind);
+		for (attempt, delay) in RETRY_DELAYS.into_iter().enumerate() {
+			let client = self.0.client.read().await;
+			let mut request = client.get(&url);
+			if let Some(token) = token {
+				request = request.bearer_auth(token);
+			}
+			let res = request.send().await?.error_for_status()?;
+			match res.status() {
+				StatusCode::ACCEPTED => {}
+				// 空仓库返回204
+				StatusCode::NO_CONTENT => return Ok(Vec::new()),
+				_ => return Ok(res.json::<Vec<JsonValue>>().await?),
+			}
+			drop(client);
+			if attempt + 1 == RETRY_DELAYS.len() {
+				break;
+			}
+			tokio::time::sleep(Duration::from_secs(delay)).await;
+		}
+		Err(Error::NotReady { resource: format!("{} statistics", kind) })
+	}
+}
+
+#[async_trait]
+impl Stats for GitHubStats {
+	async fn commit_activity(
+		&self,
+		repo_path: RepoPath,
+		option: Option<StatsOptions>,
+	) -> Result<Vec<WeeklyCommitActivity>> {
+		let option = option.unwrap_or_default();
+		let explicit_since = option.since.is_some();
+		let (since, until) = stats::time_range(&option);
+		let list: Vec<WeeklyCommitActivity> = self
+			.get_stats(&repo_path, "commit_activity")
+			.await?
+			.into_iter()
+			.map(WeeklyCommitActivity::from)
+			.collect();
+		let since = stats::week_start(since);
+		// 接口只覆盖最近52周, 显式指定了更早的开始时间时改为本地计算
+		if explicit_since && list.first().is_some_and(|w| since < w.week) {
+			let commit = GitHubCommit(self.0.clone());
+			let commits =
+				stats::collect_commits(&commit, &repo_path, Some(since), Some(until)).await?;
+			return Ok(stats::commit_activity(&commits, since, until));
+		}
+		Ok(list.into_iter().filter(|w| w.week >= since && w.week <= until).collect())
+	}
+
+	async fn code_frequency(
+		&self,
+		repo_path: RepoPath,
+		option: Option<StatsOptions>,
+	) -> Result<Vec<CodeFrequency>> {
+		let (since, until) = stats::time_range(&option.unwrap_or_default());
+		let since = stats::week_start(since);
+		Ok(self
+			.get_stats(&repo_path, "code_frequency")
+			.await?
+			.into_iter()
+			.map(CodeFrequency::from)
+			.filter(|w| w.week >= since && w.week <= until)
+			.collect())
+	}
+
+	async fn punch_card(
+		&self,
+		repo_path: RepoPath,
+		option: Option<StatsOptions>,
+	) -> Result<Vec<PunchCard>> {
+		// GitHub的打卡统计覆盖全部历史, 指定时间范围时改为本地计算
+		if option.is_none() {
+			let list = self.get_stats(&repo_path, "punch_card").await?;
+			return Ok(list.into_iter().map(PunchCard::from).collect());
+		}
+		let (since, until) = stats::time_range(&option.unwrap_or_default());
+		let commit = GitHubCommit(self.0.clone());
+		let commits = stats::collect_commits(&commit, &repo_path, Some(since), Some(until)).await?;
+		Ok(stats::punch_card(&commits))
+	}
+}
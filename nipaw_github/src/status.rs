@@ -0,0 +1,88 @@
+use crate::GitHubClientInner;
+use crate::common::JsonValue;
+use async_trait::async_trait;
+use nipaw_core::option::status::{CreateOptions, ListOptions};
+use nipaw_core::types::repo::RepoPath;
+use nipaw_core::types::status::{CombinedStatusInfo, StatusInfo, StatusState};
+use nipaw_core::{Error, Result, Status};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+pub struct GitHubStatus(pub(crate) Arc<GitHubClientInner>);
+
+#[async_trait]
+impl Status for GitHubStatus {
+	async fn create(
+		&self,
+		repo_path: RepoPath,
+		sha: &str,
+		state: StatusState,
+		option: Option<CreateOptions>,
+	) -> Result<StatusInfo> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let url =
+			format!("{}/repos/{}/{}/statuses/{}", api_url, repo_path.owner, repo_path.repo, sha);
+		let client = self.0.client.read().await;
+		let request = client.post(url).bearer_auth(token.as_ref().unwrap());
+		let mut body: HashMap<&str, String> = HashMap::new();
+		body.insert("state", state.to_string());
+		if let Some(option) = option {
+			if let Some(context) = option.context {
+				body.insert("context", context);
+			}
+			if let Some(description) = option.description {
+				body.insert("description", description);
+			}
+			if let Some(target_url) = option.target_url {
+				body.insert("target_url", target_url);
+			}
+		}
+		let res = request.json(&body).send().await?.json::<JsonValue>().await?;
+		Ok(res.into())
+	}
+
+	async fn list(
+		&self,
+		repo_path: RepoPath,
+		reference: &str,
+		option: Option<ListOptions>,
+	) -> Result<Vec<StatusInfo>> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		let url = format!(
+			"{}/repos/{}/{}/commits/{}/statuses",
+			api_url, repo_path.owner, repo_path.repo, reference
+		);
+		let client = self.0.client.read().await;
+		let mut request = client.get(url);
+		if let Some(token) = token {
+			request = request.bearer_auth(token);
+		}
+		let mut params: HashMap<&str, String> = HashMap::new();
+		if let Some(option) = option {
+			let per_page = option.per_page.unwrap_or(30).min(100);
+			params.insert("per_page", per_page.to_string());
+			let page = option.page.unwrap_or(1);
+			params.insert("page", page.to_string());
+		}
+		let res = request.query(&params).send().await?.json::<Vec<JsonValue>>().await?;
+		Ok(res.into_iter().map(|v| v.into()).collect())
+	}
+
+	async fn combined(&self, repo_path: RepoPath, reference: &str) -> Result<CombinedStatusInfo> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		let url = format!(
+			"{}/repos/{}/{}/commits/{}/status",
+			api_url, repo_path.owner, repo_path.repo, reference
+		);
+		let client = self.0.client.read().await;
+		let mut request = client.get(url);
+		if let Some(token) = token {
+			request = request.bearer_auth(token);
+		}
+		let res = request.send().await?.json::<JsonValue>().await?;
+		Ok(res.into())
+	}
+}
@@ -0,0 +1,56 @@
+use crate::GitHubClientInner;
+use crate::common::{JsonValue, parse_series};
+use async_trait::async_trait;
+use nipaw_core::types::repo::RepoPath;
+use nipaw_core::types::traffic::{TrafficPath, TrafficPer, TrafficReferrer, TrafficSeries};
+use nipaw_core::{Error, Result, Traffic};
+use std::sync::Arc;
+
+pub struct GitHubTraffic(pub(crate) Arc<GitHubClientInner>);
+
+impl GitHubTraffic {
+	/// 获取流量接口数据, 需要登录
+	async fn get_traffic(
+		&self,
+		repo_path: &RepoPath,
+		kind: &str,
+		per: Option<TrafficPer>,
+	) -> Result<JsonValue> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let url =
+			format!("{}/repos/{}/{}/traffic/{}", api_url, repo_path.owner, repo_path.repo, kind);
+		let client = self.0.client.read().await;
+		let mut request = client.get(url).bearer_auth(token.as_ref().unwrap());
+		if let Some(per) = per {
+			request = request.query(&[("per", per.to_string())]);
+		}
+		let res = request.send().await?.error_for_status()?.json::<JsonValue>().await?;
+		Ok(res)
+	}
+}
+
+#[async_trait]
+impl Traffic for GitHubTraffic {
+	async fn views(&self, repo_path: RepoPath, per: TrafficPer) -> Result<TrafficSeries> {
+		let res = self.get_traffic(&repo_path, "views", Some(per)).await?;
+		Ok(parse_series(res, "views"))
+	}
+
+	async fn clones(&self, repo_path: RepoPath, per: TrafficPer) -> Result<TrafficSeries> {
+		let res = self.get_traffic(&repo_path, "clones", Some(per)).await?;
+		Ok(parse_series(res, "clones"))
+	}
+
+	async fn referrers(&self, repo_path: RepoPath) -> Result<Vec<TrafficReferrer>> {
+		let res = self.get_traffic(&repo_path, "popular/referrers", None).await?;
+		Ok(res.into_iter().map(|v| v.into()).collect())
+	}
+
+	async fn paths(&self, repo_path: RepoPath) -> Result<Vec<TrafficPath>> {
+		let res = self.get_traffic(&repo_path, "popular/paths", None).await?;
+		Ok(res.into_iter().map(|v| v.into()).collect())
+	}
+}
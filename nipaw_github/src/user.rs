@@ -1,17 +1,60 @@
 use crate::GitHubClientInner;
 use async_trait::async_trait;
 use crate::common::{Html, JsonValue};
+use http::header;
 use nipaw_core::option::repo::ListOptions;
 use nipaw_core::types::{
+	org::OrgInfo,
 	repo::RepoInfo,
 	user::{ContributionResult, UserInfo},
 };
 use nipaw_core::{Error, Result, User};
+use reqwest::Method;
 use std::collections::HashMap;
 use std::sync::Arc;
 
 pub struct GitHubUser(pub(crate) Arc<GitHubClientInner>);
 
+impl GitHubUser {
+	/// 发送不关心响应内容的请求, 需要登录
+	async fn send_empty(&self, method: Method, url: String) -> Result<()> {
+		let token = &self.0.config.token;
+		if token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let client = self.0.client.read().await;
+		let mut request = client.request(method.clone(), url).bearer_auth(token.as_ref().unwrap());
+		if method != Method::GET {
+			request = request.header(header::CONTENT_LENGTH, 0);
+		}
+		request.send().await?.error_for_status()?;
+		Ok(())
+	}
+
+	/// 获取分页的用户列表
+	async fn get_user_list(
+		&self,
+		url: String,
+		option: Option<ListOptions>,
+	) -> Result<Vec<UserInfo>> {
+		let token = &self.0.config.token;
+		let client = self.0.client.read().await;
+		let mut request = client.get(url);
+		if let Some(token) = token {
+			request = request.bearer_auth(token);
+		}
+		let mut params: HashMap<&str, String> = HashMap::new();
+		if let Some(option) = option {
+			let per_page = option.per_page.unwrap_or(30).min(100);
+			params.insert("per_page", per_page.to_string());
+			let page = option.page.unwrap_or(1);
+			params.insert("page", page.to_string());
+		}
+		let user_infos = request.query(&params).send().await?.json::<Vec<JsonValue>>().await?;
+		Ok(user_infos.into_iter().map(|v| v.into()).collect())
+	}
+}
+
 #[async_trait]
 impl User for GitHubUser {
 	async fn info(&self, user_name: Option<&str>) -> Result<UserInfo> {
@@ -41,7 +84,7 @@ impl User for GitHubUser {
 		if let Some(user_name) = user_name {
 			let url = format!("{}/{}", base_url, user_name);
 			let client = self.0.client.read().await;
-			let request = client.get(url).header("Accept", "image/*");
+			let request = client.get(url);
 			let resp = request.send().await?;
 			let html: Html = Html::from(resp.text().await?);
 			let document = scraper::Html::parse_document(&html.0);
@@ -119,4 +162,117 @@ impl User for GitHubUser {
 		let repo_infos: Vec<JsonValue> = resp.json().await?;
 		Ok(repo_infos.into_iter().map(|v| v.into()).collect())
 	}
+
+	async fn starred_repos(
+		&self,
+		user_name: Option<&str>,
+		option: Option<ListOptions>,
+	) -> Result<Vec<RepoInfo>> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		if token.is_none() && user_name.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let url = if let Some(name) = user_name {
+			format!("{}/users/{}/starred", api_url, name)
+		} else {
+			format!("{}/user/starred", api_url)
+		};
+		let client = self.0.client.read().await;
+		let mut request = client.get(url);
+		if let Some(token) = token {
+			request = request.bearer_auth(token);
+		}
+		let mut params: HashMap<&str, String> = HashMap::new();
+		if let Some(option) = option {
+			let per_page = option.per_page.unwrap_or(30).min(100);
+			params.insert("per_page", per_page.to_string());
+			let page = option.page.unwrap_or(1);
+			params.insert("page", page.to_string());
+		}
+		let repo_infos = request.query(&params).send().await?.json::<Vec<JsonValue>>().await?;
+		Ok(repo_infos.into_iter().map(|v| v.into()).collect())
+	}
+
+	async fn followers(
+		&self,
+		user_name: Option<&str>,
+		option: Option<ListOptions>,
+	) -> Result<Vec<UserInfo>> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		if token.is_none() && user_name.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let url = if let Some(name) = user_name {
+			format!("{}/users/{}/followers", api_url, name)
+		} else {
+			format!("{}/user/followers", api_url)
+		};
+		self.get_user_list(url, option).await
+	}
+
+	async fn following(
+		&self,
+		user_name: Option<&str>,
+		option: Option<ListOptions>,
+	) -> Result<Vec<UserInfo>> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		if token.is_none() && user_name.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let url = if let Some(name) = user_name {
+			format!("{}/users/{}/following", api_url, name)
+		} else {
+			format!("{}/user/following", api_url)
+		};
+		self.get_user_list(url, option).await
+	}
+
+	async fn follow(&self, user_name: &str) -> Result<()> {
+		let url = format!("{}/user/following/{}", self.0.config.api_url, user_name);
+		self.send_empty(Method::PUT, url).await
+	}
+
+	async fn unfollow(&self, user_name: &str) -> Result<()> {
+		let url = format!("{}/user/following/{}", self.0.config.api_url, user_name);
+		self.send_empty(Method::DELETE, url).await
+	}
+
+	async fn is_following(&self, user_name: &str) -> Result<bool> {
+		let url = format!("{}/user/following/{}", self.0.config.api_url, user_name);
+		match self.send_empty(Method::GET, url).await {
+			Ok(()) => Ok(true),
+			Err(err) if err.is_not_found() => Ok(false),
+			Err(err) => Err(err),
+		}
+	}
+
+	async fn org_list(
+		&self,
+		user_name: Option<&str>,
+		option: Option<ListOptions>,
+	) -> Result<Vec<OrgInfo>> {
+		let (token, api_url) = (&self.0.config.token, &self.0.config.api_url);
+		if token.is_none() && user_name.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let url = if let Some(name) = user_name {
+			format!("{}/users/{}/orgs", api_url, name)
+		} else {
+			format!("{}/user/orgs", api_url)
+		};
+		let client = self.0.client.read().await;
+		let mut request = client.get(url);
+		if let Some(token) = token {
+			request = request.bearer_auth(token);
+		}
+		let mut params: HashMap<&str, String> = HashMap::new();
+		if let Some(option) = option {
+			let per_page = option.per_page.unwrap_or(30).min(100);
+			params.insert("per_page", per_page.to_string());
+			let page = option.page.unwrap_or(1);
+			params.insert("page", page.to_string());
+		}
+		let org_infos = request.query(&params).send().await?.json::<Vec<JsonValue>>().await?;
+		Ok(org_infos.into_iter().map(|v| v.into()).collect())
+	}
 }
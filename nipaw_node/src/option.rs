@@ -6,3 +6,17 @@ mod issue;
 pub(crate) use issue::*;
 mod release;
 pub(crate) use release::*;
+mod search;
+pub(crate) use search::*;
+mod status;
+pub(crate) use status::*;
+mod stats;
+pub(crate) use stats::*;
+mod notification;
+pub(crate) use notification::*;
+mod gist;
+pub(crate) use gist::*;
+mod pipeline;
+pub(crate) use pipeline::*;
+mod git;
+pub(crate) use git::*;
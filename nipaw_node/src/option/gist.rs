@@ -0,0 +1,42 @@
+use napi_derive::napi;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[napi(object)]
+pub struct CreateGistOptions {
+	/// 代码片段描述
+	pub description: Option<String>,
+	/// 是否公开, 默认为`false`
+	pub public: Option<bool>,
+	/// 文件名与文件内容, 至少包含一个文件
+	pub files: HashMap<String, String>,
+}
+
+impl From<CreateGistOptions> for nipaw_core::option::gist::CreateOptions {
+	fn from(value: CreateGistOptions) -> Self {
+		nipaw_core::option::gist::CreateOptions {
+			description: value.description,
+			public: value.public,
+			files: value.files,
+		}
+	}
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[napi(object)]
+pub struct UpdateGistOptions {
+	/// 代码片段描述
+	pub description: Option<String>,
+	/// 需要修改的文件名与新内容, 内容为`null`时删除该文件
+	pub files: HashMap<String, Option<String>>,
+}
+
+impl From<UpdateGistOptions> for nipaw_core::option::gist::UpdateOptions {
+	fn from(value: UpdateGistOptions) -> Self {
+		nipaw_core::option::gist::UpdateOptions {
+			description: value.description,
+			files: value.files,
+		}
+	}
+}
@@ -0,0 +1,47 @@
+use napi_derive::napi;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[napi(object)]
+pub struct TreeEntryOptions {
+	/// 相对于树根的路径
+	pub path: String,
+	/// 文件模式, 默认`100644`
+	pub mode: Option<String>,
+	/// 文件内容, 与`sha`二选一
+	pub content: Option<String>,
+	/// 已有对象的SHA, 与`content`二选一, 两者都为空时表示删除该路径
+	pub sha: Option<String>,
+}
+
+impl From<TreeEntryOptions> for nipaw_core::option::git::TreeEntryOptions {
+	fn from(value: TreeEntryOptions) -> Self {
+		nipaw_core::option::git::TreeEntryOptions {
+			path: value.path,
+			mode: value.mode,
+			content: value.content,
+			sha: value.sha,
+		}
+	}
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[napi(object)]
+pub struct FileChange {
+	/// 文件路径
+	pub path: String,
+	/// 新的文本内容, 与`bytes`都为空时删除该文件
+	pub content: Option<String>,
+	/// 新的二进制内容, 优先于`content`
+	pub bytes: Option<Vec<u8>>,
+}
+
+impl From<FileChange> for nipaw_core::option::git::FileChange {
+	fn from(value: FileChange) -> Self {
+		let content = match value.bytes {
+			Some(bytes) => Some(bytes.into()),
+			None => value.content.map(Into::into),
+		};
+		nipaw_core::option::git::FileChange { path: value.path, content }
+	}
+}
@@ -69,3 +69,18 @@ impl From<UpdateIssueOptions> for nipaw_core::option::issue::UpdateOptions {
 		}
 	}
 }
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[napi(object)]
+pub struct IssueEventListOptions {
+	/// 每页数量，默认 30，最大 100
+	pub per_page: Option<u32>,
+	/// 页码，默认 1
+	pub page: Option<u32>,
+}
+
+impl From<IssueEventListOptions> for nipaw_core::option::issue::EventListOptions {
+	fn from(value: IssueEventListOptions) -> Self {
+		nipaw_core::option::issue::EventListOptions { per_page: value.per_page, page: value.page }
+	}
+}
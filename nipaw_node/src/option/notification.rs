@@ -0,0 +1,34 @@
+use crate::types::repo::RepoPath;
+use chrono::{DateTime, Utc};
+use napi_derive::napi;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[napi(object)]
+pub struct NotificationListOptions {
+	/// 每页数量，默认 30，最大 100
+	pub per_page: Option<u32>,
+	/// 页码，默认 1
+	pub page: Option<u32>,
+	/// 是否只获取未读通知, 默认为`true`
+	pub unread: Option<bool>,
+	/// 是否只获取直接参与或被提及的通知
+	pub participating: Option<bool>,
+	/// 只获取此时间之后更新的通知
+	pub since: Option<DateTime<Utc>>,
+	/// 只获取此仓库的通知
+	pub repo_path: Option<RepoPath>,
+}
+
+impl From<NotificationListOptions> for nipaw_core::option::notification::ListOptions {
+	fn from(value: NotificationListOptions) -> Self {
+		nipaw_core::option::notification::ListOptions {
+			per_page: value.per_page,
+			page: value.page,
+			unread: value.unread,
+			participating: value.participating,
+			since: value.since,
+			repo_path: value.repo_path.map(|v| v.into()),
+		}
+	}
+}
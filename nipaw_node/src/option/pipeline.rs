@@ -0,0 +1,49 @@
+use crate::types::pipeline::PipelineStatus;
+use napi_derive::napi;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[napi(object)]
+pub struct PipelineListOptions {
+	/// 每页数量，默认 30，最大 100
+	pub per_page: Option<u32>,
+	/// 页码，默认 1
+	pub page: Option<u32>,
+	/// 筛选分支
+	pub branch: Option<String>,
+	/// 筛选状态
+	pub status: Option<PipelineStatus>,
+	/// 筛选触发事件, 如`push`、`pull_request`
+	pub event: Option<String>,
+}
+
+impl From<PipelineListOptions> for nipaw_core::option::pipeline::ListOptions {
+	fn from(value: PipelineListOptions) -> Self {
+		nipaw_core::option::pipeline::ListOptions {
+			per_page: value.per_page,
+			page: value.page,
+			branch: value.branch,
+			status: value.status.map(|s| s.into()),
+			event: value.event,
+		}
+	}
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[napi(object)]
+pub struct TriggerPipelineOptions {
+	/// 触发的分支或标签
+	pub reference: String,
+	/// 输入参数, GitHub为工作流的`inputs`, CNB为构建的环境变量
+	pub inputs: Option<HashMap<String, String>>,
+}
+
+impl From<TriggerPipelineOptions> for nipaw_core::option::pipeline::TriggerOptions {
+	fn from(value: TriggerPipelineOptions) -> Self {
+		nipaw_core::option::pipeline::TriggerOptions {
+			reference: value.reference,
+			inputs: value.inputs.unwrap_or_default(),
+		}
+	}
+}
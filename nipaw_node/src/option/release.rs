@@ -1,5 +1,14 @@
+use crate::types::release::DownloadProgress;
+use napi::Status;
+use napi::threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode};
 use napi_derive::napi;
 use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::Arc;
+
+/// 下载进度回调, 参数为[DownloadProgress]
+pub type DownloadProgressCallback =
+	ThreadsafeFunction<DownloadProgress, (), DownloadProgress, Status, false>;
 
 #[derive(Debug, Deserialize, Serialize, Default)]
 #[napi(object)]
@@ -15,3 +24,51 @@ impl From<UpdateReleaseOptions> for nipaw_core::option::release::UpdateOption {
 		nipaw_core::option::release::UpdateOption { name: value.name, body: value.body }
 	}
 }
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[napi(object)]
+pub struct DownloadAssetOptions {
+	/// 期望的SHA-256摘要, 16进制
+	pub sha256: Option<String>,
+	/// 是否使用同一Release中的`<附件名>.sha256`附件校验, 设置`sha256`时忽略
+	pub verify_sibling: Option<bool>,
+	/// 续传起点, 设置时先将文件截断到该长度, 为0时重新下载; 不设置时从文件末尾续传
+	pub offset: Option<i64>,
+}
+
+impl From<DownloadAssetOptions> for nipaw_core::option::release::DownloadOptions {
+	fn from(value: DownloadAssetOptions) -> Self {
+		let checksum = match (value.sha256, value.verify_sibling) {
+			(Some(digest), _) => Some(nipaw_core::option::release::Checksum::Sha256(digest)),
+			(None, Some(true)) => Some(nipaw_core::option::release::Checksum::Sibling),
+			_ => None,
+		};
+		nipaw_core::option::release::DownloadOptions { checksum, ..Default::default() }
+	}
+}
+
+/// 转换下载选项, 设置`offset`时先截断文件, 设置`progress`时转发下载进度
+pub(crate) async fn download_options(
+	options: Option<DownloadAssetOptions>,
+	path: &Path,
+	progress: Option<DownloadProgressCallback>,
+) -> nipaw_core::Result<nipaw_core::option::release::DownloadOptions> {
+	let options = options.unwrap_or_default();
+	if let Some(offset) = options.offset {
+		let file = napi::tokio::fs::OpenOptions::new()
+			.create(true)
+			.truncate(false)
+			.write(true)
+			.open(path)
+			.await?;
+		let len = file.metadata().await?.len();
+		file.set_len(len.min(offset.max(0) as u64)).await?;
+	}
+	let mut options: nipaw_core::option::release::DownloadOptions = options.into();
+	if let Some(progress) = progress {
+		options.progress = Some(Arc::new(move |value| {
+			progress.call(value.into(), ThreadsafeFunctionCallMode::NonBlocking);
+		}));
+	}
+	Ok(options)
+}
@@ -1,3 +1,4 @@
+use crate::types::repo::RepoVisibility;
 use napi_derive::napi;
 use serde::{Deserialize, Serialize};
 
@@ -15,3 +16,69 @@ impl From<RepoListOptions> for nipaw_core::option::repo::ListOptions {
 		nipaw_core::option::repo::ListOptions { per_page: value.per_page, page: value.page }
 	}
 }
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[napi(object)]
+pub struct CreateRepoOptions {
+	/// 仓库描述
+	pub description: Option<String>,
+	/// 主页地址
+	pub homepage: Option<String>,
+	/// 可见性, 默认为公开
+	pub visibility: Option<RepoVisibility>,
+	/// 是否使用README初始化仓库
+	pub auto_init: Option<bool>,
+	/// .gitignore模板名称
+	pub gitignore_template: Option<String>,
+	/// 开源许可证模板名称
+	pub license_template: Option<String>,
+}
+
+impl From<CreateRepoOptions> for nipaw_core::option::repo::CreateOptions {
+	fn from(value: CreateRepoOptions) -> Self {
+		nipaw_core::option::repo::CreateOptions {
+			description: value.description,
+			homepage: value.homepage,
+			visibility: value.visibility.map(|v| v.into()),
+			auto_init: value.auto_init,
+			gitignore_template: value.gitignore_template,
+			license_template: value.license_template,
+		}
+	}
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[napi(object)]
+pub struct UpdateRepoOptions {
+	/// 仓库名称
+	pub name: Option<String>,
+	/// 仓库描述
+	pub description: Option<String>,
+	/// 主页地址
+	pub homepage: Option<String>,
+	/// 默认分支
+	pub default_branch: Option<String>,
+	/// 可见性
+	pub visibility: Option<RepoVisibility>,
+	/// 是否启用issue
+	pub has_issues: Option<bool>,
+	/// 是否启用wiki
+	pub has_wiki: Option<bool>,
+	/// 是否启用项目看板
+	pub has_projects: Option<bool>,
+}
+
+impl From<UpdateRepoOptions> for nipaw_core::option::repo::UpdateOptions {
+	fn from(value: UpdateRepoOptions) -> Self {
+		nipaw_core::option::repo::UpdateOptions {
+			name: value.name,
+			description: value.description,
+			homepage: value.homepage,
+			default_branch: value.default_branch,
+			visibility: value.visibility.map(|v| v.into()),
+			has_issues: value.has_issues,
+			has_wiki: value.has_wiki,
+			has_projects: value.has_projects,
+		}
+	}
+}
@@ -0,0 +1,87 @@
+use crate::types::issue::StateType;
+use napi_derive::napi;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[napi(object)]
+pub struct SearchQuery {
+	/// 关键字
+	pub keywords: Vec<String>,
+	/// 编程语言
+	pub language: Option<String>,
+	/// 用户或组织
+	pub owner: Option<String>,
+	/// 议题状态, 仅搜索议题时有效
+	pub state: Option<StateType>,
+	/// 排序字段, 平台不支持的字段会被忽略
+	pub sort: Option<SearchSort>,
+	/// 排序方向
+	pub order: Option<SearchOrder>,
+	/// 每页数量，默认 30，最大 100
+	pub per_page: Option<u32>,
+	/// 页码，默认 1
+	pub page: Option<u32>,
+}
+
+impl From<SearchQuery> for nipaw_core::option::search::SearchQuery {
+	fn from(value: SearchQuery) -> Self {
+		nipaw_core::option::search::SearchQuery {
+			keywords: value.keywords,
+			language: value.language,
+			owner: value.owner,
+			state: value.state.map(|s| s.into()),
+			sort: value.sort.map(|s| s.into()),
+			order: value.order.map(|o| o.into()),
+			per_page: value.per_page,
+			page: value.page,
+		}
+	}
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[napi]
+pub enum SearchSort {
+	/// 星标数, 仅仓库
+	Stars,
+	/// fork数, 仅仓库
+	Forks,
+	/// 评论数, 仅议题
+	Comments,
+	/// 关注者数, 仅用户
+	Followers,
+	/// 创建时间
+	Created,
+	/// 更新时间
+	Updated,
+}
+
+impl From<SearchSort> for nipaw_core::option::search::SearchSort {
+	fn from(value: SearchSort) -> Self {
+		match value {
+			SearchSort::Stars => nipaw_core::option::search::SearchSort::Stars,
+			SearchSort::Forks => nipaw_core::option::search::SearchSort::Forks,
+			SearchSort::Comments => nipaw_core::option::search::SearchSort::Comments,
+			SearchSort::Followers => nipaw_core::option::search::SearchSort::Followers,
+			SearchSort::Created => nipaw_core::option::search::SearchSort::Created,
+			SearchSort::Updated => nipaw_core::option::search::SearchSort::Updated,
+		}
+	}
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[napi]
+pub enum SearchOrder {
+	/// 升序
+	Asc,
+	/// 降序
+	Desc,
+}
+
+impl From<SearchOrder> for nipaw_core::option::search::SearchOrder {
+	fn from(value: SearchOrder) -> Self {
+		match value {
+			SearchOrder::Asc => nipaw_core::option::search::SearchOrder::Asc,
+			SearchOrder::Desc => nipaw_core::option::search::SearchOrder::Desc,
+		}
+	}
+}
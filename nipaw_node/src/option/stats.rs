@@ -0,0 +1,18 @@
+use chrono::{DateTime, Utc};
+use napi_derive::napi;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[napi(object)]
+pub struct StatsOptions {
+	/// 统计开始时间, 默认为结束时间前52周
+	pub since: Option<DateTime<Utc>>,
+	/// 统计结束时间, 默认为当前时间
+	pub until: Option<DateTime<Utc>>,
+}
+
+impl From<StatsOptions> for nipaw_core::option::stats::StatsOptions {
+	fn from(value: StatsOptions) -> Self {
+		nipaw_core::option::stats::StatsOptions { since: value.since, until: value.until }
+	}
+}
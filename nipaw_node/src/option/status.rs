@@ -0,0 +1,38 @@
+use napi_derive::napi;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[napi(object)]
+pub struct CreateStatusOptions {
+	/// 状态的上下文标识, 默认为`default`
+	pub context: Option<String>,
+	/// 状态描述
+	pub description: Option<String>,
+	/// 详情链接
+	pub target_url: Option<String>,
+}
+
+impl From<CreateStatusOptions> for nipaw_core::option::status::CreateOptions {
+	fn from(value: CreateStatusOptions) -> Self {
+		nipaw_core::option::status::CreateOptions {
+			context: value.context,
+			description: value.description,
+			target_url: value.target_url,
+		}
+	}
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[napi(object)]
+pub struct StatusListOptions {
+	/// 每页数量，默认 30，最大 100
+	pub per_page: Option<u32>,
+	/// 页码，默认 1
+	pub page: Option<u32>,
+}
+
+impl From<StatusListOptions> for nipaw_core::option::status::ListOptions {
+	fn from(value: StatusListOptions) -> Self {
+		nipaw_core::option::status::ListOptions { per_page: value.per_page, page: value.page }
+	}
+}
@@ -86,6 +86,66 @@ macro_rules! impl_client {
 				pub fn release(&self) -> [<$client_type Release>] {
 					[<$client_type Release>]
 				}
+
+				/// 获取状态操作模块
+				#[napi]
+				pub fn status(&self) -> [<$client_type Status>] {
+					[<$client_type Status>]
+				}
+
+				/// 获取搜索操作模块
+				#[napi]
+				pub fn search(&self) -> [<$client_type Search>] {
+					[<$client_type Search>]
+				}
+
+				/// 获取SSH与GPG公钥操作模块
+				#[napi]
+				pub fn key(&self) -> [<$client_type Key>] {
+					[<$client_type Key>]
+				}
+
+				/// 获取仓库提交统计操作模块
+				#[napi]
+				pub fn stats(&self) -> [<$client_type Stats>] {
+					[<$client_type Stats>]
+				}
+
+				/// 获取仓库流量统计操作模块
+				#[napi]
+				pub fn traffic(&self) -> [<$client_type Traffic>] {
+					[<$client_type Traffic>]
+				}
+
+				/// 获取当前用户的通知操作模块
+				#[napi]
+				pub fn notifications(&self) -> [<$client_type Notifications>] {
+					[<$client_type Notifications>]
+				}
+
+				/// 获取代码片段操作模块
+				#[napi]
+				pub fn gist(&self) -> [<$client_type Gist>] {
+					[<$client_type Gist>]
+				}
+
+				/// 获取流水线操作模块
+				#[napi]
+				pub fn pipeline(&self) -> [<$client_type Pipeline>] {
+					[<$client_type Pipeline>]
+				}
+
+				/// 获取密钥操作模块
+				#[napi]
+				pub fn secret(&self) -> [<$client_type Secret>] {
+					[<$client_type Secret>]
+				}
+
+				/// 获取Git数据操作模块
+				#[napi]
+				pub fn git(&self) -> [<$client_type Git>] {
+					[<$client_type Git>]
+				}
 			}
 		}
 	};
@@ -149,6 +209,114 @@ macro_rules! impl_user {
 					let repo_infos = client.user().repo_list(user_name.as_deref(), options.map(|o| o.into())).await?;
 					Ok(repo_infos.into_iter().map(|v| v.into()).collect())
 				}
+
+				/// 获取用户星标的仓库列表
+				///
+				/// ## 参数
+				/// - `user_name` 用户名称, 为空时获取当前登录用户星标的仓库列表
+				/// - `options` 仓库列表选项
+				#[napi]
+				pub async fn starred_repos(
+					&self,
+					user_name: Option<String>,
+					options: Option<RepoListOptions>,
+				) -> Result<Vec<RepoInfo>> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let repo_infos = client
+						.user()
+						.starred_repos(user_name.as_deref(), options.map(|o| o.into()))
+						.await?;
+					Ok(repo_infos.into_iter().map(|v| v.into()).collect())
+				}
+
+				/// 获取用户所属的组织列表
+				///
+				/// ## 参数
+				/// - `user_name` 用户名称, 为空时获取当前登录用户所属的组织列表
+				/// - `options` 列表选项
+				#[napi]
+				pub async fn org_list(
+					&self,
+					user_name: Option<String>,
+					options: Option<RepoListOptions>,
+				) -> Result<Vec<OrgInfo>> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let org_infos = client
+						.user()
+						.org_list(user_name.as_deref(), options.map(|o| o.into()))
+						.await?;
+					Ok(org_infos.into_iter().map(|v| v.into()).collect())
+				}
+
+				/// 获取用户的关注者列表
+				///
+				/// ## 参数
+				/// - `user_name` 用户名称, 为空时获取当前登录用户的关注者列表
+				/// - `options` 列表选项
+				#[napi]
+				pub async fn followers(
+					&self,
+					user_name: Option<String>,
+					options: Option<RepoListOptions>,
+				) -> Result<Vec<UserInfo>> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let user_infos = client
+						.user()
+						.followers(user_name.as_deref(), options.map(|o| o.into()))
+						.await?;
+					Ok(user_infos.into_iter().map(|v| v.into()).collect())
+				}
+
+				/// 获取用户正在关注的用户列表
+				///
+				/// ## 参数
+				/// - `user_name` 用户名称, 为空时获取当前登录用户的关注列表
+				/// - `options` 列表选项
+				#[napi]
+				pub async fn following(
+					&self,
+					user_name: Option<String>,
+					options: Option<RepoListOptions>,
+				) -> Result<Vec<UserInfo>> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let user_infos = client
+						.user()
+						.following(user_name.as_deref(), options.map(|o| o.into()))
+						.await?;
+					Ok(user_infos.into_iter().map(|v| v.into()).collect())
+				}
+
+				/// 关注用户
+				///
+				/// ## 参数
+				/// - `user_name` 用户名称
+				#[napi]
+				pub async fn follow(&self, user_name: String) -> Result<()> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					client.user().follow(&user_name).await?;
+					Ok(())
+				}
+
+				/// 取消关注用户
+				///
+				/// ## 参数
+				/// - `user_name` 用户名称
+				#[napi]
+				pub async fn unfollow(&self, user_name: String) -> Result<()> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					client.user().unfollow(&user_name).await?;
+					Ok(())
+				}
+
+				/// 当前登录用户是否已关注该用户
+				///
+				/// ## 参数
+				/// - `user_name` 用户名称
+				#[napi]
+				pub async fn is_following(&self, user_name: String) -> Result<bool> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					Ok(client.user().is_following(&user_name).await?)
+				}
 			}
 		}
 	};
@@ -201,6 +369,161 @@ macro_rules! impl_org {
 					let repo_infos = client.org().repo_list(org_name.as_str(), options.map(|o| o.into())).await?;
 					Ok(repo_infos.into_iter().map(|v| v.into()).collect())
 				}
+
+				/// 获取组织成员列表
+				///
+				/// ## 参数
+				/// - `org_name` 组织名称
+				/// - `options` 列表选项
+				#[napi]
+				pub async fn members(
+					&self,
+					org_name: String,
+					options: Option<RepoListOptions>,
+				) -> Result<Vec<OrgMember>> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let members = client.org().members(&org_name, options.map(|o| o.into())).await?;
+					Ok(members.into_iter().map(|v| v.into()).collect())
+				}
+
+				/// 添加或邀请用户加入组织
+				///
+				/// ## 参数
+				/// - `org_name` 组织名称
+				/// - `user_name` 用户名称
+				/// - `role` 成员角色, 默认为 `Member`
+				#[napi]
+				pub async fn add_member(
+					&self,
+					org_name: String,
+					user_name: String,
+					role: Option<MemberRole>,
+				) -> Result<()> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					client.org().add_member(&org_name, &user_name, role.map(|r| r.into())).await?;
+					Ok(())
+				}
+
+				/// 将用户移出组织
+				///
+				/// ## 参数
+				/// - `org_name` 组织名称
+				/// - `user_name` 用户名称
+				#[napi]
+				pub async fn remove_member(
+					&self,
+					org_name: String,
+					user_name: String,
+				) -> Result<()> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					client.org().remove_member(&org_name, &user_name).await?;
+					Ok(())
+				}
+
+				/// 用户是否为组织成员
+				///
+				/// ## 参数
+				/// - `org_name` 组织名称
+				/// - `user_name` 用户名称
+				#[napi]
+				pub async fn is_member(&self, org_name: String, user_name: String) -> Result<bool> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					Ok(client.org().is_member(&org_name, &user_name).await?)
+				}
+
+				/// 获取组织的团队列表
+				///
+				/// ## 参数
+				/// - `org_name` 组织名称
+				/// - `options` 列表选项
+				#[napi]
+				pub async fn teams(
+					&self,
+					org_name: String,
+					options: Option<RepoListOptions>,
+				) -> Result<Vec<TeamInfo>> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let teams = client.org().teams(&org_name, options.map(|o| o.into())).await?;
+					Ok(teams.into_iter().map(|v| v.into()).collect())
+				}
+
+				/// 获取团队成员列表
+				///
+				/// ## 参数
+				/// - `org_name` 组织名称
+				/// - `team_slug` 团队标识
+				/// - `options` 列表选项
+				#[napi]
+				pub async fn team_members(
+					&self,
+					org_name: String,
+					team_slug: String,
+					options: Option<RepoListOptions>,
+				) -> Result<Vec<UserInfo>> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let user_infos = client
+						.org()
+						.team_members(&org_name, &team_slug, options.map(|o| o.into()))
+						.await?;
+					Ok(user_infos.into_iter().map(|v| v.into()).collect())
+				}
+
+				/// 添加用户到团队
+				///
+				/// ## 参数
+				/// - `org_name` 组织名称
+				/// - `team_slug` 团队标识
+				/// - `user_name` 用户名称
+				#[napi]
+				pub async fn add_team_member(
+					&self,
+					org_name: String,
+					team_slug: String,
+					user_name: String,
+				) -> Result<()> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					client.org().add_team_member(&org_name, &team_slug, &user_name).await?;
+					Ok(())
+				}
+
+				/// 将用户移出团队
+				///
+				/// ## 参数
+				/// - `org_name` 组织名称
+				/// - `team_slug` 团队标识
+				/// - `user_name` 用户名称
+				#[napi]
+				pub async fn remove_team_member(
+					&self,
+					org_name: String,
+					team_slug: String,
+					user_name: String,
+				) -> Result<()> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					client.org().remove_team_member(&org_name, &team_slug, &user_name).await?;
+					Ok(())
+				}
+
+				/// 获取团队可访问的仓库及权限
+				///
+				/// ## 参数
+				/// - `org_name` 组织名称
+				/// - `team_slug` 团队标识
+				/// - `options` 列表选项
+				#[napi]
+				pub async fn team_repos(
+					&self,
+					org_name: String,
+					team_slug: String,
+					options: Option<RepoListOptions>,
+				) -> Result<Vec<TeamRepoInfo>> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let team_repos = client
+						.org()
+						.team_repos(&org_name, &team_slug, options.map(|o| o.into()))
+						.await?;
+					Ok(team_repos.into_iter().map(|v| v.into()).collect())
+				}
 			}
 		}
 	};
@@ -252,238 +575,1848 @@ macro_rules! impl_repo {
 						.await?;
 					Ok(collaborator_result.into())
 				}
-			}
-		}
-	};
-}
-pub(crate) use impl_repo;
-
-macro_rules! impl_commit {
-	($client_type:ident) => {
-		paste! {
-			#[derive(Debug, Default)]
-			#[napi(constructor)]
-			pub struct [<$client_type Commit>];
 
-			#[napi]
-			impl [<$client_type Commit>] {
-				/// 获取仓库提交信息
+				/// 获取仓库协作者列表
 				///
 				/// ## 参数
-				/// - `owner` 仓库所有者
-				/// - `repo` 仓库名称
-				/// - `sha` 提交SHA, 如果不设置则会获取默认分支的最新提交
+				/// - `repo_path` 仓库路径
+				/// - `options` 列表选项
 				#[napi]
-				pub async fn info(
+				pub async fn collaborators(
 					&self,
 					repo_path: RepoPath,
-					sha: Option<String>,
-				) -> Result<CommitInfo> {
+					options: Option<RepoListOptions>,
+				) -> Result<Vec<CollaboratorInfo>> {
 					let client = [<create_client_ $client_type:lower>]().await;
-					let commit_info = client.commit().info(repo_path.into(), sha.as_deref()).await?;
-					Ok(commit_info.into())
+					let collaborators = client
+						.repo()
+						.collaborators(repo_path.into(), options.map(|o| o.into()))
+						.await?;
+					Ok(collaborators.into_iter().map(|v| v.into()).collect())
 				}
 
-				/// 获取仓库提交列表
+				/// 移除仓库协作者
 				///
 				/// ## 参数
-				/// - `owner` 仓库所有者
-				/// - `repo` 仓库名称
-				/// - `options` 提交列表选项
+				/// - `repo_path` 仓库路径
+				/// - `user_name` 协作者用户名
 				#[napi]
-				pub async fn list(
+				pub async fn remove_collaborator(
 					&self,
 					repo_path: RepoPath,
-					options: Option<CommitListOptions>,
-				) -> Result<Vec<CommitListInfo>> {
+					user_name: String,
+				) -> Result<()> {
 					let client = [<create_client_ $client_type:lower>]().await;
-					let commit_infos = client.commit().list(repo_path.into(), options.map(|o| o.into())).await?;
-					Ok(commit_infos.into_iter().map(|v| v.into()).collect())
+					client.repo().remove_collaborator(repo_path.into(), &user_name).await?;
+					Ok(())
 				}
-			}
-		}
-	};
-}
-pub(crate) use impl_commit;
-
-macro_rules! impl_release {
-	($client_type:ident) => {
-		paste! {
-			#[derive(Debug, Default)]
-			#[napi(constructor)]
-			pub struct [<$client_type Release>];
 
-			#[napi]
-			impl [<$client_type Release>] {
-				/// 创建一个Release
+				/// 获取用户在仓库中的权限
 				///
 				/// ## 参数
-				/// - `owner` 仓库所有者
-				/// - `repo` 仓库名称
-				/// - `tag_name` 标签名称
-				/// - `name` Release名称, 可选
-				/// - `body` Release内容, 可选
-				/// - `target_commitish` 目标提交SHA或分支, 可选
+				/// - `repo_path` 仓库路径
+				/// - `user_name` 用户名
 				#[napi]
-				pub async fn create(
+				pub async fn collaborator_permission(
 					&self,
 					repo_path: RepoPath,
-					tag_name: String,
-					name: Option<String>,
-					body: Option<String>,
-					target_commitish: Option<String>,
-				) -> Result<ReleaseInfo> {
+					user_name: String,
+				) -> Result<CollaboratorPermission> {
 					let client = [<create_client_ $client_type:lower>]().await;
-					let release_info = client.release().create(
-						repo_path.into(),
-						tag_name.as_str(),
-						name.as_deref(),
-						body.as_deref(),
-						target_commitish.as_deref(),
-					).await?;
-					Ok(release_info.into())
+					let permission =
+						client.repo().collaborator_permission(repo_path.into(), &user_name).await?;
+					Ok(permission.into())
 				}
 
-				/// 获取Release信息
+				/// 用户是否为仓库协作者
 				///
 				/// ## 参数
-				/// - `owner` 仓库所有者
-				/// - `repo` 仓库名称
-				/// - `tag_name` 标签名称, 可选, 为空时获取最新Release
+				/// - `repo_path` 仓库路径
+				/// - `user_name` 用户名
 				#[napi]
-				pub async fn info(
+				pub async fn is_collaborator(
 					&self,
 					repo_path: RepoPath,
-					tag_name: Option<String>,
-				) -> Result<ReleaseInfo> {
+					user_name: String,
+				) -> Result<bool> {
 					let client = [<create_client_ $client_type:lower>]().await;
-					let release_info = client.release().info(repo_path.into(), tag_name.as_deref()).await?;
-					Ok(release_info.into())
+					Ok(client.repo().is_collaborator(repo_path.into(), &user_name).await?)
 				}
 
-				/// 获取Release列表
+				/// 星标仓库
 				///
 				/// ## 参数
-				/// - `owner` 仓库所有者
-				/// - `repo` 仓库名称
+				/// - `repo_path` 仓库路径
 				#[napi]
-				pub async fn list(
+				pub async fn star(&self, repo_path: RepoPath) -> Result<()> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					client.repo().star(repo_path.into()).await?;
+					Ok(())
+				}
+
+				/// 取消星标仓库
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				#[napi]
+				pub async fn unstar(&self, repo_path: RepoPath) -> Result<()> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					client.repo().unstar(repo_path.into()).await?;
+					Ok(())
+				}
+
+				/// 当前登录用户是否已星标仓库
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				#[napi]
+				pub async fn is_starred(&self, repo_path: RepoPath) -> Result<bool> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					Ok(client.repo().is_starred(repo_path.into()).await?)
+				}
+
+				/// 获取仓库的星标用户列表
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				/// - `options` 列表选项
+				#[napi]
+				pub async fn stargazers(
 					&self,
 					repo_path: RepoPath,
-				) -> Result<Vec<ReleaseInfo>> {
+					options: Option<RepoListOptions>,
+				) -> Result<Vec<UserInfo>> {
 					let client = [<create_client_ $client_type:lower>]().await;
-					let release_infos = client.release().list(repo_path.into()).await?;
-					Ok(release_infos.into_iter().map(|v| v.into()).collect())
+					let user_infos =
+						client.repo().stargazers(repo_path.into(), options.map(|o| o.into())).await?;
+					Ok(user_infos.into_iter().map(|v| v.into()).collect())
 				}
 
-				/// 更新Release
+				/// 关注仓库
 				///
 				/// ## 参数
-				/// - `owner` 仓库所有者
-				/// - `repo` 仓库名称
-				/// - `tag_name` 标签名称
-				/// - `options` 更新参数
+				/// - `repo_path` 仓库路径
+				#[napi]
+				pub async fn watch(&self, repo_path: RepoPath) -> Result<()> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					client.repo().watch(repo_path.into()).await?;
+					Ok(())
+				}
+
+				/// 取消关注仓库
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				#[napi]
+				pub async fn unwatch(&self, repo_path: RepoPath) -> Result<()> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					client.repo().unwatch(repo_path.into()).await?;
+					Ok(())
+				}
+
+				/// 获取仓库的关注用户列表
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				/// - `options` 列表选项
+				#[napi]
+				pub async fn watchers(
+					&self,
+					repo_path: RepoPath,
+					options: Option<RepoListOptions>,
+				) -> Result<Vec<UserInfo>> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let user_infos =
+						client.repo().watchers(repo_path.into(), options.map(|o| o.into())).await?;
+					Ok(user_infos.into_iter().map(|v| v.into()).collect())
+				}
+
+				/// fork仓库
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				/// - `target_org` fork到的组织, 为空时fork到当前用户下
+				/// - `name` fork后的仓库名称, 为空时与原仓库相同
+				#[napi]
+				pub async fn fork(
+					&self,
+					repo_path: RepoPath,
+					target_org: Option<String>,
+					name: Option<String>,
+				) -> Result<RepoInfo> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let repo_info = client
+						.repo()
+						.fork(repo_path.into(), target_org.as_deref(), name.as_deref())
+						.await?;
+					Ok(repo_info.into())
+				}
+
+				/// 获取仓库的fork列表
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				/// - `options` 列表选项
+				#[napi]
+				pub async fn forks(
+					&self,
+					repo_path: RepoPath,
+					options: Option<RepoListOptions>,
+				) -> Result<Vec<RepoInfo>> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let repo_infos =
+						client.repo().forks(repo_path.into(), options.map(|o| o.into())).await?;
+					Ok(repo_infos.into_iter().map(|v| v.into()).collect())
+				}
+
+				/// 将fork仓库的分支与上游仓库同步
+				///
+				/// ## 参数
+				/// - `repo_path` fork仓库路径
+				/// - `branch` 需要同步的分支, 为空时使用默认分支
+				#[napi]
+				pub async fn sync_fork(
+					&self,
+					repo_path: RepoPath,
+					branch: Option<String>,
+				) -> Result<SyncForkResult> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let result =
+						client.repo().sync_fork(repo_path.into(), branch.as_deref()).await?;
+					Ok(result.into())
+				}
+
+				/// 创建仓库
+				///
+				/// ## 参数
+				/// - `org_name` 组织名称, 为空时创建在当前登录用户下
+				/// - `name` 仓库名称
+				/// - `options` 创建仓库选项
+				#[napi]
+				pub async fn create(
+					&self,
+					org_name: Option<String>,
+					name: String,
+					options: Option<CreateRepoOptions>,
+				) -> Result<RepoInfo> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let repo_info = client
+						.repo()
+						.create(org_name.as_deref(), &name, options.map(|o| o.into()))
+						.await?;
+					Ok(repo_info.into())
+				}
+
+				/// 更新仓库设置
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				/// - `options` 更新仓库选项
 				#[napi]
 				pub async fn update(
 					&self,
 					repo_path: RepoPath,
-					tag_name: String,
-					options: UpdateReleaseOptions,
-				) -> Result<ReleaseInfo> {
+					options: UpdateRepoOptions,
+				) -> Result<RepoInfo> {
 					let client = [<create_client_ $client_type:lower>]().await;
-					let release_info = client.release().update(repo_path.into(), tag_name.as_str(), options.into()).await?;
-					Ok(release_info.into())
+					let repo_info = client.repo().update(repo_path.into(), options.into()).await?;
+					Ok(repo_info.into())
+				}
+
+				/// 删除仓库
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				#[napi]
+				pub async fn delete(&self, repo_path: RepoPath) -> Result<()> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					client.repo().delete(repo_path.into()).await?;
+					Ok(())
+				}
+
+				/// 归档或取消归档仓库
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				/// - `archived` 是否归档
+				#[napi]
+				pub async fn set_archived(&self, repo_path: RepoPath, archived: bool) -> Result<()> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					client.repo().set_archived(repo_path.into(), archived).await?;
+					Ok(())
+				}
+
+				/// 转移仓库
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				/// - `new_owner` 新的所有者
+				/// - `new_name` 转移后的仓库名称, 为空时保持不变
+				#[napi]
+				pub async fn transfer(
+					&self,
+					repo_path: RepoPath,
+					new_owner: String,
+					new_name: Option<String>,
+				) -> Result<RepoInfo> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let repo_info = client
+						.repo()
+						.transfer(repo_path.into(), &new_owner, new_name.as_deref())
+						.await?;
+					Ok(repo_info.into())
+				}
+
+				/// 获取仓库部署公钥列表
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				/// - `options` 列表选项
+				#[napi]
+				pub async fn deploy_keys(
+					&self,
+					repo_path: RepoPath,
+					options: Option<RepoListOptions>,
+				) -> Result<Vec<DeployKeyInfo>> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let deploy_keys = client
+						.repo()
+						.deploy_keys(repo_path.into(), options.map(|o| o.into()))
+						.await?;
+					Ok(deploy_keys.into_iter().map(|v| v.into()).collect())
+				}
+
+				/// 添加仓库部署公钥, 发送前会在本地校验公钥格式
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				/// - `title` 公钥标题
+				/// - `key` SSH公钥内容
+				/// - `read_only` 是否只读, 为`false`时允许推送
+				#[napi]
+				pub async fn add_deploy_key(
+					&self,
+					repo_path: RepoPath,
+					title: String,
+					key: String,
+					read_only: bool,
+				) -> Result<DeployKeyInfo> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let deploy_key = client
+						.repo()
+						.add_deploy_key(repo_path.into(), &title, &key, read_only)
+						.await?;
+					Ok(deploy_key.into())
+				}
+
+				/// 删除仓库部署公钥
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				/// - `id` 部署公钥ID
+				#[napi]
+				pub async fn remove_deploy_key(&self, repo_path: RepoPath, id: i64) -> Result<()> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					client.repo().remove_deploy_key(repo_path.into(), id as u64).await?;
+					Ok(())
+				}
+
+				/// 获取仓库主题列表
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				#[napi]
+				pub async fn topics(&self, repo_path: RepoPath) -> Result<Vec<String>> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let topics = client.repo().topics(repo_path.into()).await?;
+					Ok(topics)
+				}
+
+				/// 替换仓库的全部主题
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				/// - `topics` 新的主题列表, 为空时清空主题
+				#[napi]
+				pub async fn set_topics(
+					&self,
+					repo_path: RepoPath,
+					topics: Vec<String>,
+				) -> Result<Vec<String>> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let topics = client.repo().set_topics(repo_path.into(), topics).await?;
+					Ok(topics)
+				}
+
+				/// 获取仓库语言占比
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				#[napi]
+				pub async fn languages(&self, repo_path: RepoPath) -> Result<RepoLanguages> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let languages = client.repo().languages(repo_path.into()).await?;
+					Ok(languages.into())
+				}
+
+				/// 获取仓库贡献者列表, 按提交数从多到少排列
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				/// - `options` 列表选项
+				#[napi]
+				pub async fn contributors(
+					&self,
+					repo_path: RepoPath,
+					options: Option<RepoListOptions>,
+				) -> Result<Vec<ContributorInfo>> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let contributors = client
+						.repo()
+						.contributors(repo_path.into(), options.map(|o| o.into()))
+						.await?;
+					Ok(contributors.into_iter().map(|v| v.into()).collect())
+				}
+
+				/// 下载仓库在指定引用下的源码压缩包到文件
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				/// - `reference` 分支、标签或提交SHA
+				/// - `format` 压缩格式
+				/// - `path` 保存路径
+				/// - `extract_to` 下载后解压到的目录, 会去掉压缩包内的顶层目录
+				#[napi]
+				pub async fn archive(
+					&self,
+					repo_path: RepoPath,
+					reference: String,
+					format: ArchiveFormat,
+					path: String,
+					extract_to: Option<String>,
+				) -> Result<DownloadResult> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let format = format.into();
+					let mut file = napi::tokio::fs::File::create(&path)
+						.await
+						.map_err(nipaw_core::Error::from)?;
+					let result = client
+						.repo()
+						.archive(repo_path.into(), &reference, format, &mut file)
+						.await?;
+					drop(file);
+					if let Some(dest) = extract_to {
+						let path = std::path::PathBuf::from(path);
+						napi::tokio::task::spawn_blocking(move || {
+							nipaw_core::archive::extract(&path, format, std::path::Path::new(&dest))
+						})
+						.await
+						.map_err(|e| napi::Error::from_reason(e.to_string()))??;
+					}
+					Ok(result.into())
 				}
 			}
 		}
 	};
 }
-pub(crate) use impl_release;
+pub(crate) use impl_repo;
 
-macro_rules! impl_issue {
+macro_rules! impl_commit {
 	($client_type:ident) => {
 		paste! {
 			#[derive(Debug, Default)]
 			#[napi(constructor)]
-			pub struct [<$client_type Issue>];
+			pub struct [<$client_type Commit>];
 
 			#[napi]
-			impl [<$client_type Issue>] {
-				/// 创建一个issue
+			impl [<$client_type Commit>] {
+				/// 获取仓库提交信息
 				///
 				/// ## 参数
 				/// - `owner` 仓库所有者
 				/// - `repo` 仓库名称
-				/// - `title` issue标题
-				/// - `body` issue内容
-				/// - `options` 创建issue选项
+				/// - `sha` 提交SHA, 如果不设置则会获取默认分支的最新提交
 				#[napi]
-				pub async fn create(
+				pub async fn info(
 					&self,
 					repo_path: RepoPath,
-					title: String,
-					body: Option<String>,
-					options: Option<CreateIssueOptions>,
-				) -> Result<IssueInfo> {
+					sha: Option<String>,
+				) -> Result<CommitInfo> {
 					let client = [<create_client_ $client_type:lower>]().await;
-					let issue_info = client.issue().create(repo_path.into(), title.as_str(), body.as_deref(), options.map(|o| o.into())).await?;
-					Ok(issue_info.into())
+					let commit_info = client.commit().info(repo_path.into(), sha.as_deref()).await?;
+					Ok(commit_info.into())
 				}
 
-				/// 获取issue信息
+				/// 获取仓库提交列表
 				///
 				/// ## 参数
 				/// - `owner` 仓库所有者
 				/// - `repo` 仓库名称
-				/// - `issue_number` issue编号
+				/// - `options` 提交列表选项
 				#[napi]
-				pub async fn info(
+				pub async fn list(
 					&self,
 					repo_path: RepoPath,
-					issue_number: String
-				) -> Result<IssueInfo> {
+					options: Option<CommitListOptions>,
+				) -> Result<Vec<CommitListInfo>> {
 					let client = [<create_client_ $client_type:lower>]().await;
-					let issue_info = client.issue().info(repo_path.into(), issue_number.as_str()).await?;
-					Ok(issue_info.into())
+					let commit_infos = client.commit().list(repo_path.into(), options.map(|o| o.into())).await?;
+					Ok(commit_infos.into_iter().map(|v| v.into()).collect())
 				}
 
-				/// 获取仓库所有issue信息
+				/// 比较两个引用之间的差异
 				///
 				/// ## 参数
 				/// - `owner` 仓库所有者
 				/// - `repo` 仓库名称
-				/// - `options` issue列表选项
+				/// - `base` 基准引用, 可以是分支、标签或提交SHA
+				/// - `head` 目标引用, 可以是分支、标签或提交SHA
 				#[napi]
-				pub async fn list(
+				pub async fn compare(
 					&self,
 					repo_path: RepoPath,
-					options: Option<IssueListOptions>
-				) -> Result<Vec<IssueInfo>> {
+					base: String,
+					head: String,
+				) -> Result<CompareInfo> {
 					let client = [<create_client_ $client_type:lower>]().await;
-					let issue_infos = client.issue().list(repo_path.into(), options.map(|o| o.into())).await?;
-					Ok(issue_infos.into_iter().map(|v| v.into()).collect())
+					let compare_info = client.commit().compare(repo_path.into(), base.as_str(), head.as_str()).await?;
+					Ok(compare_info.into())
 				}
 
-				/// 更新issue信息
+				/// 获取提交的统一差异格式文本, 可使用`DiffParser`解析
 				///
 				/// ## 参数
 				/// - `owner` 仓库所有者
 				/// - `repo` 仓库名称
-				/// - `issue_number` issue编号
-				/// - `options` 更新issue选项
+				/// - `sha` 提交SHA
 				#[napi]
-				pub async fn update(
-					&self,
-					repo_path: RepoPath,
-					issue_number: String,
-					options: Option<UpdateIssueOptions>
-				) -> Result<IssueInfo> {
+				pub async fn diff(&self, repo_path: RepoPath, sha: String) -> Result<String> {
 					let client = [<create_client_ $client_type:lower>]().await;
-					let issue_info = client.issue().update(repo_path.into(), issue_number.as_str(), options.map(|o| o.into())).await?;
-					Ok(issue_info.into())
+					let diff = client.commit().diff(repo_path.into(), sha.as_str()).await?;
+					Ok(diff)
 				}
 			}
 		}
 	};
 }
-pub(crate) use impl_issue;
+pub(crate) use impl_commit;
+
+macro_rules! impl_release {
+	($client_type:ident) => {
+		paste! {
+			#[derive(Debug, Default)]
+			#[napi(constructor)]
+			pub struct [<$client_type Release>];
+
+			#[napi]
+			impl [<$client_type Release>] {
+				/// 创建一个Release
+				///
+				/// ## 参数
+				/// - `owner` 仓库所有者
+				/// - `repo` 仓库名称
+				/// - `tag_name` 标签名称
+				/// - `name` Release名称, 可选
+				/// - `body` Release内容, 可选
+				/// - `target_commitish` 目标提交SHA或分支, 可选
+				#[napi]
+				pub async fn create(
+					&self,
+					repo_path: RepoPath,
+					tag_name: String,
+					name: Option<String>,
+					body: Option<String>,
+					target_commitish: Option<String>,
+				) -> Result<ReleaseInfo> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let release_info = client.release().create(
+						repo_path.into(),
+						tag_name.as_str(),
+						name.as_deref(),
+						body.as_deref(),
+						target_commitish.as_deref(),
+					).await?;
+					Ok(release_info.into())
+				}
+
+				/// 获取Release信息
+				///
+				/// ## 参数
+				/// - `owner` 仓库所有者
+				/// - `repo` 仓库名称
+				/// - `tag_name` 标签名称, 可选, 为空时获取最新Release
+				#[napi]
+				pub async fn info(
+					&self,
+					repo_path: RepoPath,
+					tag_name: Option<String>,
+				) -> Result<ReleaseInfo> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let release_info = client.release().info(repo_path.into(), tag_name.as_deref()).await?;
+					Ok(release_info.into())
+				}
+
+				/// 获取Release列表
+				///
+				/// ## 参数
+				/// - `owner` 仓库所有者
+				/// - `repo` 仓库名称
+				#[napi]
+				pub async fn list(
+					&self,
+					repo_path: RepoPath,
+				) -> Result<Vec<ReleaseInfo>> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let release_infos = client.release().list(repo_path.into()).await?;
+					Ok(release_infos.into_iter().map(|v| v.into()).collect())
+				}
+
+				/// 更新Release
+				///
+				/// ## 参数
+				/// - `owner` 仓库所有者
+				/// - `repo` 仓库名称
+				/// - `tag_name` 标签名称
+				/// - `options` 更新参数
+				#[napi]
+				pub async fn update(
+					&self,
+					repo_path: RepoPath,
+					tag_name: String,
+					options: UpdateReleaseOptions,
+				) -> Result<ReleaseInfo> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let release_info = client.release().update(repo_path.into(), tag_name.as_str(), options.into()).await?;
+					Ok(release_info.into())
+				}
+
+				/// 下载Release附件到文件, 文件已存在时续传
+				///
+				/// ## 参数
+				/// - `owner` 仓库所有者
+				/// - `repo` 仓库名称
+				/// - `tag_name` 标签名称
+				/// - `asset_name` 附件文件名
+				/// - `path` 保存路径
+				/// - `options` 下载选项
+				/// - `progress` 下载进度回调
+				#[napi]
+				pub async fn download_asset(
+					&self,
+					repo_path: RepoPath,
+					tag_name: String,
+					asset_name: String,
+					path: String,
+					options: Option<DownloadAssetOptions>,
+					progress: Option<DownloadProgressCallback>,
+				) -> Result<DownloadResult> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let path = std::path::Path::new(&path);
+					let options = download_options(options, path, progress).await?;
+					let result = client.release().download_asset_to_file(
+						repo_path.into(),
+						tag_name.as_str(),
+						asset_name.as_str(),
+						path,
+						Some(options),
+					).await?;
+					Ok(result.into())
+				}
+			}
+		}
+	};
+}
+pub(crate) use impl_release;
+
+macro_rules! impl_issue {
+	($client_type:ident) => {
+		paste! {
+			#[derive(Debug, Default)]
+			#[napi(constructor)]
+			pub struct [<$client_type Issue>];
+
+			#[napi]
+			impl [<$client_type Issue>] {
+				/// 创建一个issue
+				///
+				/// ## 参数
+				/// - `owner` 仓库所有者
+				/// - `repo` 仓库名称
+				/// - `title` issue标题
+				/// - `body` issue内容
+				/// - `options` 创建issue选项
+				#[napi]
+				pub async fn create(
+					&self,
+					repo_path: RepoPath,
+					title: String,
+					body: Option<String>,
+					options: Option<CreateIssueOptions>,
+				) -> Result<IssueInfo> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let issue_info = client.issue().create(repo_path.into(), title.as_str(), body.as_deref(), options.map(|o| o.into())).await?;
+					Ok(issue_info.into())
+				}
+
+				/// 获取issue信息
+				///
+				/// ## 参数
+				/// - `owner` 仓库所有者
+				/// - `repo` 仓库名称
+				/// - `issue_number` issue编号
+				#[napi]
+				pub async fn info(
+					&self,
+					repo_path: RepoPath,
+					issue_number: String
+				) -> Result<IssueInfo> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let issue_info = client.issue().info(repo_path.into(), issue_number.as_str()).await?;
+					Ok(issue_info.into())
+				}
+
+				/// 获取仓库所有issue信息
+				///
+				/// ## 参数
+				/// - `owner` 仓库所有者
+				/// - `repo` 仓库名称
+				/// - `options` issue列表选项
+				#[napi]
+				pub async fn list(
+					&self,
+					repo_path: RepoPath,
+					options: Option<IssueListOptions>
+				) -> Result<Vec<IssueInfo>> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let issue_infos = client.issue().list(repo_path.into(), options.map(|o| o.into())).await?;
+					Ok(issue_infos.into_iter().map(|v| v.into()).collect())
+				}
+
+				/// 更新issue信息
+				///
+				/// ## 参数
+				/// - `owner` 仓库所有者
+				/// - `repo` 仓库名称
+				/// - `issue_number` issue编号
+				/// - `options` 更新issue选项
+				#[napi]
+				pub async fn update(
+					&self,
+					repo_path: RepoPath,
+					issue_number: String,
+					options: Option<UpdateIssueOptions>
+				) -> Result<IssueInfo> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let issue_info = client.issue().update(repo_path.into(), issue_number.as_str(), options.map(|o| o.into())).await?;
+					Ok(issue_info.into())
+				}
+
+				/// 获取issue的事件列表
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				/// - `issue_number` issue编号
+				/// - `options` 分页选项
+				#[napi]
+				pub async fn events(
+					&self,
+					repo_path: RepoPath,
+					issue_number: String,
+					options: Option<IssueEventListOptions>,
+				) -> Result<Vec<IssueEventInfo>> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let events = client
+						.issue()
+						.events(repo_path.into(), &issue_number, options.map(|o| o.into()))
+						.await?;
+					Ok(events.into_iter().map(|v| v.into()).collect())
+				}
+
+				/// 获取issue的时间线, 评论与事件按发生时间升序合并
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				/// - `issue_number` issue编号
+				/// - `options` 分页选项
+				#[napi]
+				pub async fn timeline(
+					&self,
+					repo_path: RepoPath,
+					issue_number: String,
+					options: Option<IssueEventListOptions>,
+				) -> Result<Vec<TimelineItem>> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let timeline = client
+						.issue()
+						.timeline(repo_path.into(), &issue_number, options.map(|o| o.into()))
+						.await?;
+					Ok(timeline.into_iter().map(|v| v.into()).collect())
+				}
+
+				/// 为issue添加负责人
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				/// - `issue_number` issue编号
+				/// - `assignees` 负责人用户名列表
+				#[napi]
+				pub async fn add_assignees(
+					&self,
+					repo_path: RepoPath,
+					issue_number: String,
+					assignees: Vec<String>,
+				) -> Result<IssueInfo> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let issue_info = client
+						.issue()
+						.add_assignees(repo_path.into(), &issue_number, assignees)
+						.await?;
+					Ok(issue_info.into())
+				}
+
+				/// 移除issue的负责人
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				/// - `issue_number` issue编号
+				/// - `assignees` 负责人用户名列表
+				#[napi]
+				pub async fn remove_assignees(
+					&self,
+					repo_path: RepoPath,
+					issue_number: String,
+					assignees: Vec<String>,
+				) -> Result<IssueInfo> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let issue_info = client
+						.issue()
+						.remove_assignees(repo_path.into(), &issue_number, assignees)
+						.await?;
+					Ok(issue_info.into())
+				}
+
+				/// 锁定issue
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				/// - `issue_number` issue编号
+				#[napi]
+				pub async fn lock(&self, repo_path: RepoPath, issue_number: String) -> Result<()> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					client.issue().lock(repo_path.into(), &issue_number).await?;
+					Ok(())
+				}
+
+				/// 解锁issue
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				/// - `issue_number` issue编号
+				#[napi]
+				pub async fn unlock(
+					&self,
+					repo_path: RepoPath,
+					issue_number: String,
+				) -> Result<()> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					client.issue().unlock(repo_path.into(), &issue_number).await?;
+					Ok(())
+				}
+			}
+		}
+	};
+}
+pub(crate) use impl_issue;
+
+macro_rules! impl_status {
+	($client_type:ident) => {
+		paste! {
+			#[derive(Debug, Default)]
+			#[napi(constructor)]
+			pub struct [<$client_type Status>];
+
+			#[napi]
+			impl [<$client_type Status>] {
+				/// 为提交创建一个状态
+				///
+				/// ## 参数
+				/// - `owner` 仓库所有者
+				/// - `repo` 仓库名称
+				/// - `sha` 提交SHA
+				/// - `state` 状态
+				/// - `options` 创建状态选项
+				#[napi]
+				pub async fn create(
+					&self,
+					repo_path: RepoPath,
+					sha: String,
+					state: StatusState,
+					options: Option<CreateStatusOptions>,
+				) -> Result<StatusInfo> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let status_info = client.status().create(repo_path.into(), sha.as_str(), state.into(), options.map(|o| o.into())).await?;
+					Ok(status_info.into())
+				}
+
+				/// 获取引用的所有状态
+				///
+				/// ## 参数
+				/// - `owner` 仓库所有者
+				/// - `repo` 仓库名称
+				/// - `reference` 提交SHA、分支或标签
+				/// - `options` 状态列表选项
+				#[napi]
+				pub async fn list(
+					&self,
+					repo_path: RepoPath,
+					reference: String,
+					options: Option<StatusListOptions>,
+				) -> Result<Vec<StatusInfo>> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let status_infos = client.status().list(repo_path.into(), reference.as_str(), options.map(|o| o.into())).await?;
+					Ok(status_infos.into_iter().map(|v| v.into()).collect())
+				}
+
+				/// 获取引用的合并状态
+				///
+				/// ## 参数
+				/// - `owner` 仓库所有者
+				/// - `repo` 仓库名称
+				/// - `reference` 提交SHA、分支或标签
+				#[napi]
+				pub async fn combined(
+					&self,
+					repo_path: RepoPath,
+					reference: String,
+				) -> Result<CombinedStatusInfo> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let combined_info = client.status().combined(repo_path.into(), reference.as_str()).await?;
+					Ok(combined_info.into())
+				}
+			}
+		}
+	};
+}
+pub(crate) use impl_status;
+
+macro_rules! impl_search {
+	($client_type:ident) => {
+		paste! {
+			#[derive(Debug, Default)]
+			#[napi(constructor)]
+			pub struct [<$client_type Search>];
+
+			#[napi]
+			impl [<$client_type Search>] {
+				/// 搜索仓库
+				///
+				/// ## 参数
+				/// - `query` 搜索条件
+				#[napi]
+				pub async fn repos(&self, query: SearchQuery) -> Result<RepoSearchResult> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let result = client.search().repos(query.into()).await?;
+					Ok(result.into())
+				}
+
+				/// 搜索议题
+				///
+				/// ## 参数
+				/// - `query` 搜索条件
+				#[napi]
+				pub async fn issues(&self, query: SearchQuery) -> Result<IssueSearchResult> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let result = client.search().issues(query.into()).await?;
+					Ok(result.into())
+				}
+
+				/// 搜索用户
+				///
+				/// ## 参数
+				/// - `query` 搜索条件
+				#[napi]
+				pub async fn users(&self, query: SearchQuery) -> Result<UserSearchResult> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let result = client.search().users(query.into()).await?;
+					Ok(result.into())
+				}
+
+				/// 搜索代码
+				///
+				/// ## 参数
+				/// - `query` 搜索条件
+				#[napi]
+				pub async fn code(&self, query: SearchQuery) -> Result<CodeSearchResult> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let result = client.search().code(query.into()).await?;
+					Ok(result.into())
+				}
+			}
+		}
+	};
+}
+pub(crate) use impl_search;
+
+macro_rules! impl_key {
+	($client_type:ident) => {
+		paste! {
+			#[derive(Debug, Default)]
+			#[napi(constructor)]
+			pub struct [<$client_type Key>];
+
+			#[napi]
+			impl [<$client_type Key>] {
+				/// 获取当前用户的SSH公钥列表
+				///
+				/// ## 参数
+				/// - `options` 列表选项
+				#[napi]
+				pub async fn ssh_keys(
+					&self,
+					options: Option<RepoListOptions>,
+				) -> Result<Vec<SshKeyInfo>> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let key_infos = client.key().ssh_keys(options.map(|o| o.into())).await?;
+					Ok(key_infos.into_iter().map(|v| v.into()).collect())
+				}
+
+				/// 添加SSH公钥, 发送前会在本地校验公钥格式
+				///
+				/// ## 参数
+				/// - `title` 公钥标题
+				/// - `key` 公钥内容
+				#[napi]
+				pub async fn add_ssh_key(&self, title: String, key: String) -> Result<SshKeyInfo> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let key_info = client.key().add_ssh_key(title.as_str(), key.as_str()).await?;
+					Ok(key_info.into())
+				}
+
+				/// 删除SSH公钥
+				///
+				/// ## 参数
+				/// - `id` 公钥ID
+				#[napi]
+				pub async fn delete_ssh_key(&self, id: i64) -> Result<()> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					client.key().delete_ssh_key(id as u64).await?;
+					Ok(())
+				}
+
+				/// 获取当前用户的GPG公钥列表
+				///
+				/// ## 参数
+				/// - `options` 列表选项
+				#[napi]
+				pub async fn gpg_keys(
+					&self,
+					options: Option<RepoListOptions>,
+				) -> Result<Vec<GpgKeyInfo>> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let key_infos = client.key().gpg_keys(options.map(|o| o.into())).await?;
+					Ok(key_infos.into_iter().map(|v| v.into()).collect())
+				}
+
+				/// 添加GPG公钥, 发送前会在本地校验公钥格式
+				///
+				/// ## 参数
+				/// - `title` 公钥名称
+				/// - `key` ASCII armor格式的公钥内容
+				#[napi]
+				pub async fn add_gpg_key(
+					&self,
+					title: Option<String>,
+					key: String,
+				) -> Result<GpgKeyInfo> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let key_info = client.key().add_gpg_key(title.as_deref(), key.as_str()).await?;
+					Ok(key_info.into())
+				}
+
+				/// 删除GPG公钥
+				///
+				/// ## 参数
+				/// - `id` 公钥ID
+				#[napi]
+				pub async fn delete_gpg_key(&self, id: i64) -> Result<()> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					client.key().delete_gpg_key(id as u64).await?;
+					Ok(())
+				}
+			}
+		}
+	};
+}
+pub(crate) use impl_key;
+
+macro_rules! impl_stats {
+	($client_type:ident) => {
+		paste! {
+			#[derive(Debug, Default)]
+			#[napi(constructor)]
+			pub struct [<$client_type Stats>];
+
+			#[napi]
+			impl [<$client_type Stats>] {
+				/// 获取每周提交数
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				/// - `options` 统计选项
+				#[napi]
+				pub async fn commit_activity(
+					&self,
+					repo_path: RepoPath,
+					options: Option<StatsOptions>,
+				) -> Result<Vec<WeeklyCommitActivity>> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let activity = client
+						.stats()
+						.commit_activity(repo_path.into(), options.map(|o| o.into()))
+						.await?;
+					Ok(activity.into_iter().map(|v| v.into()).collect())
+				}
+
+				/// 获取每周新增与删除的行数
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				/// - `options` 统计选项
+				#[napi]
+				pub async fn code_frequency(
+					&self,
+					repo_path: RepoPath,
+					options: Option<StatsOptions>,
+				) -> Result<Vec<CodeFrequency>> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let frequency = client
+						.stats()
+						.code_frequency(repo_path.into(), options.map(|o| o.into()))
+						.await?;
+					Ok(frequency.into_iter().map(|v| v.into()).collect())
+				}
+
+				/// 获取按星期与小时分布的提交数
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				/// - `options` 统计选项
+				#[napi]
+				pub async fn punch_card(
+					&self,
+					repo_path: RepoPath,
+					options: Option<StatsOptions>,
+				) -> Result<Vec<PunchCard>> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let punch_card = client
+						.stats()
+						.punch_card(repo_path.into(), options.map(|o| o.into()))
+						.await?;
+					Ok(punch_card.into_iter().map(|v| v.into()).collect())
+				}
+			}
+		}
+	};
+}
+pub(crate) use impl_stats;
+
+macro_rules! impl_traffic {
+	($client_type:ident) => {
+		paste! {
+			#[derive(Debug, Default)]
+			#[napi(constructor)]
+			pub struct [<$client_type Traffic>];
+
+			#[napi]
+			impl [<$client_type Traffic>] {
+				/// 获取最近14天的访问量
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				/// - `per` 按天或按周汇总, 默认按天
+				#[napi]
+				pub async fn views(
+					&self,
+					repo_path: RepoPath,
+					per: Option<TrafficPer>,
+				) -> Result<TrafficSeries> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let per = per.map(|p| p.into()).unwrap_or_default();
+					let views = client.traffic().views(repo_path.into(), per).await?;
+					Ok(views.into())
+				}
+
+				/// 获取最近14天的克隆量
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				/// - `per` 按天或按周汇总, 默认按天
+				#[napi]
+				pub async fn clones(
+					&self,
+					repo_path: RepoPath,
+					per: Option<TrafficPer>,
+				) -> Result<TrafficSeries> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let per = per.map(|p| p.into()).unwrap_or_default();
+					let clones = client.traffic().clones(repo_path.into(), per).await?;
+					Ok(clones.into())
+				}
+
+				/// 获取最近14天访问量最多的来源站点
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				#[napi]
+				pub async fn referrers(&self, repo_path: RepoPath) -> Result<Vec<TrafficReferrer>> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let referrers = client.traffic().referrers(repo_path.into()).await?;
+					Ok(referrers.into_iter().map(|v| v.into()).collect())
+				}
+
+				/// 获取最近14天访问量最多的页面
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				#[napi]
+				pub async fn paths(&self, repo_path: RepoPath) -> Result<Vec<TrafficPath>> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let paths = client.traffic().paths(repo_path.into()).await?;
+					Ok(paths.into_iter().map(|v| v.into()).collect())
+				}
+			}
+		}
+	};
+}
+pub(crate) use impl_traffic;
+
+macro_rules! impl_notifications {
+	($client_type:ident) => {
+		paste! {
+			#[derive(Debug, Default)]
+			#[napi(constructor)]
+			pub struct [<$client_type Notifications>];
+
+			#[napi]
+			impl [<$client_type Notifications>] {
+				/// 获取当前用户的通知列表
+				///
+				/// ## 参数
+				/// - `options` 通知列表选项
+				#[napi]
+				pub async fn list(
+					&self,
+					options: Option<NotificationListOptions>,
+				) -> Result<Vec<NotificationInfo>> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let notifications =
+						client.notifications().list(options.map(|o| o.into())).await?;
+					Ok(notifications.into_iter().map(|v| v.into()).collect())
+				}
+
+				/// 将通知标记为已读
+				///
+				/// ## 参数
+				/// - `thread_id` 通知ID
+				#[napi]
+				pub async fn mark_read(&self, thread_id: String) -> Result<()> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					client.notifications().mark_read(&thread_id).await?;
+					Ok(())
+				}
+
+				/// 将全部通知标记为已读
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径, 为空时标记所有仓库的通知
+				#[napi]
+				pub async fn mark_all_read(&self, repo_path: Option<RepoPath>) -> Result<()> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					client.notifications().mark_all_read(repo_path.map(|v| v.into())).await?;
+					Ok(())
+				}
+
+				/// 订阅通知
+				///
+				/// ## 参数
+				/// - `thread_id` 通知ID
+				#[napi]
+				pub async fn subscribe(&self, thread_id: String) -> Result<()> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					client.notifications().subscribe(&thread_id).await?;
+					Ok(())
+				}
+
+				/// 取消订阅通知
+				///
+				/// ## 参数
+				/// - `thread_id` 通知ID
+				#[napi]
+				pub async fn unsubscribe(&self, thread_id: String) -> Result<()> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					client.notifications().unsubscribe(&thread_id).await?;
+					Ok(())
+				}
+			}
+		}
+	};
+}
+pub(crate) use impl_notifications;
+
+macro_rules! impl_gist {
+	($client_type:ident) => {
+		paste! {
+			#[derive(Debug, Default)]
+			#[napi(constructor)]
+			pub struct [<$client_type Gist>];
+
+			#[napi]
+			impl [<$client_type Gist>] {
+				/// 获取用户的代码片段列表
+				///
+				/// ## 参数
+				/// - `user_name` 用户名, 为空时获取当前登录用户的代码片段
+				/// - `options` 列表选项
+				#[napi]
+				pub async fn list(
+					&self,
+					user_name: Option<String>,
+					options: Option<RepoListOptions>,
+				) -> Result<Vec<GistInfo>> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let gists = client
+						.gist()
+						.list(user_name.as_deref(), options.map(|o| o.into()))
+						.await?;
+					Ok(gists.into_iter().map(|v| v.into()).collect())
+				}
+
+				/// 获取代码片段及其文件内容
+				///
+				/// ## 参数
+				/// - `id` 代码片段ID
+				#[napi]
+				pub async fn info(&self, id: String) -> Result<GistInfo> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let gist_info = client.gist().info(&id).await?;
+					Ok(gist_info.into())
+				}
+
+				/// 创建代码片段
+				///
+				/// ## 参数
+				/// - `options` 创建选项
+				#[napi]
+				pub async fn create(&self, options: CreateGistOptions) -> Result<GistInfo> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let gist_info = client.gist().create(options.into()).await?;
+					Ok(gist_info.into())
+				}
+
+				/// 更新代码片段
+				///
+				/// ## 参数
+				/// - `id` 代码片段ID
+				/// - `options` 更新选项
+				#[napi]
+				pub async fn update(
+					&self,
+					id: String,
+					options: UpdateGistOptions,
+				) -> Result<GistInfo> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let gist_info = client.gist().update(&id, options.into()).await?;
+					Ok(gist_info.into())
+				}
+
+				/// 删除代码片段
+				///
+				/// ## 参数
+				/// - `id` 代码片段ID
+				#[napi]
+				pub async fn delete(&self, id: String) -> Result<()> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					client.gist().delete(&id).await?;
+					Ok(())
+				}
+
+				/// 收藏代码片段
+				///
+				/// ## 参数
+				/// - `id` 代码片段ID
+				#[napi]
+				pub async fn star(&self, id: String) -> Result<()> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					client.gist().star(&id).await?;
+					Ok(())
+				}
+
+				/// 取消收藏代码片段
+				///
+				/// ## 参数
+				/// - `id` 代码片段ID
+				#[napi]
+				pub async fn unstar(&self, id: String) -> Result<()> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					client.gist().unstar(&id).await?;
+					Ok(())
+				}
+
+				/// Fork代码片段到当前用户
+				///
+				/// ## 参数
+				/// - `id` 代码片段ID
+				#[napi]
+				pub async fn fork(&self, id: String) -> Result<GistInfo> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let gist_info = client.gist().fork(&id).await?;
+					Ok(gist_info.into())
+				}
+			}
+		}
+	};
+}
+pub(crate) use impl_gist;
+
+macro_rules! impl_pipeline {
+	($client_type:ident) => {
+		paste! {
+			#[derive(Debug, Default)]
+			#[napi(constructor)]
+			pub struct [<$client_type Pipeline>];
+
+			#[napi]
+			impl [<$client_type Pipeline>] {
+				/// 获取仓库的运行列表, 不包含任务详情
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				/// - `options` 列表选项
+				#[napi]
+				pub async fn list(
+					&self,
+					repo_path: RepoPath,
+					options: Option<PipelineListOptions>,
+				) -> Result<Vec<PipelineRun>> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let runs =
+						client.pipeline().list(repo_path.into(), options.map(|o| o.into())).await?;
+					Ok(runs.into_iter().map(|v| v.into()).collect())
+				}
+
+				/// 获取运行详情, 包括任务与步骤
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				/// - `run_id` 运行ID
+				#[napi]
+				pub async fn info(
+					&self,
+					repo_path: RepoPath,
+					run_id: String,
+				) -> Result<PipelineRun> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let run = client.pipeline().info(repo_path.into(), &run_id).await?;
+					Ok(run.into())
+				}
+
+				/// 重新运行
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				/// - `run_id` 运行ID
+				#[napi]
+				pub async fn rerun(&self, repo_path: RepoPath, run_id: String) -> Result<()> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					client.pipeline().rerun(repo_path.into(), &run_id).await?;
+					Ok(())
+				}
+
+				/// 取消运行
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				/// - `run_id` 运行ID
+				#[napi]
+				pub async fn cancel(&self, repo_path: RepoPath, run_id: String) -> Result<()> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					client.pipeline().cancel(repo_path.into(), &run_id).await?;
+					Ok(())
+				}
+
+				/// 手动触发运行, 平台返回运行ID时返回该ID
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				/// - `workflow` GitHub为工作流文件名或ID, CNB为触发事件名
+				/// - `options` 触发选项
+				#[napi]
+				pub async fn trigger(
+					&self,
+					repo_path: RepoPath,
+					workflow: String,
+					options: TriggerPipelineOptions,
+				) -> Result<Option<String>> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let run_id = client
+						.pipeline()
+						.trigger(repo_path.into(), &workflow, options.into())
+						.await?;
+					Ok(run_id)
+				}
+
+				/// 下载运行日志到文件
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				/// - `run_id` 运行ID
+				/// - `path` 保存路径
+				#[napi]
+				pub async fn download_logs(
+					&self,
+					repo_path: RepoPath,
+					run_id: String,
+					path: String,
+				) -> Result<DownloadResult> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let mut file = napi::tokio::fs::File::create(&path)
+						.await
+						.map_err(nipaw_core::Error::from)?;
+					let result = client
+						.pipeline()
+						.download_logs(repo_path.into(), &run_id, &mut file)
+						.await?;
+					Ok(result.into())
+				}
+			}
+		}
+	};
+}
+pub(crate) use impl_pipeline;
+
+macro_rules! impl_secret {
+	($client_type:ident) => {
+		paste! {
+			#[derive(Debug, Default)]
+			#[napi(constructor)]
+			pub struct [<$client_type Secret>];
+
+			#[napi]
+			impl [<$client_type Secret>] {
+				/// 获取密钥列表, 只包含名称与时间
+				///
+				/// ## 参数
+				/// - `scope` 仓库或组织
+				/// - `options` 列表选项
+				#[napi]
+				pub async fn list_secrets(
+					&self,
+					scope: SecretScope,
+					options: Option<RepoListOptions>,
+				) -> Result<Vec<SecretInfo>> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let secrets = client
+						.secret()
+						.list_secrets(scope.try_into()?, options.map(|o| o.into()))
+						.await?;
+					Ok(secrets.into_iter().map(|v| v.into()).collect())
+				}
+
+				/// 创建或更新密钥
+				///
+				/// ## 参数
+				/// - `scope` 仓库或组织
+				/// - `name` 密钥名称
+				/// - `value` 密钥的值
+				#[napi]
+				pub async fn set_secret(
+					&self,
+					scope: SecretScope,
+					name: String,
+					value: String,
+				) -> Result<()> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let value = nipaw_core::types::secret::SecretValue::from(value);
+					client.secret().set_secret(scope.try_into()?, &name, &value).await?;
+					Ok(())
+				}
+
+				/// 删除密钥
+				///
+				/// ## 参数
+				/// - `scope` 仓库或组织
+				/// - `name` 密钥名称
+				#[napi]
+				pub async fn delete_secret(&self, scope: SecretScope, name: String) -> Result<()> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					client.secret().delete_secret(scope.try_into()?, &name).await?;
+					Ok(())
+				}
+
+				/// 获取变量列表
+				///
+				/// ## 参数
+				/// - `scope` 仓库或组织
+				/// - `options` 列表选项
+				#[napi]
+				pub async fn list_variables(
+					&self,
+					scope: SecretScope,
+					options: Option<RepoListOptions>,
+				) -> Result<Vec<VariableInfo>> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let variables = client
+						.secret()
+						.list_variables(scope.try_into()?, options.map(|o| o.into()))
+						.await?;
+					Ok(variables.into_iter().map(|v| v.into()).collect())
+				}
+
+				/// 创建或更新变量
+				///
+				/// ## 参数
+				/// - `scope` 仓库或组织
+				/// - `name` 变量名称
+				/// - `value` 变量值
+				#[napi]
+				pub async fn set_variable(
+					&self,
+					scope: SecretScope,
+					name: String,
+					value: String,
+				) -> Result<()> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					client.secret().set_variable(scope.try_into()?, &name, &value).await?;
+					Ok(())
+				}
+
+				/// 删除变量
+				///
+				/// ## 参数
+				/// - `scope` 仓库或组织
+				/// - `name` 变量名称
+				#[napi]
+				pub async fn delete_variable(
+					&self,
+					scope: SecretScope,
+					name: String,
+				) -> Result<()> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					client.secret().delete_variable(scope.try_into()?, &name).await?;
+					Ok(())
+				}
+			}
+		}
+	};
+}
+pub(crate) use impl_secret;
+
+macro_rules! impl_git {
+	($client_type:ident) => {
+		paste! {
+			#[derive(Debug, Default)]
+			#[napi(constructor)]
+			pub struct [<$client_type Git>];
+
+			#[napi]
+			impl [<$client_type Git>] {
+				/// 获取引用
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				/// - `reference` 引用名, 如`heads/main`
+				#[napi]
+				pub async fn get_ref(
+					&self,
+					repo_path: RepoPath,
+					reference: String,
+				) -> Result<GitRef> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let git_ref = client.git().get_ref(repo_path.into(), &reference).await?;
+					Ok(git_ref.into())
+				}
+
+				/// 创建引用
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				/// - `reference` 引用名
+				/// - `sha` 指向的对象SHA
+				#[napi]
+				pub async fn create_ref(
+					&self,
+					repo_path: RepoPath,
+					reference: String,
+					sha: String,
+				) -> Result<GitRef> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let git_ref =
+						client.git().create_ref(repo_path.into(), &reference, &sha).await?;
+					Ok(git_ref.into())
+				}
+
+				/// 更新引用
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				/// - `reference` 引用名
+				/// - `sha` 新的对象SHA
+				/// - `force` 是否允许非快进更新, 默认为`false`
+				#[napi]
+				pub async fn update_ref(
+					&self,
+					repo_path: RepoPath,
+					reference: String,
+					sha: String,
+					force: Option<bool>,
+				) -> Result<GitRef> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let git_ref = client
+						.git()
+						.update_ref(repo_path.into(), &reference, &sha, force.unwrap_or(false))
+						.await?;
+					Ok(git_ref.into())
+				}
+
+				/// 删除引用
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				/// - `reference` 引用名
+				#[napi]
+				pub async fn delete_ref(
+					&self,
+					repo_path: RepoPath,
+					reference: String,
+				) -> Result<()> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					client.git().delete_ref(repo_path.into(), &reference).await?;
+					Ok(())
+				}
+
+				/// 获取树对象
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				/// - `sha` 树对象SHA, 也可以是分支名或提交SHA
+				/// - `recursive` 是否递归获取子目录, 默认为`false`
+				#[napi]
+				pub async fn tree(
+					&self,
+					repo_path: RepoPath,
+					sha: String,
+					recursive: Option<bool>,
+				) -> Result<GitTree> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let tree = client
+						.git()
+						.tree(repo_path.into(), &sha, recursive.unwrap_or(false))
+						.await?;
+					Ok(tree.into())
+				}
+
+				/// 创建数据对象, 返回对象SHA
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				/// - `content` 文件内容
+				#[napi]
+				pub async fn create_blob(
+					&self,
+					repo_path: RepoPath,
+					content: String,
+				) -> Result<String> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let sha = client.git().create_blob(repo_path.into(), content.as_bytes()).await?;
+					Ok(sha)
+				}
+
+				/// 创建树对象
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				/// - `base_tree` 基础树对象SHA
+				/// - `entries` 树条目
+				#[napi]
+				pub async fn create_tree(
+					&self,
+					repo_path: RepoPath,
+					base_tree: Option<String>,
+					entries: Vec<TreeEntryOptions>,
+				) -> Result<GitTree> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let entries = entries.into_iter().map(|e| e.into()).collect();
+					let tree = client
+						.git()
+						.create_tree(repo_path.into(), base_tree.as_deref(), entries)
+						.await?;
+					Ok(tree.into())
+				}
+
+				/// 获取提交对象
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				/// - `sha` 提交SHA
+				#[napi]
+				pub async fn get_commit(
+					&self,
+					repo_path: RepoPath,
+					sha: String,
+				) -> Result<GitCommit> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let commit = client.git().get_commit(repo_path.into(), &sha).await?;
+					Ok(commit.into())
+				}
+
+				/// 创建提交对象
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				/// - `message` 提交信息
+				/// - `tree` 树对象SHA
+				/// - `parents` 父提交SHA列表
+				#[napi]
+				pub async fn create_commit(
+					&self,
+					repo_path: RepoPath,
+					message: String,
+					tree: String,
+					parents: Vec<String>,
+				) -> Result<GitCommit> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let commit = client
+						.git()
+						.create_commit(repo_path.into(), &message, &tree, parents)
+						.await?;
+					Ok(commit.into())
+				}
+
+				/// 在分支上以一次提交完成多个文件的修改
+				///
+				/// ## 参数
+				/// - `repo_path` 仓库路径
+				/// - `branch` 分支名
+				/// - `message` 提交信息
+				/// - `changes` 文件修改
+				#[napi]
+				pub async fn commit_files(
+					&self,
+					repo_path: RepoPath,
+					branch: String,
+					message: String,
+					changes: Vec<FileChange>,
+				) -> Result<GitCommit> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let changes = changes.into_iter().map(|c| c.into()).collect();
+					let commit = client
+						.git()
+						.commit_files(repo_path.into(), &branch, &message, changes)
+						.await?;
+					Ok(commit.into())
+				}
+			}
+		}
+	};
+}
+pub(crate) use impl_git;
@@ -1,21 +1,45 @@
-use super::{impl_client, impl_commit, impl_issue, impl_org, impl_release, impl_repo, impl_user};
+use super::{
+	impl_client, impl_commit, impl_gist, impl_git, impl_issue, impl_key, impl_notifications,
+	impl_org, impl_pipeline, impl_release, impl_repo, impl_search, impl_secret, impl_stats,
+	impl_status, impl_traffic, impl_user,
+};
 use crate::{
 	option::{
-		CommitListOptions, CreateIssueOptions, IssueListOptions, RepoListOptions,
-		UpdateIssueOptions, UpdateReleaseOptions,
+		CommitListOptions, CreateGistOptions, CreateIssueOptions, CreateRepoOptions,
+		CreateStatusOptions, DownloadAssetOptions, DownloadProgressCallback, FileChange,
+		IssueEventListOptions, IssueListOptions, NotificationListOptions, PipelineListOptions,
+		RepoListOptions, SearchQuery, StatsOptions, StatusListOptions, TreeEntryOptions,
+		TriggerPipelineOptions, UpdateGistOptions, UpdateIssueOptions, UpdateReleaseOptions,
+		UpdateRepoOptions, download_options,
 	},
 	types::{
-		commit::{CommitInfo, CommitListInfo},
-		issue::IssueInfo,
-		org::OrgInfo,
-		release::ReleaseInfo,
-		repo::{CollaboratorPermission, CollaboratorResult, RepoInfo, RepoPath},
+		commit::{CommitInfo, CommitListInfo, CompareInfo},
+		gist::GistInfo,
+		git::{GitCommit, GitRef, GitTree},
+		issue::{IssueEventInfo, IssueInfo, TimelineItem},
+		key::{DeployKeyInfo, GpgKeyInfo, SshKeyInfo},
+		notification::NotificationInfo,
+		org::{MemberRole, OrgInfo, OrgMember, TeamInfo, TeamRepoInfo},
+		pipeline::PipelineRun,
+		release::{DownloadResult, ReleaseInfo},
+		repo::{
+			ArchiveFormat, CollaboratorInfo, CollaboratorPermission, CollaboratorResult,
+			ContributorInfo, RepoInfo, RepoLanguages, RepoPath, SyncForkResult,
+		},
+		search::{CodeSearchResult, IssueSearchResult, RepoSearchResult, UserSearchResult},
+		secret::{SecretInfo, SecretScope, VariableInfo},
+		stats::{CodeFrequency, PunchCard, WeeklyCommitActivity},
+		status::{CombinedStatusInfo, StatusInfo, StatusState},
+		traffic::{TrafficPath, TrafficPer, TrafficReferrer, TrafficSeries},
 		user::{ContributionResult, UserInfo},
 	},
 };
 use napi::tokio::sync::{RwLock, RwLockWriteGuard};
 use napi_derive::napi;
-use nipaw_core::{Commit, Issue, Org, Provider, Proxy, Release, Repo, Token, User};
+use nipaw_core::{
+	Commit, Gist, Git, Issue, Key, Notifications, Org, Pipeline, Provider, Proxy, Release, Repo,
+	Search, Secret, Stats, Status, Token, Traffic, User,
+};
 use paste::paste;
 use std::sync::LazyLock;
 
@@ -28,3 +52,13 @@ impl_repo!(GitCode);
 impl_commit!(GitCode);
 impl_release!(GitCode);
 impl_issue!(GitCode);
+impl_status!(GitCode);
+impl_search!(GitCode);
+impl_key!(GitCode);
+impl_stats!(GitCode);
+impl_traffic!(GitCode);
+impl_notifications!(GitCode);
+impl_gist!(GitCode);
+impl_pipeline!(GitCode);
+impl_secret!(GitCode);
+impl_git!(GitCode);
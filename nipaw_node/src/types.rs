@@ -1,6 +1,17 @@
 pub(crate) mod commit;
+pub(crate) mod diff;
+pub(crate) mod gist;
+pub(crate) mod git;
 pub(crate) mod issue;
+pub(crate) mod key;
+pub(crate) mod notification;
 pub(crate) mod org;
+pub(crate) mod pipeline;
 pub(crate) mod release;
 pub(crate) mod repo;
+pub(crate) mod search;
+pub(crate) mod secret;
+pub(crate) mod stats;
+pub(crate) mod status;
+pub(crate) mod traffic;
 pub(crate) mod user;
@@ -24,6 +24,19 @@ pub struct CommitListInfo {
 	pub commit: CommitData,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+#[napi(object)]
+pub struct CompareInfo {
+	/// head领先base的提交数
+	pub ahead_by: u32,
+	/// head落后base的提交数
+	pub behind_by: u32,
+	/// base与head之间的提交
+	pub commits: Vec<CommitListInfo>,
+	/// 文件差异状态
+	pub files: Vec<FileInfo>,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 #[napi(object)]
 pub struct CommitData {
@@ -76,6 +89,17 @@ impl From<nipaw_core::types::commit::CommitListInfo> for CommitListInfo {
 	}
 }
 
+impl From<nipaw_core::types::commit::CompareInfo> for CompareInfo {
+	fn from(value: nipaw_core::types::commit::CompareInfo) -> Self {
+		Self {
+			ahead_by: value.ahead_by as u32,
+			behind_by: value.behind_by as u32,
+			commits: value.commits.into_iter().map(|c| c.into()).collect(),
+			files: value.files.into_iter().map(|f| f.into()).collect(),
+		}
+	}
+}
+
 impl From<nipaw_core::types::commit::CommitData> for CommitData {
 	fn from(value: nipaw_core::types::commit::CommitData) -> Self {
 		Self {
@@ -120,6 +144,14 @@ pub struct FileInfo {
 	pub deletions: u32,
 	/// 修改行数
 	pub changes: u32,
+	/// 文件差异内容
+	pub patch: Option<String>,
+	/// 重命名前的文件名
+	pub previous_file_name: Option<String>,
+	/// 文件的blob SHA
+	pub sha: Option<String>,
+	/// 文件原始内容URL
+	pub raw_url: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -149,6 +181,10 @@ impl From<nipaw_core::types::commit::FileInfo> for FileInfo {
 			additions: value.additions as u32,
 			deletions: value.deletions as u32,
 			changes: value.changes as u32,
+			patch: value.patch,
+			previous_file_name: value.previous_file_name,
+			sha: value.sha,
+			raw_url: value.raw_url,
 		}
 	}
 }
@@ -0,0 +1,114 @@
+use napi_derive::napi;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize)]
+#[napi(object)]
+pub struct DiffFile {
+	/// 变更前的文件路径, 新增文件为空
+	pub old_path: Option<String>,
+	/// 变更后的文件路径, 删除文件为空
+	pub new_path: Option<String>,
+	/// 差异块
+	pub hunks: Vec<DiffHunk>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[napi(object)]
+pub struct DiffHunk {
+	/// 变更前的起始行号
+	pub old_start: u32,
+	/// 变更前的行数
+	pub old_lines: u32,
+	/// 变更后的起始行号
+	pub new_start: u32,
+	/// 变更后的行数
+	pub new_lines: u32,
+	/// `@@`之后的上下文说明
+	pub header: Option<String>,
+	/// 差异行
+	pub lines: Vec<DiffLine>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[napi(object)]
+pub struct DiffLine {
+	/// 行类型
+	pub kind: DiffLineKind,
+	/// 行内容, 不包含前缀符号
+	pub content: String,
+	/// 在变更前文件中的行号
+	pub old_line: Option<u32>,
+	/// 在变更后文件中的行号
+	pub new_line: Option<u32>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[napi]
+pub enum DiffLineKind {
+	/// 上下文行
+	Context,
+	/// 新增行
+	Addition,
+	/// 删除行
+	Deletion,
+}
+
+#[derive(Debug, Default)]
+#[napi(constructor)]
+pub struct DiffParser;
+
+#[napi]
+impl DiffParser {
+	/// 解析统一差异格式文本
+	///
+	/// ## 参数
+	/// - `diff` 差异文本, 例如提交`diff`方法的返回值
+	#[napi]
+	pub fn parse(&self, diff: String) -> Vec<DiffFile> {
+		nipaw_core::types::diff::DiffFile::parse(&diff).into_iter().map(|f| f.into()).collect()
+	}
+}
+
+impl From<nipaw_core::types::diff::DiffFile> for DiffFile {
+	fn from(value: nipaw_core::types::diff::DiffFile) -> Self {
+		Self {
+			old_path: value.old_path,
+			new_path: value.new_path,
+			hunks: value.hunks.into_iter().map(|h| h.into()).collect(),
+		}
+	}
+}
+
+impl From<nipaw_core::types::diff::DiffHunk> for DiffHunk {
+	fn from(value: nipaw_core::types::diff::DiffHunk) -> Self {
+		Self {
+			old_start: value.old_start,
+			old_lines: value.old_lines,
+			new_start: value.new_start,
+			new_lines: value.new_lines,
+			header: value.header,
+			lines: value.lines.into_iter().map(|l| l.into()).collect(),
+		}
+	}
+}
+
+impl From<nipaw_core::types::diff::DiffLine> for DiffLine {
+	fn from(value: nipaw_core::types::diff::DiffLine) -> Self {
+		Self {
+			kind: value.kind.into(),
+			content: value.content,
+			old_line: value.old_line,
+			new_line: value.new_line,
+		}
+	}
+}
+
+impl From<nipaw_core::types::diff::DiffLineKind> for DiffLineKind {
+	fn from(value: nipaw_core::types::diff::DiffLineKind) -> Self {
+		match value {
+			nipaw_core::types::diff::DiffLineKind::Context => Self::Context,
+			nipaw_core::types::diff::DiffLineKind::Addition => Self::Addition,
+			nipaw_core::types::diff::DiffLineKind::Deletion => Self::Deletion,
+		}
+	}
+}
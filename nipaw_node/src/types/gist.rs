@@ -0,0 +1,66 @@
+use chrono::{DateTime, Utc};
+use napi_derive::napi;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct GistInfo {
+	/// 代码片段ID
+	pub id: String,
+	/// 代码片段描述
+	pub description: Option<String>,
+	/// 是否公开
+	pub public: bool,
+	/// 所有者用户名
+	pub owner: Option<String>,
+	/// 网页地址
+	pub html_url: String,
+	/// 文件列表
+	pub files: Vec<GistFile>,
+	/// 创建时间
+	pub created_at: DateTime<Utc>,
+	/// 更新时间
+	pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct GistFile {
+	/// 文件名
+	pub filename: String,
+	/// 文件语言
+	pub language: Option<String>,
+	/// 文件大小, 单位为字节
+	pub size: u32,
+	/// 原始文件地址
+	pub raw_url: Option<String>,
+	/// 文件内容
+	pub content: Option<String>,
+}
+
+impl From<nipaw_core::types::gist::GistInfo> for GistInfo {
+	fn from(value: nipaw_core::types::gist::GistInfo) -> Self {
+		Self {
+			id: value.id,
+			description: value.description,
+			public: value.public,
+			owner: value.owner,
+			html_url: value.html_url,
+			files: value.files.into_iter().map(|v| v.into()).collect(),
+			created_at: value.created_at,
+			updated_at: value.updated_at,
+		}
+	}
+}
+
+impl From<nipaw_core::types::gist::GistFile> for GistFile {
+	fn from(value: nipaw_core::types::gist::GistFile) -> Self {
+		Self {
+			filename: value.filename,
+			language: value.language,
+			size: value.size as u32,
+			raw_url: value.raw_url,
+			content: value.content,
+		}
+	}
+}
@@ -0,0 +1,94 @@
+use napi_derive::napi;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct GitRef {
+	/// 引用全名, 如`refs/heads/main`
+	pub name: String,
+	/// 指向的对象SHA
+	pub sha: String,
+	/// 指向的对象类型
+	pub object_type: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct GitTree {
+	/// 树对象SHA
+	pub sha: String,
+	/// 条目过多时平台会截断结果
+	pub truncated: bool,
+	/// 树条目
+	pub entries: Vec<GitTreeEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct GitTreeEntry {
+	/// 相对于树根的路径
+	pub path: String,
+	/// 文件模式
+	pub mode: String,
+	/// 条目类型, 如`blob`、`tree`、`commit`
+	pub entry_type: String,
+	/// 对象SHA
+	pub sha: String,
+	/// 文件大小
+	pub size: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct GitCommit {
+	/// 提交SHA
+	pub sha: String,
+	/// 提交信息
+	pub message: String,
+	/// 提交的树对象SHA
+	pub tree: String,
+	/// 父提交SHA列表
+	pub parents: Vec<String>,
+	/// 网页地址
+	pub html_url: Option<String>,
+}
+
+impl From<nipaw_core::types::git::GitRef> for GitRef {
+	fn from(value: nipaw_core::types::git::GitRef) -> Self {
+		Self { name: value.name, sha: value.sha, object_type: value.object_type }
+	}
+}
+
+impl From<nipaw_core::types::git::GitTree> for GitTree {
+	fn from(value: nipaw_core::types::git::GitTree) -> Self {
+		Self {
+			sha: value.sha,
+			truncated: value.truncated,
+			entries: value.entries.into_iter().map(|e| e.into()).collect(),
+		}
+	}
+}
+
+impl From<nipaw_core::types::git::GitTreeEntry> for GitTreeEntry {
+	fn from(value: nipaw_core::types::git::GitTreeEntry) -> Self {
+		Self {
+			path: value.path,
+			mode: value.mode,
+			entry_type: value.entry_type,
+			sha: value.sha,
+			size: value.size.map(|s| s as u32),
+		}
+	}
+}
+
+impl From<nipaw_core::types::git::GitCommit> for GitCommit {
+	fn from(value: nipaw_core::types::git::GitCommit) -> Self {
+		Self {
+			sha: value.sha,
+			message: value.message,
+			tree: value.tree,
+			parents: value.parents,
+			html_url: value.html_url,
+		}
+	}
+}
@@ -23,6 +23,18 @@ pub struct IssueInfo {
 	pub updated_at: DateTime<Utc>,
 	/// 议题关闭时间
 	pub closed_at: Option<DateTime<Utc>>,
+	/// 负责人信息
+	pub assignees: Vec<IssueUserInfo>,
+	/// 评论数量
+	pub comments: u32,
+	/// 里程碑名称
+	pub milestone: Option<String>,
+	/// 议题网页地址
+	pub html_url: Option<String>,
+	/// 是否已锁定, 只有GitHub返回锁定状态, 其他平台为空
+	pub locked: Option<bool>,
+	/// 是否为拉取请求, GitHub的议题列表会包含拉取请求
+	pub is_pull_request: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -96,6 +108,169 @@ impl From<nipaw_core::types::issue::IssueInfo> for IssueInfo {
 			created_at: value.created_at,
 			updated_at: value.updated_at,
 			closed_at: value.closed_at,
+			assignees: value.assignees.into_iter().map(Into::into).collect(),
+			comments: value.comments as u32,
+			milestone: value.milestone,
+			html_url: value.html_url,
+			locked: value.locked,
+			is_pull_request: value.is_pull_request,
+		}
+	}
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct IssueCommentInfo {
+	/// 评论ID
+	pub id: String,
+	/// 评论者信息
+	pub user: Option<IssueUserInfo>,
+	/// 评论内容
+	pub body: String,
+	/// 创建时间
+	pub created_at: DateTime<Utc>,
+	/// 更新时间
+	pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct IssueEventInfo {
+	/// 事件ID
+	pub id: Option<String>,
+	/// 操作者信息
+	pub actor: Option<IssueUserInfo>,
+	/// 事件类型
+	pub event: IssueEventType,
+	/// 标签名, 只有`Labeled`与`Unlabeled`有值
+	pub label: Option<String>,
+	/// 负责人, 只有`Assigned`与`Unassigned`有值
+	pub assignee: Option<String>,
+	/// 提交SHA, 只有`Closed`与`Referenced`可能有值
+	pub commit_id: Option<String>,
+	/// 原标题, 只有`Renamed`有值
+	pub from: Option<String>,
+	/// 新标题, 只有`Renamed`有值
+	pub to: Option<String>,
+	/// 里程碑, 只有`Milestoned`与`Demilestoned`有值
+	pub milestone: Option<String>,
+	/// 平台返回的原始事件名, 只有`Other`有值
+	pub raw: Option<String>,
+	/// 发生时间
+	pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi]
+pub enum IssueEventType {
+	/// 添加标签
+	Labeled,
+	/// 移除标签
+	Unlabeled,
+	/// 分配负责人
+	Assigned,
+	/// 取消分配负责人
+	Unassigned,
+	/// 关闭
+	Closed,
+	/// 重新打开
+	Reopened,
+	/// 修改标题
+	Renamed,
+	/// 被引用
+	Referenced,
+	/// 设置里程碑
+	Milestoned,
+	/// 移除里程碑
+	Demilestoned,
+	/// 其他事件
+	Other,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct TimelineItem {
+	/// 发生时间
+	pub created_at: DateTime<Utc>,
+	/// 评论, 条目为事件时为空
+	pub comment: Option<IssueCommentInfo>,
+	/// 事件, 条目为评论时为空
+	pub event: Option<IssueEventInfo>,
+}
+
+impl From<nipaw_core::types::issue::IssueCommentInfo> for IssueCommentInfo {
+	fn from(value: nipaw_core::types::issue::IssueCommentInfo) -> Self {
+		Self {
+			id: value.id,
+			user: value.user.map(Into::into),
+			body: value.body,
+			created_at: value.created_at,
+			updated_at: value.updated_at,
+		}
+	}
+}
+
+impl From<nipaw_core::types::issue::IssueEventInfo> for IssueEventInfo {
+	fn from(value: nipaw_core::types::issue::IssueEventInfo) -> Self {
+		use nipaw_core::types::issue::IssueEvent;
+		let mut info = Self {
+			id: value.id,
+			actor: value.actor.map(Into::into),
+			event: IssueEventType::Other,
+			label: None,
+			assignee: None,
+			commit_id: None,
+			from: None,
+			to: None,
+			milestone: None,
+			raw: None,
+			created_at: value.created_at,
+		};
+		match value.event {
+			IssueEvent::Labeled { label } => {
+				(info.event, info.label) = (IssueEventType::Labeled, Some(label))
+			}
+			IssueEvent::Unlabeled { label } => {
+				(info.event, info.label) = (IssueEventType::Unlabeled, Some(label))
+			}
+			IssueEvent::Assigned { assignee } => {
+				(info.event, info.assignee) = (IssueEventType::Assigned, Some(assignee))
+			}
+			IssueEvent::Unassigned { assignee } => {
+				(info.event, info.assignee) = (IssueEventType::Unassigned, Some(assignee))
+			}
+			IssueEvent::Closed { commit_id } => {
+				(info.event, info.commit_id) = (IssueEventType::Closed, commit_id)
+			}
+			IssueEvent::Reopened => info.event = IssueEventType::Reopened,
+			IssueEvent::Renamed { from, to } => {
+				(info.event, info.from, info.to) = (IssueEventType::Renamed, Some(from), Some(to))
+			}
+			IssueEvent::Referenced { commit_id } => {
+				(info.event, info.commit_id) = (IssueEventType::Referenced, commit_id)
+			}
+			IssueEvent::Milestoned { milestone } => {
+				(info.event, info.milestone) = (IssueEventType::Milestoned, Some(milestone))
+			}
+			IssueEvent::Demilestoned { milestone } => {
+				(info.event, info.milestone) = (IssueEventType::Demilestoned, Some(milestone))
+			}
+			IssueEvent::Other(raw) => info.raw = Some(raw),
+		}
+		info
+	}
+}
+
+impl From<nipaw_core::types::issue::TimelineItem> for TimelineItem {
+	fn from(value: nipaw_core::types::issue::TimelineItem) -> Self {
+		let created_at = value.created_at();
+		match value {
+			nipaw_core::types::issue::TimelineItem::Comment(comment) => {
+				Self { created_at, comment: Some(comment.into()), event: None }
+			}
+			nipaw_core::types::issue::TimelineItem::Event(event) => {
+				Self { created_at, comment: None, event: Some(event.into()) }
+			}
 		}
 	}
 }
@@ -0,0 +1,90 @@
+use chrono::{DateTime, Utc};
+use napi_derive::napi;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct SshKeyInfo {
+	/// 公钥ID
+	pub id: i64,
+	/// 公钥标题
+	pub title: String,
+	/// 公钥内容
+	pub key: String,
+	/// 公钥指纹
+	pub fingerprint: Option<String>,
+	/// 创建时间
+	pub created_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct GpgKeyInfo {
+	/// 公钥ID
+	pub id: i64,
+	/// GPG密钥ID
+	pub key_id: String,
+	/// 公钥名称
+	pub title: Option<String>,
+	/// 关联的邮箱列表
+	pub emails: Vec<String>,
+	/// 创建时间
+	pub created_at: Option<DateTime<Utc>>,
+	/// 过期时间
+	pub expires_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct DeployKeyInfo {
+	/// 部署公钥ID
+	pub id: i64,
+	/// 公钥标题
+	pub title: String,
+	/// 公钥内容
+	pub key: String,
+	/// 是否只读
+	pub read_only: bool,
+	/// 公钥指纹
+	pub fingerprint: Option<String>,
+	/// 创建时间
+	pub created_at: Option<DateTime<Utc>>,
+}
+
+impl From<nipaw_core::types::key::SshKeyInfo> for SshKeyInfo {
+	fn from(value: nipaw_core::types::key::SshKeyInfo) -> Self {
+		Self {
+			id: value.id as i64,
+			title: value.title,
+			key: value.key,
+			fingerprint: value.fingerprint,
+			created_at: value.created_at,
+		}
+	}
+}
+
+impl From<nipaw_core::types::key::GpgKeyInfo> for GpgKeyInfo {
+	fn from(value: nipaw_core::types::key::GpgKeyInfo) -> Self {
+		Self {
+			id: value.id as i64,
+			key_id: value.key_id,
+			title: value.title,
+			emails: value.emails,
+			created_at: value.created_at,
+			expires_at: value.expires_at,
+		}
+	}
+}
+
+impl From<nipaw_core::types::key::DeployKeyInfo> for DeployKeyInfo {
+	fn from(value: nipaw_core::types::key::DeployKeyInfo) -> Self {
+		Self {
+			id: value.id as i64,
+			title: value.title,
+			key: value.key,
+			read_only: value.read_only,
+			fingerprint: value.fingerprint,
+			created_at: value.created_at,
+		}
+	}
+}
@@ -0,0 +1,70 @@
+use crate::types::repo::RepoPath;
+use chrono::{DateTime, Utc};
+use napi_derive::napi;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct NotificationInfo {
+	/// 通知ID
+	pub id: String,
+	/// 通知对象的类型
+	pub subject_type: SubjectType,
+	/// 通知标题
+	pub title: String,
+	/// 通知所属的仓库
+	pub repo_path: RepoPath,
+	/// 收到通知的原因
+	pub reason: Option<String>,
+	/// 是否未读
+	pub unread: bool,
+	/// 更新时间
+	pub updated_at: DateTime<Utc>,
+	/// 通知对象的网页地址
+	pub html_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi]
+pub enum SubjectType {
+	/// 议题
+	Issue,
+	/// 拉取请求
+	PullRequest,
+	/// 提交
+	Commit,
+	/// 发行版
+	Release,
+	/// 讨论
+	Discussion,
+	/// 其他类型
+	Other,
+}
+
+impl From<nipaw_core::types::notification::NotificationInfo> for NotificationInfo {
+	fn from(value: nipaw_core::types::notification::NotificationInfo) -> Self {
+		Self {
+			id: value.id,
+			subject_type: value.subject_type.into(),
+			title: value.title,
+			repo_path: value.repo_path.into(),
+			reason: value.reason,
+			unread: value.unread,
+			updated_at: value.updated_at,
+			html_url: value.html_url,
+		}
+	}
+}
+
+impl From<nipaw_core::types::notification::SubjectType> for SubjectType {
+	fn from(value: nipaw_core::types::notification::SubjectType) -> Self {
+		match value {
+			nipaw_core::types::notification::SubjectType::Issue => SubjectType::Issue,
+			nipaw_core::types::notification::SubjectType::PullRequest => SubjectType::PullRequest,
+			nipaw_core::types::notification::SubjectType::Commit => SubjectType::Commit,
+			nipaw_core::types::notification::SubjectType::Release => SubjectType::Release,
+			nipaw_core::types::notification::SubjectType::Discussion => SubjectType::Discussion,
+			nipaw_core::types::notification::SubjectType::Other => SubjectType::Other,
+		}
+	}
+}
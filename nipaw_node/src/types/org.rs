@@ -1,3 +1,4 @@
+use crate::types::repo::{CollaboratorPermission, RepoInfo};
 use napi_derive::napi;
 use serde::{Deserialize, Serialize};
 
@@ -30,3 +31,79 @@ impl From<nipaw_core::types::org::OrgInfo> for OrgInfo {
 		}
 	}
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct OrgMember {
+	/// 成员用户名
+	pub login: String,
+	/// 成员头像URL
+	pub avatar_url: String,
+	/// 成员角色
+	pub role: MemberRole,
+}
+
+impl From<nipaw_core::types::org::OrgMember> for OrgMember {
+	fn from(member: nipaw_core::types::org::OrgMember) -> Self {
+		Self { login: member.login, avatar_url: member.avatar_url, role: member.role.into() }
+	}
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi]
+pub enum MemberRole {
+	/// 管理员
+	Admin,
+	/// 普通成员
+	Member,
+}
+
+impl From<nipaw_core::types::org::MemberRole> for MemberRole {
+	fn from(role: nipaw_core::types::org::MemberRole) -> Self {
+		match role {
+			nipaw_core::types::org::MemberRole::Admin => MemberRole::Admin,
+			nipaw_core::types::org::MemberRole::Member => MemberRole::Member,
+		}
+	}
+}
+
+impl From<MemberRole> for nipaw_core::types::org::MemberRole {
+	fn from(role: MemberRole) -> Self {
+		match role {
+			MemberRole::Admin => nipaw_core::types::org::MemberRole::Admin,
+			MemberRole::Member => nipaw_core::types::org::MemberRole::Member,
+		}
+	}
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct TeamInfo {
+	/// 团队标识
+	pub slug: String,
+	/// 团队名称
+	pub name: String,
+	/// 团队描述
+	pub description: Option<String>,
+}
+
+impl From<nipaw_core::types::org::TeamInfo> for TeamInfo {
+	fn from(team: nipaw_core::types::org::TeamInfo) -> Self {
+		Self { slug: team.slug, name: team.name, description: team.description }
+	}
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct TeamRepoInfo {
+	/// 仓库信息
+	pub repo: RepoInfo,
+	/// 团队对仓库的权限
+	pub permission: CollaboratorPermission,
+}
+
+impl From<nipaw_core::types::org::TeamRepoInfo> for TeamRepoInfo {
+	fn from(info: nipaw_core::types::org::TeamRepoInfo) -> Self {
+		Self { repo: info.repo.into(), permission: info.permission.into() }
+	}
+}
@@ -0,0 +1,156 @@
+use chrono::{DateTime, Utc};
+use napi_derive::napi;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct PipelineRun {
+	/// 运行ID, GitHub为运行ID, CNB为构建号`sn`
+	pub id: String,
+	/// 工作流或构建名称
+	pub name: Option<String>,
+	/// 运行序号
+	pub number: Option<u32>,
+	/// 触发的分支
+	pub branch: Option<String>,
+	/// 触发的提交SHA
+	pub sha: Option<String>,
+	/// 触发事件, 如`push`、`pull_request`
+	pub event: Option<String>,
+	/// 运行状态
+	pub status: PipelineStatus,
+	/// 网页地址
+	pub html_url: Option<String>,
+	/// 创建时间
+	pub created_at: Option<DateTime<Utc>>,
+	/// 更新时间
+	pub updated_at: Option<DateTime<Utc>>,
+	/// 任务列表, 只有获取运行详情时返回
+	pub jobs: Vec<PipelineJob>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct PipelineJob {
+	/// 任务ID
+	pub id: String,
+	/// 任务名称
+	pub name: String,
+	/// 任务状态
+	pub status: PipelineStatus,
+	/// 开始时间
+	pub started_at: Option<DateTime<Utc>>,
+	/// 结束时间
+	pub completed_at: Option<DateTime<Utc>>,
+	/// 步骤列表
+	pub steps: Vec<PipelineStep>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct PipelineStep {
+	/// 步骤名称
+	pub name: String,
+	/// 步骤序号
+	pub number: u32,
+	/// 步骤状态
+	pub status: PipelineStatus,
+	/// 开始时间
+	pub started_at: Option<DateTime<Utc>>,
+	/// 结束时间
+	pub completed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi]
+pub enum PipelineStatus {
+	/// 排队中
+	Queued,
+	/// 等待审批或资源
+	Waiting,
+	/// 运行中
+	Running,
+	/// 成功
+	Success,
+	/// 失败, 包括超时
+	Failure,
+	/// 已取消
+	Cancelled,
+	/// 已跳过
+	Skipped,
+	/// 无法识别的状态
+	Unknown,
+}
+
+impl From<nipaw_core::types::pipeline::PipelineRun> for PipelineRun {
+	fn from(value: nipaw_core::types::pipeline::PipelineRun) -> Self {
+		Self {
+			id: value.id,
+			name: value.name,
+			number: value.number.map(|n| n as u32),
+			branch: value.branch,
+			sha: value.sha,
+			event: value.event,
+			status: value.status.into(),
+			html_url: value.html_url,
+			created_at: value.created_at,
+			updated_at: value.updated_at,
+			jobs: value.jobs.into_iter().map(|j| j.into()).collect(),
+		}
+	}
+}
+
+impl From<nipaw_core::types::pipeline::PipelineJob> for PipelineJob {
+	fn from(value: nipaw_core::types::pipeline::PipelineJob) -> Self {
+		Self {
+			id: value.id,
+			name: value.name,
+			status: value.status.into(),
+			started_at: value.started_at,
+			completed_at: value.completed_at,
+			steps: value.steps.into_iter().map(|s| s.into()).collect(),
+		}
+	}
+}
+
+impl From<nipaw_core::types::pipeline::PipelineStep> for PipelineStep {
+	fn from(value: nipaw_core::types::pipeline::PipelineStep) -> Self {
+		Self {
+			name: value.name,
+			number: value.number as u32,
+			status: value.status.into(),
+			started_at: value.started_at,
+			completed_at: value.completed_at,
+		}
+	}
+}
+
+impl From<nipaw_core::types::pipeline::PipelineStatus> for PipelineStatus {
+	fn from(value: nipaw_core::types::pipeline::PipelineStatus) -> Self {
+		match value {
+			nipaw_core::types::pipeline::PipelineStatus::Queued => PipelineStatus::Queued,
+			nipaw_core::types::pipeline::PipelineStatus::Waiting => PipelineStatus::Waiting,
+			nipaw_core::types::pipeline::PipelineStatus::Running => PipelineStatus::Running,
+			nipaw_core::types::pipeline::PipelineStatus::Success => PipelineStatus::Success,
+			nipaw_core::types::pipeline::PipelineStatus::Failure => PipelineStatus::Failure,
+			nipaw_core::types::pipeline::PipelineStatus::Cancelled => PipelineStatus::Cancelled,
+			nipaw_core::types::pipeline::PipelineStatus::Skipped => PipelineStatus::Skipped,
+			nipaw_core::types::pipeline::PipelineStatus::Unknown => PipelineStatus::Unknown,
+		}
+	}
+}
+
+impl From<PipelineStatus> for nipaw_core::types::pipeline::PipelineStatus {
+	fn from(value: PipelineStatus) -> Self {
+		match value {
+			PipelineStatus::Queued => nipaw_core::types::pipeline::PipelineStatus::Queued,
+			PipelineStatus::Waiting => nipaw_core::types::pipeline::PipelineStatus::Waiting,
+			PipelineStatus::Running => nipaw_core::types::pipeline::PipelineStatus::Running,
+			PipelineStatus::Success => nipaw_core::types::pipeline::PipelineStatus::Success,
+			PipelineStatus::Failure => nipaw_core::types::pipeline::PipelineStatus::Failure,
+			PipelineStatus::Cancelled => nipaw_core::types::pipeline::PipelineStatus::Cancelled,
+			PipelineStatus::Skipped => nipaw_core::types::pipeline::PipelineStatus::Skipped,
+			PipelineStatus::Unknown => nipaw_core::types::pipeline::PipelineStatus::Unknown,
+		}
+	}
+}
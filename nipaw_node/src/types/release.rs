@@ -67,3 +67,42 @@ impl From<nipaw_core::types::release::AssetsInfo> for AssetsInfo {
 		Self { name: value.name, url: value.url }
 	}
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct DownloadResult {
+	/// 本次写入的字节数
+	pub written: i64,
+	/// 文件总字节数
+	pub size: i64,
+	/// 是否为续传
+	pub resumed: bool,
+	/// 校验通过的SHA-256摘要
+	pub sha256: Option<String>,
+}
+
+impl From<nipaw_core::types::release::DownloadResult> for DownloadResult {
+	fn from(value: nipaw_core::types::release::DownloadResult) -> Self {
+		Self {
+			written: value.written as i64,
+			size: value.size as i64,
+			resumed: value.resumed,
+			sha256: value.sha256,
+		}
+	}
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct DownloadProgress {
+	/// 已下载字节数, 包含续传前的部分
+	pub downloaded: i64,
+	/// 总字节数, 服务端未返回长度时为空
+	pub total: Option<i64>,
+}
+
+impl From<nipaw_core::types::release::DownloadProgress> for DownloadProgress {
+	fn from(value: nipaw_core::types::release::DownloadProgress) -> Self {
+		Self { downloaded: value.downloaded as i64, total: value.total.map(|v| v as i64) }
+	}
+}
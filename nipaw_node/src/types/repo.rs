@@ -1,6 +1,7 @@
 use chrono::{DateTime, Utc};
 use napi_derive::napi;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[napi(object)]
@@ -13,14 +14,18 @@ pub struct RepoInfo {
 	pub full_name: String,
 	/// 仓库描述
 	pub description: Option<String>,
-	/// 仓库可见性, public/private
+	/// 仓库可见性, public/private/internal
 	pub visibility: RepoVisibility,
 	/// 是否是fork仓库
 	pub fork: bool,
 	/// 仓库fork数量
 	pub fork_count: u32,
+	/// fork仓库的上游仓库
+	pub parent: Option<RepoPath>,
 	/// 仓库语言
 	pub language: Option<String>,
+	/// 仓库主题
+	pub topics: Vec<String>,
 	/// 仓库星标数量
 	pub star_count: u32,
 	/// 仓库默认分支
@@ -43,7 +48,9 @@ impl From<nipaw_core::types::repo::RepoInfo> for RepoInfo {
 			visibility: repo_info.visibility.into(),
 			fork: repo_info.fork,
 			fork_count: repo_info.fork_count as u32,
+			parent: repo_info.parent.map(|v| v.into()),
 			language: repo_info.language,
+			topics: repo_info.topics,
 			star_count: repo_info.star_count as u32,
 			default_branch: repo_info.default_branch,
 			created_at: repo_info.created_at,
@@ -60,6 +67,8 @@ pub enum RepoVisibility {
 	Public,
 	/// 私有
 	Private,
+	/// 内部公开
+	Internal,
 }
 
 impl From<nipaw_core::types::repo::Visibility> for RepoVisibility {
@@ -67,6 +76,17 @@ impl From<nipaw_core::types::repo::Visibility> for RepoVisibility {
 		match visibility {
 			nipaw_core::types::repo::Visibility::Public => RepoVisibility::Public,
 			nipaw_core::types::repo::Visibility::Private => RepoVisibility::Private,
+			nipaw_core::types::repo::Visibility::Internal => RepoVisibility::Internal,
+		}
+	}
+}
+
+impl From<RepoVisibility> for nipaw_core::types::repo::Visibility {
+	fn from(visibility: RepoVisibility) -> Self {
+		match visibility {
+			RepoVisibility::Public => nipaw_core::types::repo::Visibility::Public,
+			RepoVisibility::Private => nipaw_core::types::repo::Visibility::Private,
+			RepoVisibility::Internal => nipaw_core::types::repo::Visibility::Internal,
 		}
 	}
 }
@@ -86,6 +106,23 @@ impl From<nipaw_core::types::repo::CollaboratorResult> for CollaboratorResult {
 	}
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct CollaboratorInfo {
+	/// 协作者用户名
+	pub login: String,
+	/// 协作者头像URL
+	pub avatar_url: String,
+	/// 协作者权限
+	pub permission: CollaboratorPermission,
+}
+
+impl From<nipaw_core::types::repo::CollaboratorInfo> for CollaboratorInfo {
+	fn from(info: nipaw_core::types::repo::CollaboratorInfo) -> Self {
+		Self { login: info.login, avatar_url: info.avatar_url, permission: info.permission.into() }
+	}
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[napi]
 pub enum CollaboratorPermission {
@@ -95,6 +132,8 @@ pub enum CollaboratorPermission {
 	Push,
 	/// 拉取权限
 	Pull,
+	/// 无访问权限
+	None,
 }
 
 impl From<CollaboratorPermission> for nipaw_core::types::repo::CollaboratorPermission {
@@ -103,10 +142,119 @@ impl From<CollaboratorPermission> for nipaw_core::types::repo::CollaboratorPermi
 			CollaboratorPermission::Admin => nipaw_core::types::repo::CollaboratorPermission::Admin,
 			CollaboratorPermission::Push => nipaw_core::types::repo::CollaboratorPermission::Push,
 			CollaboratorPermission::Pull => nipaw_core::types::repo::CollaboratorPermission::Pull,
+			CollaboratorPermission::None => nipaw_core::types::repo::CollaboratorPermission::None,
+		}
+	}
+}
+
+impl From<nipaw_core::types::repo::CollaboratorPermission> for CollaboratorPermission {
+	fn from(permission: nipaw_core::types::repo::CollaboratorPermission) -> Self {
+		match permission {
+			nipaw_core::types::repo::CollaboratorPermission::Admin => CollaboratorPermission::Admin,
+			nipaw_core::types::repo::CollaboratorPermission::Push => CollaboratorPermission::Push,
+			nipaw_core::types::repo::CollaboratorPermission::Pull => CollaboratorPermission::Pull,
+			nipaw_core::types::repo::CollaboratorPermission::None => CollaboratorPermission::None,
+		}
+	}
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct SyncForkResult {
+	/// 同步说明
+	pub message: Option<String>,
+	/// 合并方式
+	pub merge_type: Option<String>,
+	/// 同步来源的上游分支
+	pub base_branch: Option<String>,
+}
+
+impl From<nipaw_core::types::repo::SyncForkResult> for SyncForkResult {
+	fn from(result: nipaw_core::types::repo::SyncForkResult) -> Self {
+		Self {
+			message: result.message,
+			merge_type: result.merge_type,
+			base_branch: result.base_branch,
+		}
+	}
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct ContributorInfo {
+	/// 贡献者用户名, 平台只返回提交作者时为作者名称
+	pub login: String,
+	/// 贡献者邮箱
+	pub email: Option<String>,
+	/// 贡献者头像URL
+	pub avatar_url: Option<String>,
+	/// 提交数
+	pub contributions: u32,
+}
+
+impl From<nipaw_core::types::repo::ContributorInfo> for ContributorInfo {
+	fn from(value: nipaw_core::types::repo::ContributorInfo) -> Self {
+		Self {
+			login: value.login,
+			email: value.email,
+			avatar_url: value.avatar_url,
+			contributions: value.contributions as u32,
+		}
+	}
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct RepoLanguages {
+	/// 统计单位
+	pub unit: LanguageUnit,
+	/// 语言与对应的字节数或百分比
+	pub languages: HashMap<String, f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi]
+pub enum LanguageUnit {
+	/// 字节数
+	Bytes,
+	/// 百分比
+	Percent,
+}
+
+impl From<nipaw_core::types::repo::RepoLanguages> for RepoLanguages {
+	fn from(value: nipaw_core::types::repo::RepoLanguages) -> Self {
+		Self { unit: value.unit.into(), languages: value.languages }
+	}
+}
+
+impl From<nipaw_core::types::repo::LanguageUnit> for LanguageUnit {
+	fn from(value: nipaw_core::types::repo::LanguageUnit) -> Self {
+		match value {
+			nipaw_core::types::repo::LanguageUnit::Bytes => LanguageUnit::Bytes,
+			nipaw_core::types::repo::LanguageUnit::Percent => LanguageUnit::Percent,
+		}
+	}
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi]
+pub enum ArchiveFormat {
+	/// gzip压缩的tar包
+	TarGz,
+	/// zip压缩包
+	Zip,
+}
+
+impl From<ArchiveFormat> for nipaw_core::types::repo::ArchiveFormat {
+	fn from(value: ArchiveFormat) -> Self {
+		match value {
+			ArchiveFormat::TarGz => nipaw_core::types::repo::ArchiveFormat::TarGz,
+			ArchiveFormat::Zip => nipaw_core::types::repo::ArchiveFormat::Zip,
 		}
 	}
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[napi(object)]
 pub struct RepoPath {
 	/// 仓库所有者
@@ -0,0 +1,118 @@
+use crate::types::{issue::IssueInfo, repo::RepoInfo, user::UserInfo};
+use napi_derive::napi;
+use nipaw_core::types::search::SearchResult;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct RepoSearchResult {
+	/// 匹配的结果总数
+	pub total_count: u32,
+	/// 结果是否不完整
+	pub incomplete: bool,
+	/// 本页结果
+	pub items: Vec<RepoInfo>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct IssueSearchResult {
+	/// 匹配的结果总数
+	pub total_count: u32,
+	/// 结果是否不完整
+	pub incomplete: bool,
+	/// 本页结果
+	pub items: Vec<IssueInfo>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct UserSearchResult {
+	/// 匹配的结果总数
+	pub total_count: u32,
+	/// 结果是否不完整
+	pub incomplete: bool,
+	/// 本页结果
+	pub items: Vec<UserInfo>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct CodeSearchResult {
+	/// 匹配的结果总数
+	pub total_count: u32,
+	/// 结果是否不完整
+	pub incomplete: bool,
+	/// 本页结果
+	pub items: Vec<CodeInfo>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct CodeInfo {
+	/// 文件名
+	pub name: String,
+	/// 文件路径
+	pub path: String,
+	/// 文件的blob SHA
+	pub sha: Option<String>,
+	/// 仓库所有者
+	pub owner: String,
+	/// 仓库名称
+	pub repo: String,
+	/// 文件网页URL
+	pub html_url: Option<String>,
+}
+
+impl From<SearchResult<nipaw_core::types::repo::RepoInfo>> for RepoSearchResult {
+	fn from(value: SearchResult<nipaw_core::types::repo::RepoInfo>) -> Self {
+		Self {
+			total_count: value.total_count as u32,
+			incomplete: value.incomplete,
+			items: value.items.into_iter().map(|v| v.into()).collect(),
+		}
+	}
+}
+
+impl From<SearchResult<nipaw_core::types::issue::IssueInfo>> for IssueSearchResult {
+	fn from(value: SearchResult<nipaw_core::types::issue::IssueInfo>) -> Self {
+		Self {
+			total_count: value.total_count as u32,
+			incomplete: value.incomplete,
+			items: value.items.into_iter().map(|v| v.into()).collect(),
+		}
+	}
+}
+
+impl From<SearchResult<nipaw_core::types::user::UserInfo>> for UserSearchResult {
+	fn from(value: SearchResult<nipaw_core::types::user::UserInfo>) -> Self {
+		Self {
+			total_count: value.total_count as u32,
+			incomplete: value.incomplete,
+			items: value.items.into_iter().map(|v| v.into()).collect(),
+		}
+	}
+}
+
+impl From<SearchResult<nipaw_core::types::search::CodeInfo>> for CodeSearchResult {
+	fn from(value: SearchResult<nipaw_core::types::search::CodeInfo>) -> Self {
+		Self {
+			total_count: value.total_count as u32,
+			incomplete: value.incomplete,
+			items: value.items.into_iter().map(|v| v.into()).collect(),
+		}
+	}
+}
+
+impl From<nipaw_core::types::search::CodeInfo> for CodeInfo {
+	fn from(value: nipaw_core::types::search::CodeInfo) -> Self {
+		Self {
+			name: value.name,
+			path: value.path,
+			sha: value.sha,
+			owner: value.owner,
+			repo: value.repo,
+			html_url: value.html_url,
+		}
+	}
+}
@@ -0,0 +1,79 @@
+use crate::types::repo::RepoPath;
+use chrono::{DateTime, Utc};
+use napi_derive::napi;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct SecretScope {
+	/// 仓库路径, 与`org_name`二选一
+	pub repo_path: Option<RepoPath>,
+	/// 组织名, 与`repo_path`二选一
+	pub org_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct SecretInfo {
+	/// 密钥名称
+	pub name: String,
+	/// 组织密钥的可见范围, 仓库密钥为空
+	pub visibility: Option<String>,
+	/// 创建时间
+	pub created_at: Option<DateTime<Utc>>,
+	/// 更新时间
+	pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct VariableInfo {
+	/// 变量名称
+	pub name: String,
+	/// 变量值
+	pub value: String,
+	/// 组织变量的可见范围, 仓库变量为空
+	pub visibility: Option<String>,
+	/// 创建时间
+	pub created_at: Option<DateTime<Utc>>,
+	/// 更新时间
+	pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<SecretScope> for nipaw_core::types::secret::SecretScope {
+	type Error = nipaw_core::Error;
+
+	fn try_from(value: SecretScope) -> Result<Self, Self::Error> {
+		match (value.repo_path, value.org_name) {
+			(Some(repo_path), None) => Ok(Self::Repo(repo_path.into())),
+			(None, Some(org_name)) => Ok(Self::Org(org_name)),
+			_ => Err(nipaw_core::Error::InvalidParam {
+				param: "scope".to_string(),
+				reason: "exactly one of repo_path and org_name must be set".to_string(),
+			}),
+		}
+	}
+}
+
+impl From<nipaw_core::types::secret::SecretInfo> for SecretInfo {
+	fn from(value: nipaw_core::types::secret::SecretInfo) -> Self {
+		Self {
+			name: value.name,
+			visibility: value.visibility,
+			created_at: value.created_at,
+			updated_at: value.updated_at,
+		}
+	}
+}
+
+impl From<nipaw_core::types::secret::VariableInfo> for VariableInfo {
+	fn from(value: nipaw_core::types::secret::VariableInfo) -> Self {
+		Self {
+			name: value.name,
+			value: value.value,
+			visibility: value.visibility,
+			created_at: value.created_at,
+			updated_at: value.updated_at,
+		}
+	}
+}
@@ -0,0 +1,66 @@
+use chrono::{DateTime, Utc};
+use napi_derive::napi;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct WeeklyCommitActivity {
+	/// 周起始时间, 即当周周日零点(UTC)
+	pub week: DateTime<Utc>,
+	/// 当周提交总数
+	pub total: u32,
+	/// 从周日到周六每天的提交数
+	pub days: Vec<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct CodeFrequency {
+	/// 周起始时间, 即当周周日零点(UTC)
+	pub week: DateTime<Utc>,
+	/// 新增的行数
+	pub additions: u32,
+	/// 删除的行数
+	pub deletions: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct PunchCard {
+	/// 星期, `0`为周日
+	pub weekday: u32,
+	/// 小时(UTC)
+	pub hour: u32,
+	/// 提交数
+	pub commits: u32,
+}
+
+impl From<nipaw_core::types::stats::WeeklyCommitActivity> for WeeklyCommitActivity {
+	fn from(value: nipaw_core::types::stats::WeeklyCommitActivity) -> Self {
+		Self {
+			week: value.week,
+			total: value.total as u32,
+			days: value.days.into_iter().map(|v| v as u32).collect(),
+		}
+	}
+}
+
+impl From<nipaw_core::types::stats::CodeFrequency> for CodeFrequency {
+	fn from(value: nipaw_core::types::stats::CodeFrequency) -> Self {
+		Self {
+			week: value.week,
+			additions: value.additions as u32,
+			deletions: value.deletions as u32,
+		}
+	}
+}
+
+impl From<nipaw_core::types::stats::PunchCard> for PunchCard {
+	fn from(value: nipaw_core::types::stats::PunchCard) -> Self {
+		Self {
+			weekday: value.weekday as u32,
+			hour: value.hour as u32,
+			commits: value.commits as u32,
+		}
+	}
+}
@@ -0,0 +1,98 @@
+use chrono::{DateTime, Utc};
+use napi_derive::napi;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct StatusInfo {
+	/// 状态ID
+	pub id: Option<i64>,
+	/// 状态
+	pub state: StatusState,
+	/// 状态的上下文标识
+	pub context: String,
+	/// 状态描述
+	pub description: Option<String>,
+	/// 详情链接
+	pub target_url: Option<String>,
+	/// 创建者登录名
+	pub creator: Option<String>,
+	/// 创建时间
+	pub created_at: Option<DateTime<Utc>>,
+	/// 更新时间
+	pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct CombinedStatusInfo {
+	/// 合并后的状态
+	pub state: StatusState,
+	/// 提交SHA
+	pub sha: String,
+	/// 状态总数
+	pub total_count: u32,
+	/// 每个上下文的最新状态
+	pub statuses: Vec<StatusInfo>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi]
+pub enum StatusState {
+	/// 等待中
+	Pending,
+	/// 成功
+	Success,
+	/// 失败
+	Failure,
+	/// 出错
+	Error,
+}
+
+impl From<nipaw_core::types::status::StatusInfo> for StatusInfo {
+	fn from(value: nipaw_core::types::status::StatusInfo) -> Self {
+		Self {
+			id: value.id.map(|id| id as i64),
+			state: value.state.into(),
+			context: value.context,
+			description: value.description,
+			target_url: value.target_url,
+			creator: value.creator,
+			created_at: value.created_at,
+			updated_at: value.updated_at,
+		}
+	}
+}
+
+impl From<nipaw_core::types::status::CombinedStatusInfo> for CombinedStatusInfo {
+	fn from(value: nipaw_core::types::status::CombinedStatusInfo) -> Self {
+		Self {
+			state: value.state.into(),
+			sha: value.sha,
+			total_count: value.total_count as u32,
+			statuses: value.statuses.into_iter().map(|s| s.into()).collect(),
+		}
+	}
+}
+
+impl From<nipaw_core::types::status::StatusState> for StatusState {
+	fn from(value: nipaw_core::types::status::StatusState) -> Self {
+		match value {
+			nipaw_core::types::status::StatusState::Pending => StatusState::Pending,
+			nipaw_core::types::status::StatusState::Success => StatusState::Success,
+			nipaw_core::types::status::StatusState::Failure => StatusState::Failure,
+			nipaw_core::types::status::StatusState::Error => StatusState::Error,
+		}
+	}
+}
+
+impl From<StatusState> for nipaw_core::types::status::StatusState {
+	fn from(value: StatusState) -> Self {
+		match value {
+			StatusState::Pending => nipaw_core::types::status::StatusState::Pending,
+			StatusState::Success => nipaw_core::types::status::StatusState::Success,
+			StatusState::Failure => nipaw_core::types::status::StatusState::Failure,
+			StatusState::Error => nipaw_core::types::status::StatusState::Error,
+		}
+	}
+}
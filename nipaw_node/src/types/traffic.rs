@@ -0,0 +1,104 @@
+use chrono::{DateTime, Utc};
+use napi_derive::napi;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct TrafficSeries {
+	/// 统计周期内的总次数
+	pub count: u32,
+	/// 统计周期内的独立访客数
+	pub uniques: u32,
+	/// 按天或按周的明细
+	pub items: Vec<TrafficPoint>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct TrafficPoint {
+	/// 当天或当周的起始时间
+	pub timestamp: DateTime<Utc>,
+	/// 次数
+	pub count: u32,
+	/// 独立访客数
+	pub uniques: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct TrafficReferrer {
+	/// 来源站点
+	pub referrer: String,
+	/// 访问次数
+	pub count: u32,
+	/// 独立访客数
+	pub uniques: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct TrafficPath {
+	/// 页面路径
+	pub path: String,
+	/// 页面标题
+	pub title: String,
+	/// 访问次数
+	pub count: u32,
+	/// 独立访客数
+	pub uniques: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi]
+pub enum TrafficPer {
+	/// 按天
+	Day,
+	/// 按周
+	Week,
+}
+
+impl From<nipaw_core::types::traffic::TrafficSeries> for TrafficSeries {
+	fn from(value: nipaw_core::types::traffic::TrafficSeries) -> Self {
+		Self {
+			count: value.count as u32,
+			uniques: value.uniques as u32,
+			items: value.items.into_iter().map(|v| v.into()).collect(),
+		}
+	}
+}
+
+impl From<nipaw_core::types::traffic::TrafficPoint> for TrafficPoint {
+	fn from(value: nipaw_core::types::traffic::TrafficPoint) -> Self {
+		Self {
+			timestamp: value.timestamp,
+			count: value.count as u32,
+			uniques: value.uniques as u32,
+		}
+	}
+}
+
+impl From<nipaw_core::types::traffic::TrafficReferrer> for TrafficReferrer {
+	fn from(value: nipaw_core::types::traffic::TrafficReferrer) -> Self {
+		Self { referrer: value.referrer, count: value.count as u32, uniques: value.uniques as u32 }
+	}
+}
+
+impl From<nipaw_core::types::traffic::TrafficPath> for TrafficPath {
+	fn from(value: nipaw_core::types::traffic::TrafficPath) -> Self {
+		Self {
+			path: value.path,
+			title: value.title,
+			count: value.count as u32,
+			uniques: value.uniques as u32,
+		}
+	}
+}
+
+impl From<TrafficPer> for nipaw_core::types::traffic::TrafficPer {
+	fn from(value: TrafficPer) -> Self {
+		match value {
+			TrafficPer::Day => nipaw_core::types::traffic::TrafficPer::Day,
+			TrafficPer::Week => nipaw_core::types::traffic::TrafficPer::Week,
+		}
+	}
+}